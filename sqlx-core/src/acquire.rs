@@ -0,0 +1,92 @@
+use std::ops::DerefMut;
+
+use futures_core::future::BoxFuture;
+
+use crate::connection::Connection;
+use crate::database::Database;
+use crate::pool::{Pool, PoolConnection};
+use crate::transaction::Transaction;
+
+/// Something a database connection can be acquired from.
+///
+/// Implemented for a `&Pool`, a `&mut` reference to a connection, a `&mut PoolConnection` and a
+/// `&mut Transaction` so that functions can be generic over where the connection they run
+/// queries on comes from.
+///
+/// ```rust,ignore
+/// async fn count_accounts<'c, A>(conn: A) -> tokio_sqlx::Result<i64>
+/// where
+///     A: Acquire<'c, Database = Postgres>,
+/// {
+///     let mut conn = conn.acquire().await?;
+///
+///     let row = tokio_sqlx::query("SELECT count(*) FROM accounts")
+///         .fetch_one(&mut *conn)
+///         .await?;
+///
+///     Ok(row.get(0))
+/// }
+/// ```
+pub trait Acquire<'c> {
+    type Database: Database;
+
+    type Connection: DerefMut<Target = <Self::Database as Database>::Connection> + Send + 'c;
+
+    /// Acquire a connection, checking it out of the pool if necessary.
+    fn acquire(self) -> BoxFuture<'c, crate::Result<Self::Connection>>;
+}
+
+impl<'c, DB> Acquire<'c> for &'c Pool<DB>
+where
+    DB: Database,
+{
+    type Database = DB;
+
+    type Connection = PoolConnection<DB>;
+
+    fn acquire(self) -> BoxFuture<'c, crate::Result<Self::Connection>> {
+        Box::pin(self.acquire_owned())
+    }
+}
+
+impl<'c, C> Acquire<'c> for &'c mut C
+where
+    C: Connection,
+    C::Database: Database<Connection = C> + Sized,
+{
+    type Database = C::Database;
+
+    type Connection = &'c mut C;
+
+    fn acquire(self) -> BoxFuture<'c, crate::Result<Self::Connection>> {
+        Box::pin(async move { Ok(self) })
+    }
+}
+
+impl<'c, DB> Acquire<'c> for &'c mut PoolConnection<DB>
+where
+    DB: Database,
+{
+    type Database = DB;
+
+    type Connection = &'c mut DB::Connection;
+
+    fn acquire(self) -> BoxFuture<'c, crate::Result<Self::Connection>> {
+        Box::pin(async move { Ok(&mut **self) })
+    }
+}
+
+// The queries run on the connection of the transaction, inside of it
+impl<'c, C> Acquire<'c> for &'c mut Transaction<'_, C>
+where
+    C: Connection,
+    C::Database: Database<Connection = C> + Sized,
+{
+    type Database = C::Database;
+
+    type Connection = &'c mut C;
+
+    fn acquire(self) -> BoxFuture<'c, crate::Result<Self::Connection>> {
+        Box::pin(async move { Ok(&mut **self) })
+    }
+}
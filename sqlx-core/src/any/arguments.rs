@@ -0,0 +1,145 @@
+use std::fmt::{self, Write};
+
+use crate::any::types::AnyKind;
+use crate::any::Any;
+use crate::arguments::{internal, Arguments};
+use crate::encode::{DynEncode, Encode, IsNull};
+use crate::placeholders::PlaceholderStyle;
+use crate::types::HasSqlType;
+
+#[derive(Default)]
+pub struct AnyArguments {
+    // The kind of every value and its encoding, or `None` for NULL
+    values: Vec<(AnyKind, Option<Vec<u8>>)>,
+
+    // The placeholders of the backend the arguments are for, if known
+    placeholders: Option<PlaceholderStyle>,
+}
+
+impl internal::Sealed for AnyArguments {}
+
+impl Arguments for AnyArguments {
+    type Database = Any;
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn size(&self) -> usize {
+        self.values
+            .iter()
+            .map(|(_, value)| value.as_ref().map_or(0, Vec::len))
+            .sum()
+    }
+
+    fn reserve(&mut self, len: usize, _size: usize) {
+        self.values.reserve(len);
+    }
+
+    fn add<T>(&mut self, value: T)
+    where
+        Self::Database: HasSqlType<T>,
+        T: Encode<Self::Database>,
+    {
+        self.push(<Any as HasSqlType<T>>::metadata().kind, &value);
+    }
+
+    fn add_dyn(&mut self, value: &dyn DynEncode<Self::Database>) {
+        self.push(value.type_metadata().kind, value);
+    }
+
+    // fails if the backend is not known
+    fn format_placeholder<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        match self.placeholders {
+            Some(PlaceholderStyle::Dollar) => write!(writer, "${}", self.values.len() + 1),
+            Some(PlaceholderStyle::QuestionMark) => writer.write_char('?'),
+            None => Err(fmt::Error),
+        }
+    }
+}
+
+impl AnyArguments {
+    #[cfg(feature = "postgres")]
+    pub(crate) fn for_postgres() -> Self {
+        AnyArguments {
+            values: Vec::new(),
+            placeholders: Some(PlaceholderStyle::Dollar),
+        }
+    }
+
+    #[cfg(feature = "mysql")]
+    pub(crate) fn for_mysql() -> Self {
+        AnyArguments {
+            values: Vec::new(),
+            placeholders: Some(PlaceholderStyle::QuestionMark),
+        }
+    }
+
+    fn push<T: Encode<Any> + ?Sized>(&mut self, kind: AnyKind, value: &T) {
+        let mut buf = Vec::with_capacity(value.size_hint());
+
+        let value = match value.encode_nullable(&mut buf) {
+            IsNull::No => Some(buf),
+            IsNull::Yes => None,
+        };
+
+        self.values.push((kind, value));
+    }
+
+    #[cfg(feature = "postgres")]
+    pub(super) fn into_postgres(self) -> crate::postgres::PgArguments {
+        let mut arguments = crate::postgres::PgArguments::default();
+
+        arguments.reserve(self.len(), self.size());
+
+        // the values are already in the binary format of Postgres
+        for (kind, value) in &self.values {
+            let oid = kind_metadata!(kind, crate::Postgres).oid;
+
+            arguments.push_raw(oid, value.as_deref());
+        }
+
+        arguments
+    }
+
+    #[cfg(feature = "mysql")]
+    pub(super) fn into_mysql(self) -> crate::mysql::MySqlArguments {
+        use byteorder::LittleEndian;
+
+        use crate::mysql::io::BufMutExt;
+
+        let mut arguments = crate::mysql::MySqlArguments::default();
+        let mut buf = Vec::new();
+
+        arguments.reserve(self.len(), self.size());
+
+        for (kind, value) in &self.values {
+            let value = match value {
+                Some(value) => value,
+                None => {
+                    arguments.push_raw(kind_metadata!(kind, crate::MySql), None);
+                    continue;
+                }
+            };
+
+            buf.clear();
+
+            match kind {
+                AnyKind::Bool => buf.extend_from_slice(value),
+
+                // MySQL sends numbers little-endian
+                AnyKind::SmallInt
+                | AnyKind::Int
+                | AnyKind::BigInt
+                | AnyKind::Float
+                | AnyKind::Double => buf.extend(value.iter().rev()),
+
+                AnyKind::Text | AnyKind::Bytes => buf.put_bytes_lenenc::<LittleEndian>(value),
+            }
+
+            arguments.push_raw(kind_metadata!(kind, crate::MySql), Some(&buf));
+        }
+
+        arguments
+    }
+}
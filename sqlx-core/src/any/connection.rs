@@ -0,0 +1,175 @@
+use std::borrow::Cow;
+use std::convert::TryInto;
+
+use futures_core::future::BoxFuture;
+
+use crate::any::{Any, AnyArguments};
+use crate::connection::internal::ConnectionState;
+use crate::connection::Connection;
+use crate::query_builder::QueryBuilder;
+use crate::transaction::{self, IsolationLevel, Transaction};
+use crate::url::Url;
+
+/// A connection to **Postgres** or **MySQL**, selected at runtime from the scheme of the URL
+/// passed to [Connection::open].
+///
+/// `postgres://` and `postgresql://` URLs connect to Postgres; `mysql://` and `mariadb://`
+/// URLs connect to MySQL.
+pub struct AnyConnection(pub(super) AnyConnectionKind);
+
+pub(super) enum AnyConnectionKind {
+    #[cfg(feature = "postgres")]
+    Postgres(crate::postgres::PgConnection),
+
+    #[cfg(feature = "mysql")]
+    MySql(crate::mysql::MySqlConnection),
+}
+
+impl AnyConnection {
+    pub(super) async fn open(url: crate::Result<Url>) -> crate::Result<Self> {
+        let url = url?;
+
+        let kind = match url.scheme() {
+            #[cfg(feature = "postgres")]
+            "postgres" | "postgresql" => {
+                AnyConnectionKind::Postgres(crate::postgres::PgConnection::open(Ok(url)).await?)
+            }
+
+            #[cfg(feature = "mysql")]
+            "mysql" | "mariadb" => {
+                AnyConnectionKind::MySql(crate::mysql::MySqlConnection::open(Ok(url)).await?)
+            }
+
+            scheme => return Err(crate::Error::UnsupportedScheme(scheme.into())),
+        };
+
+        Ok(AnyConnection(kind))
+    }
+
+    /// Sets a function that rewrites the SQL of every query run on this connection before it is
+    /// sent; see [PgConnection::set_sql_rewriter](crate::postgres::PgConnection::set_sql_rewriter)
+    /// and [MySqlConnection::set_sql_rewriter](crate::mysql::MySqlConnection::set_sql_rewriter).
+    pub fn set_sql_rewriter<F>(&mut self, rewrite: F)
+    where
+        F: for<'a> Fn(&'a str) -> Cow<'a, str> + Send + Sync + 'static,
+    {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.set_sql_rewriter(rewrite),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.set_sql_rewriter(rewrite),
+        }
+    }
+
+    /// Start a [QueryBuilder] with `init`, which writes the placeholders of the backend of this
+    /// connection (`$1`, `$2`, .. or `?`).
+    pub fn query_builder(&self, init: impl Into<String>) -> QueryBuilder<Any> {
+        let new_arguments: fn() -> AnyArguments = match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(_) => AnyArguments::for_postgres,
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(_) => AnyArguments::for_mysql,
+        };
+
+        QueryBuilder::with_arguments(init, new_arguments)
+    }
+}
+
+impl Connection for AnyConnection {
+    fn open<T>(url: T) -> BoxFuture<'static, crate::Result<Self>>
+    where
+        T: TryInto<Url, Error = crate::Error>,
+        Self: Sized,
+    {
+        Box::pin(AnyConnection::open(url.try_into()))
+    }
+
+    fn close(self) -> BoxFuture<'static, crate::Result<()>> {
+        match self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.close(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.close(),
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.is_closed(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.is_closed(),
+        }
+    }
+
+    fn shrink_buffers(&mut self) {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.shrink_buffers(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.shrink_buffers(),
+        }
+    }
+
+    fn ping(&mut self) -> BoxFuture<'_, crate::Result<()>> {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.ping(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.ping(),
+        }
+    }
+
+    fn begin_with(
+        &mut self,
+        isolation: IsolationLevel,
+        read_only: bool,
+    ) -> BoxFuture<'_, crate::Result<Transaction<'_, Self>>> {
+        Box::pin(async move {
+            match &self.0 {
+                #[cfg(feature = "postgres")]
+                AnyConnectionKind::Postgres(_) => {
+                    let start = transaction::start_sql(isolation, read_only);
+
+                    Transaction::begin(self, &[&start]).await
+                }
+
+                #[cfg(feature = "mysql")]
+                AnyConnectionKind::MySql(_) => {
+                    let set =
+                        crate::mysql::MySqlConnection::set_transaction_sql(isolation, read_only);
+
+                    Transaction::begin(self, &[&set, "START TRANSACTION"]).await
+                }
+            }
+        })
+    }
+}
+
+impl ConnectionState for AnyConnection {
+    fn start_rollback(&mut self) {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.start_rollback(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.start_rollback(),
+        }
+    }
+
+    fn reset(&mut self) {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.reset(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.reset(),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::database::Database;
+
+/// A database driver that dispatches to **Postgres** or **MySQL** at runtime.
+pub struct Any;
+
+impl Database for Any {
+    type Connection = super::AnyConnection;
+
+    type Arguments = super::AnyArguments;
+
+    type Row = super::AnyRow;
+}
+
+impl_into_arguments_for_database!(Any);
@@ -0,0 +1,201 @@
+use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use futures_util::TryStreamExt;
+
+use crate::any::connection::AnyConnectionKind;
+use crate::any::row::AnyRowKind;
+use crate::any::{Any, AnyArguments, AnyConnection, AnyRow, AnyTypeId};
+use crate::describe::{Column, Describe};
+use crate::executor::{CommandTag, Executor};
+
+impl Executor for AnyConnection {
+    type Database = Any;
+
+    fn send<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+    ) -> BoxFuture<'e, crate::Result<Vec<CommandTag>>> {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => Executor::send(conn, query),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => Executor::send(conn, query),
+        }
+    }
+
+    fn execute<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: AnyArguments,
+    ) -> BoxFuture<'e, crate::Result<u64>> {
+        Box::pin(self.execute_query(query, args, true))
+    }
+
+    fn execute_uncached<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: AnyArguments,
+    ) -> BoxFuture<'e, crate::Result<u64>> {
+        Box::pin(self.execute_query(query, args, false))
+    }
+
+    fn fetch<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: AnyArguments,
+    ) -> BoxStream<'e, crate::Result<AnyRow>> {
+        self.fetch_rows(query, args, None, true)
+    }
+
+    fn fetch_paged<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: AnyArguments,
+        fetch_size: u32,
+    ) -> BoxStream<'e, crate::Result<AnyRow>> {
+        self.fetch_rows(query, args, Some(fetch_size), true)
+    }
+
+    fn fetch_uncached<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: AnyArguments,
+        fetch_size: Option<u32>,
+    ) -> BoxStream<'e, crate::Result<AnyRow>> {
+        self.fetch_rows(query, args, fetch_size, false)
+    }
+
+    fn describe<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+    ) -> BoxFuture<'e, crate::Result<Describe<Any>>> {
+        Box::pin(async move {
+            let describe = match &mut self.0 {
+                #[cfg(feature = "postgres")]
+                AnyConnectionKind::Postgres(conn) => {
+                    let describe = Executor::describe(conn, query).await?;
+
+                    Describe {
+                        param_types: map_slice(describe.param_types, AnyTypeId::Postgres),
+                        result_columns: map_slice(describe.result_columns, |column| Column {
+                            name: column.name,
+                            table_id: column.table_id.map(|id| id.to_string().into()),
+                            type_id: AnyTypeId::Postgres(column.type_id),
+                            type_name: column.type_name,
+                            type_is_enum: column.type_is_enum,
+                            format: column.format,
+                            type_size: column.type_size,
+                            type_modifier: column.type_modifier,
+                            display_size: column.display_size,
+                            nullable: column.nullable,
+                        }),
+                    }
+                }
+
+                #[cfg(feature = "mysql")]
+                AnyConnectionKind::MySql(conn) => {
+                    let describe = Executor::describe(conn, query).await?;
+
+                    Describe {
+                        param_types: map_slice(describe.param_types, AnyTypeId::MySql),
+                        result_columns: map_slice(describe.result_columns, |column| Column {
+                            name: column.name,
+                            table_id: column.table_id,
+                            type_id: AnyTypeId::MySql(column.type_id),
+                            type_name: column.type_name,
+                            type_is_enum: column.type_is_enum,
+                            format: column.format,
+                            type_size: column.type_size,
+                            type_modifier: column.type_modifier,
+                            display_size: column.display_size,
+                            nullable: column.nullable,
+                        }),
+                    }
+                }
+            };
+
+            Ok(describe)
+        })
+    }
+}
+
+impl AnyConnection {
+    async fn execute_query(
+        &mut self,
+        query: &str,
+        args: AnyArguments,
+        persistent: bool,
+    ) -> crate::Result<u64> {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => {
+                let args = args.into_postgres();
+
+                if persistent {
+                    Executor::execute(conn, query, args).await
+                } else {
+                    Executor::execute_uncached(conn, query, args).await
+                }
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => {
+                let args = args.into_mysql();
+
+                if persistent {
+                    Executor::execute(conn, query, args).await
+                } else {
+                    Executor::execute_uncached(conn, query, args).await
+                }
+            }
+        }
+    }
+
+    fn fetch_rows<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: AnyArguments,
+        fetch_size: Option<u32>,
+        persistent: bool,
+    ) -> BoxStream<'e, crate::Result<AnyRow>> {
+        Box::pin(async_stream::try_stream! {
+            match &mut self.0 {
+                #[cfg(feature = "postgres")]
+                AnyConnectionKind::Postgres(conn) => {
+                    let args = args.into_postgres();
+
+                    let mut rows = match fetch_size {
+                        _ if !persistent => Executor::fetch_uncached(conn, query, args, fetch_size),
+                        Some(fetch_size) => Executor::fetch_paged(conn, query, args, fetch_size),
+                        None => Executor::fetch(conn, query, args),
+                    };
+
+                    while let Some(row) = rows.try_next().await? {
+                        yield AnyRow(AnyRowKind::Postgres(row));
+                    }
+                }
+
+                #[cfg(feature = "mysql")]
+                AnyConnectionKind::MySql(conn) => {
+                    let args = args.into_mysql();
+
+                    let mut rows = match fetch_size {
+                        _ if !persistent => Executor::fetch_uncached(conn, query, args, fetch_size),
+                        Some(fetch_size) => Executor::fetch_paged(conn, query, args, fetch_size),
+                        None => Executor::fetch(conn, query, args),
+                    };
+
+                    while let Some(row) = rows.try_next().await? {
+                        yield AnyRow(AnyRowKind::MySql(row));
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[allow(dead_code)]
+fn map_slice<T, U>(slice: Box<[T]>, f: impl FnMut(T) -> U) -> Box<[U]> {
+    slice.into_vec().into_iter().map(f).collect()
+}
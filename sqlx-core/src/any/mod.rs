@@ -0,0 +1,40 @@
+//! Database and connection types that select **Postgres** or **MySQL** at runtime.
+//!
+//! The backend is picked from the scheme of the connection URL (`postgres://` or `mysql://`).
+//! The SQL of queries is sent to the database as is, so it has to use the placeholders of
+//! the backend (`$1` or `?`); [AnyConnection::query_builder] writes them for you.
+//!
+//! Only the types every backend supports can be bound and decoded: `bool`, `i16`, `i32`, `i64`,
+//! `f32`, `f64`, `String` (`&str`) and `Vec<u8>` (`&[u8]`), and their `Option`s.
+
+use std::convert::TryInto;
+
+pub use arguments::AnyArguments;
+pub use connection::AnyConnection;
+pub use database::Any;
+pub use row::AnyRow;
+pub use types::{AnyTypeId, AnyTypeMetadata};
+
+use crate::url::Url;
+
+// defines `kind_metadata!` for the other modules
+#[macro_use]
+mod types;
+
+mod arguments;
+mod connection;
+mod database;
+mod executor;
+mod row;
+
+/// An alias for [`Pool`], specialized for [Any] database.
+pub type AnyPool = super::Pool<Any>;
+
+// used in tests and hidden code in examples
+#[doc(hidden)]
+pub async fn connect<T>(url: T) -> crate::Result<AnyConnection>
+where
+    T: TryInto<Url, Error = crate::Error>,
+{
+    AnyConnection::open(url.try_into()).await
+}
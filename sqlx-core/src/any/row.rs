@@ -0,0 +1,125 @@
+#[cfg(feature = "mysql")]
+use std::borrow::Cow;
+
+#[cfg(feature = "mysql")]
+use crate::any::types::AnyKind;
+use crate::any::Any;
+use crate::decode::Decode;
+#[cfg(feature = "mysql")]
+use crate::decode::DecodeError;
+use crate::row::{Row, RowIndex};
+use crate::types::HasSqlType;
+
+/// A row of a result set returned from [Any] database.
+pub struct AnyRow(pub(super) AnyRowKind);
+
+pub(super) enum AnyRowKind {
+    #[cfg(feature = "postgres")]
+    Postgres(crate::postgres::PgRow),
+
+    #[cfg(feature = "mysql")]
+    MySql(crate::mysql::MySqlRow),
+}
+
+impl Row for AnyRow {
+    type Database = Any;
+
+    fn len(&self) -> usize {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyRowKind::Postgres(row) => row.len(),
+
+            #[cfg(feature = "mysql")]
+            AnyRowKind::MySql(row) => row.len(),
+        }
+    }
+}
+
+impl AnyRow {
+    fn column_index(&self, name: &str) -> crate::Result<usize> {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyRowKind::Postgres(row) => row.column_index(name),
+
+            #[cfg(feature = "mysql")]
+            AnyRowKind::MySql(row) => row.column_index(name),
+        }
+    }
+
+    fn decode<T>(&self, index: usize) -> crate::Result<T>
+    where
+        Any: HasSqlType<T>,
+        T: Decode<Any>,
+    {
+        match &self.0 {
+            // the values are in the binary format of Postgres, which is the format of [Any]
+            #[cfg(feature = "postgres")]
+            AnyRowKind::Postgres(row) => Ok(T::decode_nullable(row.get_raw(index))?),
+
+            #[cfg(feature = "mysql")]
+            AnyRowKind::MySql(row) => {
+                let kind = <Any as HasSqlType<T>>::metadata().kind;
+                let value = row
+                    .get_raw(index)
+                    .map(|raw| from_mysql(kind, raw))
+                    .transpose()?;
+
+                Ok(T::decode_nullable(value.as_deref())?)
+            }
+        }
+    }
+}
+
+// converts a value of `kind` received from MySQL to the format of [Any]
+#[cfg(feature = "mysql")]
+fn from_mysql(kind: AnyKind, raw: &[u8]) -> Result<Cow<'_, [u8]>, DecodeError> {
+    use byteorder::LittleEndian;
+
+    use crate::mysql::io::BufExt;
+
+    Ok(match kind {
+        // booleans are sent as integers
+        AnyKind::Bool => Cow::Owned(vec![raw.iter().any(|&byte| byte != 0) as u8]),
+
+        // MySQL sends numbers little-endian
+        AnyKind::SmallInt | AnyKind::Int | AnyKind::BigInt | AnyKind::Float | AnyKind::Double => {
+            Cow::Owned(raw.iter().rev().copied().collect())
+        }
+
+        // text and bytes are prefixed with their length
+        AnyKind::Text | AnyKind::Bytes => {
+            let mut buf = raw;
+            let len = buf.get_uint_lenenc::<LittleEndian>()?.unwrap_or_default() as usize;
+
+            Cow::Borrowed(buf.get(..len).ok_or_else(|| {
+                DecodeError::Message(Box::new(format!(
+                    "expected {} bytes, received {}",
+                    len,
+                    buf.len()
+                )))
+            })?)
+        }
+    })
+}
+
+impl RowIndex<AnyRow> for usize {
+    fn try_get<T>(&self, row: &AnyRow) -> crate::Result<T>
+    where
+        <AnyRow as Row>::Database: HasSqlType<T>,
+        T: Decode<<AnyRow as Row>::Database>,
+    {
+        row.decode(*self)
+    }
+}
+
+impl RowIndex<AnyRow> for &'_ str {
+    fn try_get<T>(&self, row: &AnyRow) -> crate::Result<T>
+    where
+        <AnyRow as Row>::Database: HasSqlType<T>,
+        T: Decode<<AnyRow as Row>::Database>,
+    {
+        row.decode(row.column_index(self)?)
+    }
+}
+
+impl_from_row_for_row!(AnyRow);
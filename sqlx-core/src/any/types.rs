@@ -0,0 +1,251 @@
+use std::convert::TryInto;
+use std::fmt::{self, Display};
+use std::mem;
+use std::str;
+
+use crate::any::Any;
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::types::{HasSqlType, HasTypeMetadata};
+
+// The types supported by every backend. Values are encoded in the binary format of Postgres
+// (the integers and floats big-endian), so they are passed to and from Postgres as they are
+// and only their bytes are converted for MySQL.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum AnyKind {
+    Bool,
+    SmallInt,
+    Int,
+    BigInt,
+    Float,
+    Double,
+    Text,
+    Bytes,
+}
+
+/// The SQL type of a value bound to or decoded from a query on [Any] database.
+#[derive(Debug)]
+pub struct AnyTypeMetadata {
+    pub(crate) kind: AnyKind,
+}
+
+impl AnyTypeMetadata {
+    const fn new(kind: AnyKind) -> Self {
+        Self { kind }
+    }
+}
+
+/// The type ID reported by the backend of an [Any] connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnyTypeId {
+    #[cfg(feature = "postgres")]
+    Postgres(u32),
+
+    #[cfg(feature = "mysql")]
+    MySql(u8),
+}
+
+impl Display for AnyTypeId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            #[cfg(feature = "postgres")]
+            AnyTypeId::Postgres(oid) => write!(f, "{}", oid),
+
+            #[cfg(feature = "mysql")]
+            AnyTypeId::MySql(ty) => write!(f, "{}", ty),
+        }
+    }
+}
+
+impl HasTypeMetadata for Any {
+    type TypeMetadata = AnyTypeMetadata;
+
+    type TableId = Box<str>;
+
+    type TypeId = AnyTypeId;
+}
+
+// the metadata of the type of a kind in the backend `$DB`
+#[allow(unused_macros)]
+macro_rules! kind_metadata {
+    ($kind:expr, $DB:ty) => {
+        match $kind {
+            AnyKind::Bool => <$DB as HasSqlType<bool>>::metadata(),
+            AnyKind::SmallInt => <$DB as HasSqlType<i16>>::metadata(),
+            AnyKind::Int => <$DB as HasSqlType<i32>>::metadata(),
+            AnyKind::BigInt => <$DB as HasSqlType<i64>>::metadata(),
+            AnyKind::Float => <$DB as HasSqlType<f32>>::metadata(),
+            AnyKind::Double => <$DB as HasSqlType<f64>>::metadata(),
+            AnyKind::Text => <$DB as HasSqlType<String>>::metadata(),
+            AnyKind::Bytes => <$DB as HasSqlType<Vec<u8>>>::metadata(),
+        }
+    };
+}
+
+impl PartialEq<AnyTypeId> for AnyTypeMetadata {
+    fn eq(&self, id: &AnyTypeId) -> bool {
+        match *id {
+            #[cfg(feature = "postgres")]
+            AnyTypeId::Postgres(oid) => kind_metadata!(self.kind, crate::Postgres) == oid,
+
+            #[cfg(feature = "mysql")]
+            AnyTypeId::MySql(ty) => kind_metadata!(self.kind, crate::MySql) == ty,
+        }
+    }
+}
+
+impl HasSqlType<bool> for Any {
+    fn metadata() -> AnyTypeMetadata {
+        AnyTypeMetadata::new(AnyKind::Bool)
+    }
+}
+
+impl Encode<Any> for bool {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(*self as u8);
+    }
+}
+
+impl Decode<Any> for bool {
+    fn decode(raw: &[u8]) -> Result<Self, DecodeError> {
+        match raw {
+            [value] => Ok(*value != 0),
+
+            _ => Err(DecodeError::Message(Box::new(format!(
+                "expected 1 byte for bool, received {}",
+                raw.len()
+            )))),
+        }
+    }
+}
+
+macro_rules! impl_any_number {
+    ($($ty:ty => $kind:ident),*) => {
+        $(
+            impl HasSqlType<$ty> for Any {
+                fn metadata() -> AnyTypeMetadata {
+                    AnyTypeMetadata::new(AnyKind::$kind)
+                }
+            }
+
+            impl Encode<Any> for $ty {
+                fn encode(&self, buf: &mut Vec<u8>) {
+                    buf.extend_from_slice(&self.to_be_bytes());
+                }
+            }
+
+            impl Decode<Any> for $ty {
+                fn decode(raw: &[u8]) -> Result<Self, DecodeError> {
+                    raw.try_into().map(<$ty>::from_be_bytes).map_err(|_| {
+                        DecodeError::Message(Box::new(format!(
+                            "expected {} bytes for {}, received {}",
+                            mem::size_of::<$ty>(),
+                            stringify!($ty),
+                            raw.len()
+                        )))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_any_number!(
+    i16 => SmallInt,
+    i32 => Int,
+    i64 => BigInt,
+    f32 => Float,
+    f64 => Double
+);
+
+impl HasSqlType<str> for Any {
+    fn metadata() -> AnyTypeMetadata {
+        AnyTypeMetadata::new(AnyKind::Text)
+    }
+}
+
+impl HasSqlType<String> for Any {
+    fn metadata() -> AnyTypeMetadata {
+        <Any as HasSqlType<str>>::metadata()
+    }
+}
+
+impl Encode<Any> for str {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Encode<Any> for String {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <str as Encode<Any>>::encode(self.as_str(), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Decode<Any> for String {
+    fn decode(raw: &[u8]) -> Result<Self, DecodeError> {
+        Ok(str::from_utf8(raw)?.to_owned())
+    }
+}
+
+impl HasSqlType<[u8]> for Any {
+    fn metadata() -> AnyTypeMetadata {
+        AnyTypeMetadata::new(AnyKind::Bytes)
+    }
+}
+
+impl HasSqlType<Vec<u8>> for Any {
+    fn metadata() -> AnyTypeMetadata {
+        <Any as HasSqlType<[u8]>>::metadata()
+    }
+}
+
+impl Encode<Any> for [u8] {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self);
+    }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Encode<Any> for Vec<u8> {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <[u8] as Encode<Any>>::encode(self, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Decode<Any> for Vec<u8> {
+    fn decode(raw: &[u8]) -> Result<Self, DecodeError> {
+        Ok(raw.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Any, Decode, Encode};
+
+    #[test]
+    fn it_round_trips_numbers() {
+        let mut buf = Vec::new();
+        Encode::<Any>::encode(&-2_i32, &mut buf);
+
+        assert_eq!(buf, (-2_i32).to_be_bytes());
+        assert_eq!(<i32 as Decode<Any>>::decode(&buf).unwrap(), -2);
+
+        assert!(<i64 as Decode<Any>>::decode(&buf).is_err());
+    }
+}
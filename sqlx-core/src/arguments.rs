@@ -1,11 +1,15 @@
 //! Traits for passing arguments to SQL queries.
 
+use std::fmt::{self, Write};
+
 use crate::database::Database;
-use crate::encode::Encode;
+use crate::encode::{DynEncode, Encode};
 use crate::types::HasSqlType;
 
 /// A tuple of arguments to be sent to the database.
-pub trait Arguments: Send + Sized + Default + 'static {
+///
+/// This trait is sealed; it is implemented by the arguments of this crate only.
+pub trait Arguments: Send + Sized + Default + 'static + internal::Sealed {
     type Database: Database + ?Sized;
 
     /// Returns `true` if there are no values.
@@ -29,6 +33,21 @@ pub trait Arguments: Send + Sized + Default + 'static {
     where
         Self::Database: HasSqlType<T>,
         T: Encode<Self::Database>;
+
+    /// Add the value, whose type is only known at runtime, to the end of the arguments.
+    fn add_dyn(&mut self, value: &dyn DynEncode<Self::Database>);
+
+    /// Write the placeholder for the next value to be added, e.g. `$3` for Postgres or `?`
+    /// for MySQL.
+    fn format_placeholder<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        writer.write_char('?')
+    }
+}
+
+// The trait is public to be a supertrait of [Arguments], but cannot be named outside of this
+// crate.
+pub(crate) mod internal {
+    pub trait Sealed {}
 }
 
 pub trait IntoArguments<DB>
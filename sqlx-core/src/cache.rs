@@ -43,6 +43,27 @@ where
         self.statements.insert(query, id);
     }
 
+    pub fn remove(&mut self, query: &str) -> Option<Id> {
+        let id = self.statements.remove(query)?;
+        self.columns.remove(&id);
+
+        Some(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.statements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.statements.is_empty()
+    }
+
+    // Forget all statements, returning their ids
+    pub fn clear(&mut self) -> Vec<Id> {
+        self.columns.clear();
+        self.statements.drain().map(|(_, id)| id).collect()
+    }
+
     pub fn put_columns(&mut self, id: Id, columns: HashMap<Box<str>, usize>) {
         self.columns.insert(id, Arc::new(columns));
     }
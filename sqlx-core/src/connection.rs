@@ -1,4 +1,5 @@
 use crate::executor::Executor;
+use crate::transaction::{self, IsolationLevel, Transaction};
 use crate::url::Url;
 use futures_core::future::BoxFuture;
 use futures_util::TryFutureExt;
@@ -8,7 +9,9 @@ use std::convert::TryInto;
 ///
 /// Prefer running queries from [Pool] unless there is a specific need for a single, continuous
 /// connection.
-pub trait Connection: Executor + Send + 'static {
+///
+/// This trait is sealed; it is implemented by the connections of this crate only.
+pub trait Connection: Executor + Send + 'static + internal::ConnectionState {
     /// Establish a new database connection.
     fn open<T>(url: T) -> BoxFuture<'static, crate::Result<Self>>
     where
@@ -18,8 +21,62 @@ pub trait Connection: Executor + Send + 'static {
     /// Close this database connection.
     fn close(self) -> BoxFuture<'static, crate::Result<()>>;
 
+    /// Returns `true` if the connection is known to have been closed by the other end.
+    ///
+    /// Operations on a closed connection fail with [Error::ConnectionClosed].
+    ///
+    /// [Error::ConnectionClosed]: crate::Error::ConnectionClosed
+    fn is_closed(&self) -> bool {
+        false
+    }
+
+    /// Releases the memory that the buffers of the connection have grown to beyond their
+    /// initial sizes, e.g. after reading a large row.
+    ///
+    /// This is called by [Pool] when a connection is returned to it.
+    fn shrink_buffers(&mut self) {}
+
+    /// Start a transaction with the default isolation level of the database.
+    fn begin(&mut self) -> BoxFuture<'_, crate::Result<Transaction<'_, Self>>>
+    where
+        Self: Sized,
+    {
+        Box::pin(Transaction::begin(self, &["BEGIN"]))
+    }
+
+    /// Start a transaction with the given isolation level, which is read-only if `read_only`
+    /// is `true`.
+    ///
+    /// The default runs `START TRANSACTION ISOLATION LEVEL ..` of standard SQL.
+    fn begin_with(
+        &mut self,
+        isolation: IsolationLevel,
+        read_only: bool,
+    ) -> BoxFuture<'_, crate::Result<Transaction<'_, Self>>>
+    where
+        Self: Sized,
+    {
+        let start = transaction::start_sql(isolation, read_only);
+
+        Box::pin(async move { Transaction::begin(self, &[&start]).await })
+    }
+
     /// Verifies a connection to the database is still alive.
     fn ping(&mut self) -> BoxFuture<crate::Result<()>> {
         Box::pin(self.execute("SELECT 1", Default::default()).map_ok(|_| ()))
     }
 }
+
+// The state of a connection that only this crate manages; the trait is public to be a supertrait
+// of [Connection], but cannot be named outside of this crate.
+pub(crate) mod internal {
+    pub trait ConnectionState {
+        // Roll back the current transaction before the next query is run; called when a
+        // [Transaction](crate::Transaction) is dropped, so this cannot wait for the database
+        fn start_rollback(&mut self);
+
+        // Forget what the user of the connection set on it, e.g. its SQL rewriter; called when
+        // the connection is returned to a [Pool](crate::Pool)
+        fn reset(&mut self);
+    }
+}
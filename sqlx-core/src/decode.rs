@@ -22,6 +22,15 @@ where
 {
     fn decode(raw: &[u8]) -> Result<Self, DecodeError>;
 
+    /// Decodes a value sent in the text format, e.g. in the rows of a Postgres simple query,
+    /// instead of the binary format.
+    ///
+    /// The default implementation passes the text to [Decode::decode], for types that can
+    /// tell both formats apart themselves.
+    fn decode_text(raw: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode(raw)
+    }
+
     /// Creates a new value of this type from a `NULL` SQL value.
     ///
     /// The default implementation returns [DecodeError::UnexpectedNull].
@@ -47,6 +56,10 @@ where
         T::decode(buf).map(Some)
     }
 
+    fn decode_text(buf: &[u8]) -> Result<Self, DecodeError> {
+        T::decode_text(buf).map(Some)
+    }
+
     fn decode_null() -> Result<Self, DecodeError> {
         Ok(None)
     }
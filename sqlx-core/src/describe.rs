@@ -40,6 +40,37 @@ where
     pub name: Option<Box<str>>,
     pub table_id: Option<<DB as HasTypeMetadata>::TableId>,
     pub type_id: <DB as HasTypeMetadata>::TypeId,
+
+    /// The name of the column type, if reported.
+    ///
+    /// For Postgres this is only looked up for types that are not builtin (such as the types
+    /// of extensions) as they do not have a fixed type ID.
+    pub type_name: Option<Box<str>>,
+
+    /// Whether the column type is an enum, if reported.
+    ///
+    /// For Postgres this is looked up along with [type_name](#structfield.type_name).
+    pub type_is_enum: Option<bool>,
+
+    /// The format code the column will be transmitted in (0 = text, 1 = binary), if reported.
+    pub format: Option<i16>,
+
+    /// The size of the column type in bytes, if reported.
+    ///
+    /// Negative values denote variable-width types.
+    pub type_size: Option<i16>,
+
+    /// The type-specific modifier of the column (e.g. the `N` of `VARCHAR(N)`), if reported.
+    pub type_modifier: Option<i32>,
+
+    /// The display width of the column (e.g. the `1` of a MySQL `TINYINT(1)`), if reported.
+    pub display_size: Option<u32>,
+
+    /// Whether the column may be `NULL`, if reported.
+    ///
+    /// For Postgres this is only reported (as `true`) for columns of the nullable side of an
+    /// outer join, e.g. the right table of a `LEFT JOIN`, in a query with a `JOIN`.
+    pub nullable: Option<bool>,
 }
 
 impl<DB> Debug for Column<DB>
@@ -53,6 +84,13 @@ where
             .field("name", &self.name)
             .field("table_id", &self.table_id)
             .field("type_id", &self.type_id)
+            .field("type_name", &self.type_name)
+            .field("type_is_enum", &self.type_is_enum)
+            .field("format", &self.format)
+            .field("type_size", &self.type_size)
+            .field("type_modifier", &self.type_modifier)
+            .field("display_size", &self.display_size)
+            .field("nullable", &self.nullable)
             .finish()
     }
 }
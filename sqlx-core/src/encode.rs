@@ -1,7 +1,10 @@
 //! Types and traits for encoding values to the database.
 
 use crate::database::Database;
-use crate::types::HasSqlType;
+use crate::types::{HasSqlType, HasTypeMetadata};
+use std::borrow::Cow;
+use std::error::Error as StdError;
+use std::fmt::{self, Display};
 use std::mem;
 
 /// The return type of [Encode::encode].
@@ -32,6 +35,55 @@ where
     fn size_hint(&self) -> usize {
         mem::size_of_val(self)
     }
+
+    /// Returns an error if the value cannot be encoded, e.g. because it is out of the range
+    /// of the SQL type.
+    ///
+    /// [encode] is only called for values that pass this check; a query with an argument
+    /// that does not fails with [crate::Error::Encode] before it is sent.
+    fn check(&self) -> Result<(), EncodeError> {
+        Ok(())
+    }
+}
+
+/// An error returned by [Encode::check] for a value that cannot be encoded.
+#[derive(Debug, Clone)]
+pub struct EncodeError(Box<str>);
+
+impl EncodeError {
+    pub fn new(message: impl Display) -> Self {
+        EncodeError(message.to_string().into_boxed_str())
+    }
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl StdError for EncodeError {}
+
+/// An object-safe [Encode] that also knows the SQL type of the value.
+///
+/// This allows binding a list of values of different types, e.g. when building a query
+/// dynamically, with [Query::bind_dyn]. It is implemented for every type that can be bound.
+pub trait DynEncode<DB>: Encode<DB>
+where
+    DB: Database + ?Sized,
+{
+    /// Returns the metadata of the SQL type of the value.
+    fn type_metadata(&self) -> <DB as HasTypeMetadata>::TypeMetadata;
+}
+
+impl<T, DB> DynEncode<DB> for T
+where
+    DB: Database + HasSqlType<T> + ?Sized,
+    T: Encode<DB>,
+{
+    fn type_metadata(&self) -> <DB as HasTypeMetadata>::TypeMetadata {
+        <DB as HasSqlType<T>>::metadata()
+    }
 }
 
 impl<T: ?Sized, DB> Encode<DB> for &'_ T
@@ -50,6 +102,32 @@ where
     fn size_hint(&self) -> usize {
         (*self).size_hint()
     }
+
+    fn check(&self) -> Result<(), EncodeError> {
+        (*self).check()
+    }
+}
+
+impl<T: ?Sized, DB> Encode<DB> for Cow<'_, T>
+where
+    DB: Database + HasSqlType<T>,
+    T: Encode<DB> + ToOwned,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        (**self).encode(buf)
+    }
+
+    fn encode_nullable(&self, buf: &mut Vec<u8>) -> IsNull {
+        (**self).encode_nullable(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        (**self).size_hint()
+    }
+
+    fn check(&self) -> Result<(), EncodeError> {
+        (**self).check()
+    }
 }
 
 impl<T, DB> Encode<DB> for Option<T>
@@ -73,10 +151,10 @@ where
     }
 
     fn size_hint(&self) -> usize {
-        if self.is_some() {
-            mem::size_of::<T>()
-        } else {
-            0
-        }
+        self.as_ref().map_or(0, Encode::size_hint)
+    }
+
+    fn check(&self) -> Result<(), EncodeError> {
+        self.as_ref().map_or(Ok(()), Encode::check)
     }
 }
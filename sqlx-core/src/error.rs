@@ -1,6 +1,7 @@
 //! Error and Result types.
 
 use crate::decode::DecodeError;
+use crate::encode::EncodeError;
 use std::error::Error as StdError;
 use std::fmt::{self, Debug, Display};
 use std::io;
@@ -14,9 +15,28 @@ pub enum Error {
     /// Error communicating with the database.
     Io(io::Error),
 
+    /// The connection to the database was closed unexpectedly, e.g. because the server
+    /// shut down or the network connection was lost.
+    ///
+    /// The connection is not usable anymore and should be dropped.
+    ///
+    /// Contains the I/O error the connection failed with, or an error of the kind
+    /// [io::ErrorKind::UnexpectedEof] if the server closed it.
+    ConnectionClosed(io::Error),
+
     /// Connection URL was malformed.
     UrlParse(url::ParseError),
 
+    /// The scheme of the connection URL does not name a supported database, e.g. when
+    /// opening an `AnyConnection`.
+    ///
+    /// Contains the scheme of the URL.
+    UnsupportedScheme(Box<str>),
+
+    /// The server asked for the password over a connection that is not encrypted, which the
+    /// connection URL forbids with `require_ssl_for_password=true`.
+    PasswordRequiresSsl,
+
     /// An error was returned by the database.
     Database(Box<dyn DatabaseError>),
 
@@ -26,6 +46,17 @@ pub enum Error {
     /// More than one row was returned by a query that expected to return exactly one row.
     FoundMoreThanOne,
 
+    /// More rows were returned than allowed by [Query::fetch_all_limited].
+    ///
+    /// Contains the limit that was exceeded.
+    RowLimitExceeded(usize),
+
+    /// The query uses bind parameter placeholders of another database, e.g. `?` in a query
+    /// for Postgres or `$1` in a query for MySQL.
+    ///
+    /// Contains the placeholders the database expects.
+    PlaceholderStyleMismatch(&'static str),
+
     /// Column was not found in Row during [Row::try_get].
     ColumnNotFound(Box<str>),
 
@@ -46,6 +77,10 @@ pub enum Error {
 
     Decode(DecodeError),
 
+    /// An argument of the query could not be encoded, e.g. because it is out of the range
+    /// of its SQL type. The query was not sent.
+    Encode(EncodeError),
+
     // TODO: Remove and replace with `#[non_exhaustive]` when possible
     #[doc(hidden)]
     __Nonexhaustive,
@@ -56,10 +91,16 @@ impl StdError for Error {
         match self {
             Error::Io(error) => Some(error),
 
+            Error::ConnectionClosed(error) => Some(error),
+
+            Error::Encode(error) => Some(error),
+
             Error::UrlParse(error) => Some(error),
 
             Error::Decode(DecodeError::Other(error)) => Some(&**error),
 
+            Error::Database(error) => Some(error.as_std_error()),
+
             _ => None,
         }
     }
@@ -72,12 +113,39 @@ impl Display for Error {
 
             Error::UrlParse(error) => write!(f, "{}", error),
 
+            Error::UnsupportedScheme(ref scheme) => {
+                write!(f, "unsupported database URL scheme {:?}", scheme)
+            }
+
+            Error::PasswordRequiresSsl => {
+                f.write_str("refusing to send the password over a connection without TLS")
+            }
+
             Error::Decode(error) => write!(f, "{}", error),
 
+            Error::Encode(error) => write!(f, "{}", error),
+
             Error::Database(error) => Display::fmt(error, f),
 
+            Error::ConnectionClosed(_) => f.write_str("connection was closed unexpectedly"),
+
             Error::NotFound => f.write_str("found no rows when we expected at least one"),
 
+            Error::RowLimitExceeded(limit) => {
+                write!(
+                    f,
+                    "found more than {} rows which is the allowed maximum",
+                    limit
+                )
+            }
+
+            Error::PlaceholderStyleMismatch(expected) => write!(
+                f,
+                "the query uses the wrong style of bind parameter placeholders; \
+                 the database expects {}",
+                expected
+            ),
+
             Error::ColumnNotFound(ref name) => {
                 write!(f, "no column found with the name {:?}", name)
             }
@@ -97,17 +165,30 @@ impl Display for Error {
     }
 }
 
+impl Error {
+    // the server closed the connection without an error
+    pub(crate) fn connection_closed() -> Self {
+        Error::ConnectionClosed(io::ErrorKind::UnexpectedEof.into())
+    }
+}
+
 impl From<io::Error> for Error {
     #[inline]
     fn from(err: io::Error) -> Self {
-        Error::Io(err)
+        match err.kind() {
+            io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::BrokenPipe => Error::ConnectionClosed(err),
+
+            _ => Error::Io(err),
+        }
     }
 }
 
 impl From<io::ErrorKind> for Error {
     #[inline]
     fn from(err: io::ErrorKind) -> Self {
-        Error::Io(err.into())
+        io::Error::from(err).into()
     }
 }
 
@@ -118,6 +199,13 @@ impl From<DecodeError> for Error {
     }
 }
 
+impl From<EncodeError> for Error {
+    #[inline]
+    fn from(err: EncodeError) -> Self {
+        Error::Encode(err)
+    }
+}
+
 impl From<url::ParseError> for Error {
     #[inline]
     fn from(err: url::ParseError) -> Self {
@@ -143,10 +231,14 @@ where
 }
 
 /// An error that was returned by the database.
-pub trait DatabaseError: Display + Debug + Send + Sync {
+pub trait DatabaseError: StdError + Send + Sync + 'static {
     /// The primary, human-readable error message.
     fn message(&self) -> &str;
 
+    /// Returns this error as a `std::error::Error`, e.g. to downcast it to the error type
+    /// of the database driver.
+    fn as_std_error(&self) -> &(dyn StdError + Send + Sync + 'static);
+
     fn details(&self) -> Option<&str> {
         None
     }
@@ -166,6 +258,12 @@ pub trait DatabaseError: Display + Debug + Send + Sync {
     fn constraint_name(&self) -> Option<&str> {
         None
     }
+
+    /// The position in the query string that the error refers to, as a 1-based index
+    /// counted in characters.
+    fn position(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// Used by the `protocol_error!()` macro for a lazily evaluated conversion to
@@ -193,6 +291,7 @@ macro_rules! impl_fmt_error {
                     .field("table_name", &self.table_name())
                     .field("column_name", &self.column_name())
                     .field("constraint_name", &self.constraint_name())
+                    .field("position", &self.position())
                     .finish()
             }
         }
@@ -202,5 +301,7 @@ macro_rules! impl_fmt_error {
                 f.pad(self.message())
             }
         }
+
+        impl std::error::Error for $err {}
     };
 }
@@ -1,7 +1,7 @@
 use crate::database::Database;
 use crate::describe::Describe;
 use futures_core::future::BoxFuture;
-use futures_core::stream::BoxStream;
+use futures_core::stream::{BoxStream, Stream};
 use futures_util::TryStreamExt;
 
 /// Encapsulates query execution on the database.
@@ -10,12 +10,19 @@ use futures_util::TryStreamExt;
 pub trait Executor {
     type Database: Database + ?Sized;
 
-    /// Send a raw SQL command to the database.
+    /// Send raw SQL commands to the database, without preparing them or binding arguments.
     ///
-    /// This is intended for queries that cannot or should not be prepared (ex. `BEGIN`).
+    /// This is intended for queries that cannot or should not be prepared (ex. `BEGIN`), and
+    /// for scripts such as migrations. Postgres accepts several commands separated by `;`,
+    /// which run in an implicit transaction unless they manage it themselves; MySQL accepts a
+    /// single command.
     ///
-    /// Does not support fetching results.
-    fn send<'e, 'q: 'e>(&'e mut self, command: &'q str) -> BoxFuture<'e, crate::Result<()>>;
+    /// All results are consumed and discarded. Returns the [CommandTag] of every command, in
+    /// order.
+    fn send<'e, 'q: 'e>(
+        &'e mut self,
+        command: &'q str,
+    ) -> BoxFuture<'e, crate::Result<Vec<CommandTag>>>;
 
     /// Execute the query, returning the number of rows affected.
     fn execute<'e, 'q: 'e>(
@@ -31,6 +38,52 @@ pub trait Executor {
         args: <Self::Database as Database>::Arguments,
     ) -> BoxStream<'e, crate::Result<<Self::Database as Database>::Row>>;
 
+    /// Executes the query and returns a [Stream] of [Row], fetching at most `fetch_size` rows
+    /// from the database at a time.
+    ///
+    /// This bounds the memory used for large result sets where the database supports it
+    /// (Postgres); otherwise it is the same as [fetch].
+    fn fetch_paged<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: <Self::Database as Database>::Arguments,
+        fetch_size: u32,
+    ) -> BoxStream<'e, crate::Result<<Self::Database as Database>::Row>> {
+        let _ = fetch_size;
+
+        self.fetch(query, args)
+    }
+
+    /// Execute the query without keeping its prepared statement, returning the number of rows
+    /// affected.
+    ///
+    /// The statement is not added to the statement cache of the connection where the database
+    /// supports it (Postgres); otherwise it is the same as [execute].
+    fn execute_uncached<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: <Self::Database as Database>::Arguments,
+    ) -> BoxFuture<'e, crate::Result<u64>> {
+        self.execute(query, args)
+    }
+
+    /// Executes the query without keeping its prepared statement and returns a [Stream] of
+    /// [Row], fetching at most `fetch_size` rows from the database at a time if given.
+    ///
+    /// The statement is not added to the statement cache of the connection where the database
+    /// supports it (Postgres); otherwise it is the same as [fetch] or [fetch_paged].
+    fn fetch_uncached<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: <Self::Database as Database>::Arguments,
+        fetch_size: Option<u32>,
+    ) -> BoxStream<'e, crate::Result<<Self::Database as Database>::Row>> {
+        match fetch_size {
+            Some(fetch_size) => self.fetch_paged(query, args, fetch_size),
+            None => self.fetch(query, args),
+        }
+    }
+
     /// Executes the query and returns up to resulting record.
     ///
     /// * [crate::Error::FoundMoreThanOne] will be returned if the query produced more than 1 row.
@@ -39,22 +92,12 @@ pub trait Executor {
         query: &'q str,
         args: <Self::Database as Database>::Arguments,
     ) -> BoxFuture<'e, crate::Result<Option<<Self::Database as Database>::Row>>> {
-        let mut s = self.fetch(query, args);
-        Box::pin(async move {
-            match s.try_next().await? {
-                Some(val) => {
-                    if s.try_next().await?.is_some() {
-                        Err(crate::Error::FoundMoreThanOne)
-                    } else {
-                        Ok(Some(val))
-                    }
-                }
-                None => Ok(None),
-            }
-        })
+        Box::pin(fetch_optional_row(self.fetch(query, args)))
     }
 
-    /// Execute the query and return at most one resulting record.
+    /// Execute the query and return its first resulting record.
+    ///
+    /// * [crate::Error::NotFound] will be returned if the query produced no rows.
     fn fetch_one<'e, 'q: 'e>(
         &'e mut self,
         query: &'q str,
@@ -70,3 +113,48 @@ pub trait Executor {
         query: &'q str,
     ) -> BoxFuture<'e, crate::Result<Describe<Self::Database>>>;
 }
+
+// Resolves to the only row of the stream, if any
+pub(crate) async fn fetch_optional_row<T>(
+    mut s: impl Stream<Item = crate::Result<T>> + Unpin,
+) -> crate::Result<Option<T>> {
+    match s.try_next().await? {
+        Some(val) => {
+            if s.try_next().await?.is_some() {
+                Err(crate::Error::FoundMoreThanOne)
+            } else {
+                Ok(Some(val))
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+/// The result of a command run by [Executor::send].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandTag {
+    command: Box<str>,
+    rows_affected: u64,
+}
+
+impl CommandTag {
+    pub(crate) fn new(command: impl Into<Box<str>>, rows_affected: u64) -> Self {
+        Self {
+            command: command.into(),
+            rows_affected,
+        }
+    }
+
+    /// The command that was run, such as `INSERT` or `CREATE TABLE`.
+    ///
+    /// Postgres reports the command in its command tag. MySQL does not, so this is the first
+    /// keyword of the SQL, uppercased.
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// The number of rows affected or returned by the command, or `0` if it does not report one.
+    pub fn rows_affected(&self) -> u64 {
+        self.rows_affected
+    }
+}
@@ -2,11 +2,12 @@ use std::io;
 use tokio::io::*;
 
 const RBUF_SIZE: usize = 8 * 1024;
+const WBUF_SIZE: usize = 1024;
 
 pub struct BufStream<S> {
     pub(crate) stream: S,
 
-    // Have we reached end-of-file (been disconnected)
+    // Have we reached end-of-file or otherwise been disconnected
     stream_eof: bool,
 
     // Buffer used when sending outgoing messages
@@ -16,6 +17,9 @@ pub struct BufStream<S> {
     rbuf: Vec<u8>,
     rbuf_rindex: usize,
     rbuf_windex: usize,
+
+    // Size above which the read buffer is shrunk again once it has been consumed
+    rbuf_max: usize,
 }
 
 impl<S> BufStream<S>
@@ -26,10 +30,36 @@ where
         Self {
             stream,
             stream_eof: false,
-            wbuf: Vec::with_capacity(1024),
+            wbuf: Vec::with_capacity(WBUF_SIZE),
             rbuf: vec![0; RBUF_SIZE],
             rbuf_rindex: 0,
             rbuf_windex: 0,
+            rbuf_max: usize::MAX,
+        }
+    }
+
+    /// Sets the size above which the read buffer is shrunk back to its initial size after
+    /// an oversized message has been consumed.
+    ///
+    /// The buffer still grows to hold messages of any size.
+    #[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+    pub fn set_rbuf_max(&mut self, max: usize) {
+        self.rbuf_max = max.max(RBUF_SIZE);
+    }
+
+    /// Releases the capacity of the buffers beyond their initial sizes.
+    ///
+    /// A buffer that still holds data is left as it is.
+    pub fn shrink(&mut self) {
+        if self.rbuf_rindex == self.rbuf_windex {
+            self.rbuf_rindex = 0;
+            self.rbuf_windex = 0;
+            self.rbuf.truncate(RBUF_SIZE);
+            self.rbuf.shrink_to(RBUF_SIZE);
+        }
+
+        if self.wbuf.is_empty() {
+            self.wbuf.shrink_to(WBUF_SIZE);
         }
     }
 
@@ -38,11 +68,33 @@ where
         &mut self.wbuf
     }
 
+    /// Returns `true` if the other end has closed the stream.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.stream_eof
+    }
+
     #[inline]
+    // The bytes are removed from the buffer as they are written so that a flush that is
+    // cancelled can be resumed without sending any of them twice
     pub async fn flush(&mut self) -> io::Result<()> {
-        if !self.wbuf.is_empty() {
-            self.stream.write_all(&self.wbuf).await?;
-            self.wbuf.clear();
+        while !self.wbuf.is_empty() {
+            let written = match self.stream.write(&self.wbuf).await {
+                Ok(0) => Err(io::ErrorKind::WriteZero.into()),
+                res => res,
+            };
+
+            match written {
+                Ok(n) => {
+                    self.wbuf.drain(..n);
+                }
+
+                Err(err) => {
+                    self.stream_eof |= is_disconnect(&err);
+
+                    return Err(err);
+                }
+            }
         }
 
         Ok(())
@@ -51,6 +103,13 @@ where
     #[inline]
     pub fn consume(&mut self, cnt: usize) {
         self.rbuf_rindex += cnt;
+
+        // release the memory grown for a large message once nothing else is buffered
+        if self.rbuf.len() > self.rbuf_max && self.rbuf_rindex == self.rbuf_windex {
+            self.rbuf = vec![0; RBUF_SIZE];
+            self.rbuf_rindex = 0;
+            self.rbuf_windex = 0;
+        }
     }
 
     pub async fn peek(&mut self, cnt: usize) -> io::Result<Option<&[u8]>> {
@@ -104,7 +163,15 @@ where
                 }
             }
 
-            let n = self.stream.read(&mut self.rbuf[self.rbuf_windex..]).await?;
+            let n = match self.stream.read(&mut self.rbuf[self.rbuf_windex..]).await {
+                Ok(n) => n,
+
+                Err(err) => {
+                    self.stream_eof |= is_disconnect(&err);
+
+                    return Err(err);
+                }
+            };
 
             self.rbuf_windex += n;
 
@@ -115,6 +182,89 @@ where
     }
 }
 
+// Does the error mean the other end is gone
+fn is_disconnect(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::BrokenPipe
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BufStream, RBUF_SIZE, WBUF_SIZE};
+    use std::io::Cursor;
+
+    // reads a message of 4 times the initial buffer size followed by a small one and returns
+    // the size of the read buffer in between
+    async fn rbuf_len_after_large_message(max: Option<usize>) -> std::io::Result<usize> {
+        let mut stream = BufStream::new(Cursor::new(vec![1; 4 * RBUF_SIZE + 3]));
+
+        if let Some(max) = max {
+            stream.set_rbuf_max(max);
+        }
+
+        assert_eq!(
+            stream.peek(4 * RBUF_SIZE).await?.unwrap().len(),
+            4 * RBUF_SIZE
+        );
+        stream.consume(4 * RBUF_SIZE);
+
+        let len = stream.rbuf.len();
+
+        assert_eq!(stream.peek(3).await?, Some(&[1, 1, 1][..]));
+
+        Ok(len)
+    }
+
+    #[tokio::test]
+    async fn it_keeps_read_buffer_by_default() -> std::io::Result<()> {
+        assert_eq!(rbuf_len_after_large_message(None).await?, 4 * RBUF_SIZE);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_shrinks_read_buffer_above_max() -> std::io::Result<()> {
+        assert_eq!(
+            rbuf_len_after_large_message(Some(2 * RBUF_SIZE)).await?,
+            RBUF_SIZE
+        );
+
+        // not below the initial size
+        assert_eq!(rbuf_len_after_large_message(Some(0)).await?, RBUF_SIZE);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_shrinks_buffers() -> std::io::Result<()> {
+        let mut stream = BufStream::new(Cursor::new(vec![1; 4 * RBUF_SIZE + 3]));
+
+        stream.peek(4 * RBUF_SIZE).await?;
+        stream.buffer_mut().extend_from_slice(&[0; 4 * RBUF_SIZE]);
+
+        // neither buffer is shrunk while it holds data
+        stream.shrink();
+
+        assert!(stream.rbuf.capacity() >= 4 * RBUF_SIZE);
+        assert!(stream.wbuf.capacity() >= 4 * RBUF_SIZE);
+
+        stream.consume(4 * RBUF_SIZE);
+        stream.buffer_mut().clear();
+        stream.shrink();
+
+        assert_eq!(stream.rbuf.capacity(), RBUF_SIZE);
+        assert_eq!(stream.wbuf.capacity(), WBUF_SIZE);
+
+        assert_eq!(stream.peek(3).await?, Some(&[1, 1, 1][..]));
+
+        Ok(())
+    }
+}
+
 // TODO: Find a nicer way to do this
 // Return `Ok(None)` immediately from a function if the wrapped value is `None`
 #[allow(unused)]
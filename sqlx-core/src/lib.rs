@@ -11,11 +11,20 @@ mod io;
 #[cfg(any(feature = "mysql", feature = "postgres"))]
 mod cache;
 
+#[cfg(any(feature = "mysql", feature = "postgres"))]
+mod placeholders;
+
+#[cfg(any(feature = "mysql", feature = "postgres"))]
+mod rewrite;
+
+mod acquire;
 mod connection;
 mod database;
 mod executor;
 mod query;
 mod query_as;
+mod query_builder;
+mod transaction;
 mod url;
 
 #[macro_use]
@@ -32,6 +41,9 @@ pub mod row;
 #[cfg(feature = "mysql")]
 pub mod mysql;
 
+#[cfg(all(feature = "any", any(feature = "postgres", feature = "mysql")))]
+pub mod any;
+
 #[cfg(feature = "postgres")]
 pub mod postgres;
 
@@ -40,13 +52,14 @@ pub use database::Database;
 #[doc(inline)]
 pub use error::{Error, Result};
 
+pub use acquire::Acquire;
 pub use connection::Connection;
-pub use executor::Executor;
+pub use executor::{CommandTag, Executor};
 pub use query::{query, Query};
-pub use query_as::{query_as, QueryAs};
-
-#[doc(hidden)]
-pub use query_as::query_as_mapped;
+pub use query_as::{query_as, query_as_mapped, QueryAs, TryMap};
+pub use query_builder::QueryBuilder;
+pub use transaction::{IsolationLevel, Transaction};
+pub use url::Url;
 
 #[doc(inline)]
 pub use pool::Pool;
@@ -61,3 +74,7 @@ pub use mysql::MySql;
 #[cfg(feature = "postgres")]
 #[doc(inline)]
 pub use postgres::Postgres;
+
+#[cfg(all(feature = "any", any(feature = "postgres", feature = "mysql")))]
+#[doc(inline)]
+pub use any::Any;
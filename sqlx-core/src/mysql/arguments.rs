@@ -1,5 +1,5 @@
-use crate::arguments::Arguments;
-use crate::encode::{Encode, IsNull};
+use crate::arguments::{internal, Arguments};
+use crate::encode::{DynEncode, Encode, EncodeError, IsNull};
 use crate::mysql::types::MySqlTypeMetadata;
 use crate::mysql::MySql;
 use crate::types::HasSqlType;
@@ -9,8 +9,13 @@ pub struct MySqlArguments {
     pub(crate) param_types: Vec<MySqlTypeMetadata>,
     pub(crate) params: Vec<u8>,
     pub(crate) null_bitmap: Vec<u8>,
+
+    // The error of the first value that could not be encoded, returned when the query is run
+    pub(crate) error: Option<EncodeError>,
 }
 
+impl internal::Sealed for MySqlArguments {}
+
 impl Arguments for MySqlArguments {
     type Database = MySql;
 
@@ -38,14 +43,42 @@ impl Arguments for MySqlArguments {
         Self::Database: HasSqlType<T>,
         T: Encode<Self::Database>,
     {
-        let metadata = <MySql as HasSqlType<T>>::metadata();
+        self.push(<MySql as HasSqlType<T>>::metadata(), &value);
+    }
+
+    fn add_dyn(&mut self, value: &dyn DynEncode<Self::Database>) {
+        self.push(value.type_metadata(), value);
+    }
+}
+
+impl MySqlArguments {
+    fn push<T: Encode<MySql> + ?Sized>(&mut self, metadata: MySqlTypeMetadata, value: &T) {
         let index = self.param_types.len();
 
         self.param_types.push(metadata);
         self.null_bitmap.resize((index / 8) + 1, 0);
 
+        if let Err(err) = value.check() {
+            self.error.get_or_insert(err);
+            return;
+        }
+
         if let IsNull::Yes = value.encode_nullable(&mut self.params) {
-            self.null_bitmap[index / 8] &= (1 << index % 8) as u8;
+            self.null_bitmap[index / 8] |= (1 << index % 8) as u8;
+        }
+    }
+
+    // adds a value that is already encoded in the binary protocol, or `None` for NULL
+    #[cfg(feature = "any")]
+    pub(crate) fn push_raw(&mut self, metadata: MySqlTypeMetadata, value: Option<&[u8]>) {
+        let index = self.param_types.len();
+
+        self.param_types.push(metadata);
+        self.null_bitmap.resize((index / 8) + 1, 0);
+
+        match value {
+            Some(value) => self.params.extend_from_slice(value),
+            None => self.null_bitmap[index / 8] |= (1 << (index % 8)) as u8,
         }
     }
 }
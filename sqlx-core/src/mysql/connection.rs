@@ -1,5 +1,5 @@
+use std::borrow::Cow;
 use std::convert::TryInto;
-use std::io;
 use std::net::Shutdown;
 
 use byteorder::{ByteOrder, LittleEndian};
@@ -8,6 +8,7 @@ use sha1::Sha1;
 use tokio::net::TcpStream;
 
 use crate::cache::StatementCache;
+use crate::connection::internal::ConnectionState;
 use crate::connection::Connection;
 use crate::io::{Buf, BufMut, BufStream};
 use crate::mysql::error::MySqlError;
@@ -17,6 +18,8 @@ use crate::mysql::protocol::{
 };
 use crate::mysql::rsa;
 use crate::mysql::util::xor_eq;
+use crate::rewrite::SqlRewriter;
+use crate::transaction::{IsolationLevel, Transaction};
 use crate::url::Url;
 
 // Size before a packet is split
@@ -48,6 +51,12 @@ pub struct MySqlConnection {
     // Packets in a command sequence have an incrementing sequence number
     // This number must be 0 at the start of each command
     pub(super) next_seq_no: u8,
+
+    // Was a transaction dropped before it was committed or rolled back
+    pub(super) pending_rollback: bool,
+
+    // Applied to the SQL of queries before they are sent; see [set_sql_rewriter]
+    pub(super) sql_rewriter: SqlRewriter,
 }
 
 impl MySqlConnection {
@@ -114,15 +123,16 @@ impl MySqlConnection {
         let mut header = ret_if_none!(self.stream.peek(4).await?);
         self.packet_len = header.get_uint::<LittleEndian>(3)? as usize;
         self.next_seq_no = header.get_u8()?.wrapping_add(1);
-        self.stream.consume(4);
 
         // Read the packet body and copy it into our internal buf
         // We must have a separate buffer around the stream as we can't operate directly
         // on bytes returned from the stream. We have various kinds of payload manipulation
         // that must be handled before decoding.
-        let payload = ret_if_none!(self.stream.peek(self.packet_len).await?);
-        self.packet.extend_from_slice(payload);
-        self.stream.consume(self.packet_len);
+        // Nothing is consumed until the whole packet is buffered, so a receive that is
+        // cancelled can be resumed
+        let payload = ret_if_none!(self.stream.peek(4 + self.packet_len).await?);
+        self.packet.extend_from_slice(&payload[4..]);
+        self.stream.consume(4 + self.packet_len);
 
         // TODO: Implement packet compression
         // TODO: Implement packet joining
@@ -134,7 +144,7 @@ impl MySqlConnection {
     pub(crate) async fn receive(&mut self) -> crate::Result<&mut Self> {
         self.try_receive()
             .await?
-            .ok_or(io::ErrorKind::ConnectionAborted)?;
+            .ok_or_else(crate::Error::connection_closed)?;
 
         Ok(self)
     }
@@ -333,7 +343,9 @@ impl MySqlConnection {
             packet: Vec::with_capacity(8192),
             packet_len: 0,
             next_seq_no: 0,
+            pending_rollback: false,
             statement_cache: StatementCache::new(),
+            sql_rewriter: SqlRewriter::default(),
         })
     }
 
@@ -376,7 +388,7 @@ impl MySqlConnection {
 }
 
 impl MySqlConnection {
-    pub(super) async fn open(url: crate::Result<Url>) -> crate::Result<Self> {
+    pub(crate) async fn open(url: crate::Result<Url>) -> crate::Result<Self> {
         let url = url?;
         let mut self_ = Self::new(&url).await?;
 
@@ -427,6 +439,34 @@ impl MySqlConnection {
     }
 }
 
+impl MySqlConnection {
+    /// Sets a function that rewrites the SQL of every query run on this connection before it is
+    /// sent, e.g. to qualify tables with the database of a tenant.
+    ///
+    /// It is applied to queries with and without bind parameters, including [send]; the
+    /// prepared statements of the connection are cached by the rewritten SQL. It is cleared when
+    /// the connection is returned to a [Pool](crate::Pool). The queries of the
+    /// query macros are checked at compile-time before they are rewritten, so the rewritten SQL
+    /// must keep the same parameters and output columns.
+    ///
+    /// [send]: crate::Executor::send
+    pub fn set_sql_rewriter<F>(&mut self, rewrite: F)
+    where
+        F: for<'a> Fn(&'a str) -> Cow<'a, str> + Send + Sync + 'static,
+    {
+        self.sql_rewriter.set(rewrite);
+    }
+}
+
+impl MySqlConnection {
+    // The characteristics of the next transaction are set before it is started
+    pub(crate) fn set_transaction_sql(isolation: IsolationLevel, read_only: bool) -> String {
+        let access = if read_only { "READ ONLY" } else { "READ WRITE" };
+
+        format!("SET TRANSACTION ISOLATION LEVEL {}, {}", isolation, access)
+    }
+}
+
 impl Connection for MySqlConnection {
     fn open<T>(url: T) -> BoxFuture<'static, crate::Result<Self>>
     where
@@ -439,4 +479,33 @@ impl Connection for MySqlConnection {
     fn close(self) -> BoxFuture<'static, crate::Result<()>> {
         Box::pin(self.close())
     }
+
+    fn is_closed(&self) -> bool {
+        self.stream.is_closed()
+    }
+
+    fn shrink_buffers(&mut self) {
+        self.stream.shrink();
+        self.packet.shrink_to(8192);
+    }
+
+    fn begin_with(
+        &mut self,
+        isolation: IsolationLevel,
+        read_only: bool,
+    ) -> BoxFuture<'_, crate::Result<Transaction<'_, Self>>> {
+        let set = Self::set_transaction_sql(isolation, read_only);
+
+        Box::pin(async move { Transaction::begin(self, &[&set, "START TRANSACTION"]).await })
+    }
+}
+
+impl ConnectionState for MySqlConnection {
+    fn start_rollback(&mut self) {
+        self.pending_rollback = true;
+    }
+
+    fn reset(&mut self) {
+        self.sql_rewriter.clear();
+    }
 }
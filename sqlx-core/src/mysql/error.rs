@@ -1,3 +1,5 @@
+use std::error::Error as StdError;
+
 use crate::error::DatabaseError;
 use crate::mysql::protocol::ErrPacket;
 
@@ -7,6 +9,10 @@ impl DatabaseError for MySqlError {
     fn message(&self) -> &str {
         &*self.0.error_message
     }
+
+    fn as_std_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
+        self
+    }
 }
 
 impl_fmt_error!(MySqlError);
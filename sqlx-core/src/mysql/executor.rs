@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -5,12 +6,13 @@ use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 
 use crate::describe::{Column, Describe};
-use crate::executor::Executor;
+use crate::executor::{CommandTag, Executor};
 use crate::mysql::protocol::{
     Capabilities, ColumnCount, ColumnDefinition, ComQuery, ComStmtExecute, ComStmtPrepare,
     ComStmtPrepareOk, Cursor, Decode, EofPacket, OkPacket, Row, Type,
 };
 use crate::mysql::{MySql, MySqlArguments, MySqlConnection, MySqlRow};
+use crate::placeholders::{self, PlaceholderStyle};
 
 enum Step {
     Command(u64),
@@ -72,10 +74,21 @@ impl MySqlConnection {
             }
         }
 
+        if self.pending_rollback {
+            // A transaction was dropped before it was committed or rolled back
+            self.send(ComQuery { query: "ROLLBACK" }).await?;
+            self.pending_rollback = false;
+
+            let _ok = self.receive_ok_or_column_count().await?;
+            self.next_seq_no = 0;
+        }
+
         Ok(())
     }
 
     async fn prepare(&mut self, query: &str) -> crate::Result<ComStmtPrepareOk> {
+        placeholders::check(query, PlaceholderStyle::QuestionMark)?;
+
         // Start by sending a COM_STMT_PREPARE
         self.send(ComStmtPrepare { query }).await?;
 
@@ -135,6 +148,10 @@ impl MySqlConnection {
 
     // [COM_STMT_EXECUTE]
     async fn execute_statement(&mut self, id: u32, args: MySqlArguments) -> crate::Result<()> {
+        if let Some(err) = args.error {
+            return Err(err.into());
+        }
+
         self.send(ComStmtExecute {
             cursor: Cursor::NO_CURSOR,
             statement_id: id,
@@ -179,16 +196,17 @@ impl MySqlConnection {
 }
 
 impl MySqlConnection {
-    pub(super) async fn execute_raw(&mut self, query: &str) -> crate::Result<()> {
+    // Returns the number of rows affected, or returned for a query with a result set
+    pub(super) async fn execute_raw(&mut self, query: &str) -> crate::Result<u64> {
         self.wait_for_ready().await?;
 
         self.send(ComQuery { query }).await?;
 
         // COM_QUERY can terminate before the result set with an ERR or OK packet
         let num_columns = match self.receive_ok_or_column_count().await? {
-            OkOrResultSet::Ok(_) => {
+            OkOrResultSet::Ok(ok) => {
                 self.next_seq_no = 0;
-                return Ok(());
+                return Ok(ok.affected_rows);
             }
 
             OkOrResultSet::ResultSet(cc) => cc.columns as usize,
@@ -196,11 +214,16 @@ impl MySqlConnection {
 
         let columns = self.receive_column_types(num_columns as usize).await?;
 
-        while let Some(_step) = self.step(&columns, false).await? {
+        let mut rows = 0;
+
+        while let Some(step) = self.step(&columns, false).await? {
             // Drop all responses
+            if let Step::Row(_) = step {
+                rows += 1;
+            }
         }
 
-        Ok(())
+        Ok(rows)
     }
 
     async fn execute(&mut self, query: &str, args: MySqlArguments) -> crate::Result<u64> {
@@ -259,6 +282,14 @@ impl MySqlConnection {
                 table_id: column.table_alias.or(column.table),
 
                 type_id: column.r#type.0,
+                type_name: None,
+                type_is_enum: None,
+
+                format: None,
+                type_size: None,
+                type_modifier: None,
+                display_size: Some(column.max_size),
+                nullable: None,
             });
         }
 
@@ -277,13 +308,13 @@ impl MySqlConnection {
 
     fn fetch<'e, 'q: 'e>(
         &'e mut self,
-        query: &'q str,
+        query: Cow<'q, str>,
         args: MySqlArguments,
     ) -> BoxStream<'e, crate::Result<MySqlRow>> {
         Box::pin(async_stream::try_stream! {
             self.wait_for_ready().await?;
 
-            let statement_id = self.prepare_with_cache(query).await?;
+            let statement_id = self.prepare_with_cache(&query).await?;
 
             let columns = self.statement_cache.get_columns(statement_id);
 
@@ -313,8 +344,24 @@ impl MySqlConnection {
 impl Executor for MySqlConnection {
     type Database = super::MySql;
 
-    fn send<'e, 'q: 'e>(&'e mut self, query: &'q str) -> BoxFuture<'e, crate::Result<()>> {
-        Box::pin(self.execute_raw(query))
+    fn send<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+    ) -> BoxFuture<'e, crate::Result<Vec<CommandTag>>> {
+        Box::pin(async move {
+            let query = self.sql_rewriter.rewrite(query);
+
+            let rows = self.execute_raw(&query).await?;
+
+            // MySQL does not report the command, so it is taken from the SQL
+            let command = query
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_ascii_uppercase();
+
+            Ok(vec![CommandTag::new(command, rows)])
+        })
     }
 
     fn execute<'e, 'q: 'e>(
@@ -322,7 +369,11 @@ impl Executor for MySqlConnection {
         query: &'q str,
         args: MySqlArguments,
     ) -> BoxFuture<'e, crate::Result<u64>> {
-        Box::pin(self.execute(query, args))
+        Box::pin(async move {
+            let query = self.sql_rewriter.rewrite(query);
+
+            self.execute(&query, args).await
+        })
     }
 
     fn fetch<'e, 'q: 'e>(
@@ -330,6 +381,8 @@ impl Executor for MySqlConnection {
         query: &'q str,
         args: MySqlArguments,
     ) -> BoxStream<'e, crate::Result<MySqlRow>> {
+        let query = self.sql_rewriter.rewrite(query);
+
         self.fetch(query, args)
     }
 
@@ -337,6 +390,10 @@ impl Executor for MySqlConnection {
         &'e mut self,
         query: &'q str,
     ) -> BoxFuture<'e, crate::Result<Describe<Self::Database>>> {
-        Box::pin(self.describe(query))
+        Box::pin(async move {
+            let query = self.sql_rewriter.rewrite(query);
+
+            self.describe(&query).await
+        })
     }
 }
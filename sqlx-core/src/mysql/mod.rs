@@ -5,7 +5,7 @@ mod connection;
 mod database;
 mod error;
 mod executor;
-mod io;
+pub(crate) mod io;
 mod protocol;
 mod row;
 mod rsa;
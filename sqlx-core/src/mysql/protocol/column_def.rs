@@ -75,3 +75,23 @@ impl Decode for ColumnDefinition {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ColumnDefinition, Decode, Type};
+
+    const COLUMN_DEF_TINYINT_1: &[u8] = b"\x03def\x04test\x05users\x05users\x06active\x06active\
+        \x0c\x3f\x00\x01\x00\x00\x00\x01\x01\x10\x00\x00\x00";
+
+    #[test]
+    fn it_decodes_column_def() {
+        let column = ColumnDefinition::decode(COLUMN_DEF_TINYINT_1).unwrap();
+
+        assert_eq!(column.schema.as_deref(), Some("test"));
+        assert_eq!(column.table.as_deref(), Some("users"));
+        assert_eq!(column.column.as_deref(), Some("active"));
+        assert_eq!(column.r#type, Type::TINY);
+        // the display width of `TINYINT(1)`
+        assert_eq!(column.max_size, 1);
+    }
+}
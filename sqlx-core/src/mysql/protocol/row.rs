@@ -97,7 +97,8 @@ impl Row {
             } else {
                 let size = match columns[column_idx] {
                     Type::TINY => 1,
-                    Type::SHORT => 2,
+                    // YEAR is sent as a 2-byte integer
+                    Type::SHORT | Type::YEAR => 2,
                     Type::LONG => 4,
                     Type::LONGLONG => 8,
 
@@ -113,7 +114,13 @@ impl Row {
                     | Type::GEOMETRY
                     | Type::STRING
                     | Type::VARCHAR
-                    | Type::VAR_STRING => get_lenenc(&buffer[index..]),
+                    | Type::VAR_STRING
+                    | Type::JSON
+                    | Type::ENUM
+                    | Type::SET
+                    | Type::BIT
+                    | Type::DECIMAL
+                    | Type::NEWDECIMAL => get_lenenc(&buffer[index..]),
 
                     r#type => {
                         unimplemented!("encountered unknown field type: {:?}", r#type);
@@ -132,3 +139,26 @@ impl Row {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Row, Type};
+
+    // a LONG of 1, a JSON of `[1, 2]` and a NEWDECIMAL of 1.50
+    const ROW_LONG_JSON_DECIMAL: &[u8] = b"\x00\x00\x01\x00\x00\x00\x06[1, 2]\x041.50";
+
+    #[test]
+    fn it_decodes_length_encoded_binary_values() {
+        let row = Row::decode(
+            ROW_LONG_JSON_DECIMAL,
+            &[Type::LONG, Type::JSON, Type::NEWDECIMAL],
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(row.len(), 3);
+        assert_eq!(row.get(0), Some(&b"\x01\x00\x00\x00"[..]));
+        assert_eq!(row.get(1), Some(&b"\x06[1, 2]"[..]));
+        assert_eq!(row.get(2), Some(&b"\x041.50"[..]));
+    }
+}
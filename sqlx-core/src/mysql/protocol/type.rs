@@ -19,6 +19,7 @@ impl Type {
     pub const LONGLONG: Type = Type(8);
     pub const LONG_BLOB: Type = Type(251);
     pub const MEDIUM_BLOB: Type = Type(250);
+    pub const NEWDECIMAL: Type = Type(246);
     pub const NULL: Type = Type(6);
     pub const SET: Type = Type(248);
     pub const SHORT: Type = Type(2);
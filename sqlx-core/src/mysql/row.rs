@@ -12,21 +12,27 @@ pub struct MySqlRow {
     pub(super) columns: Arc<HashMap<Box<str>, usize>>,
 }
 
+impl MySqlRow {
+    pub(crate) fn column_index(&self, name: &str) -> crate::Result<usize> {
+        self.columns
+            .get(name)
+            .copied()
+            .ok_or_else(|| crate::Error::ColumnNotFound(name.into()))
+    }
+
+    // the value at `index` as it was received, or `None` for NULL
+    #[cfg(feature = "any")]
+    pub(crate) fn get_raw(&self, index: usize) -> Option<&[u8]> {
+        self.row.get(index)
+    }
+}
+
 impl Row for MySqlRow {
     type Database = MySql;
 
     fn len(&self) -> usize {
         self.row.len()
     }
-
-    fn get<T, I>(&self, index: I) -> T
-    where
-        Self::Database: HasSqlType<T>,
-        I: RowIndex<Self>,
-        T: Decode<Self::Database>,
-    {
-        index.try_get(self).unwrap()
-    }
 }
 
 impl RowIndex<MySqlRow> for usize {
@@ -45,12 +51,9 @@ impl RowIndex<MySqlRow> for &'_ str {
         <MySqlRow as Row>::Database: HasSqlType<T>,
         T: Decode<<MySqlRow as Row>::Database>,
     {
-        let index = row
-            .columns
-            .get(*self)
-            .ok_or_else(|| crate::Error::ColumnNotFound((*self).into()))?;
+        let index = row.column_index(self)?;
 
-        let value = Decode::decode_nullable(row.row.get(*index))?;
+        let value = Decode::decode_nullable(row.row.get(index))?;
 
         Ok(value)
     }
@@ -0,0 +1,52 @@
+use byteorder::LittleEndian;
+use serde_json::Value;
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::mysql::io::{BufExt, BufMutExt};
+use crate::mysql::protocol::Type;
+use crate::mysql::types::MySqlTypeMetadata;
+use crate::mysql::MySql;
+use crate::types::HasSqlType;
+
+impl HasSqlType<Value> for MySql {
+    fn metadata() -> MySqlTypeMetadata {
+        // documents are sent as text, which the server converts where a JSON value is expected;
+        // JSON columns (MySQL 5.7+) are received as text
+        MySqlTypeMetadata::compatible(Type::VAR_STRING, &[Type::JSON])
+    }
+}
+
+impl Encode<MySql> for Value {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.put_str_lenenc::<LittleEndian>(&self.to_string());
+    }
+}
+
+impl Decode<MySql> for Value {
+    fn decode(mut buf: &[u8]) -> Result<Self, DecodeError> {
+        Ok(serde_json::from_str(
+            buf.get_str_lenenc::<LittleEndian>()?.unwrap_or_default(),
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decode, Encode, MySql, Value};
+
+    #[test]
+    fn it_encodes_json() {
+        let mut buf = Vec::new();
+        Encode::<MySql>::encode(&serde_json::json!({ "a": [1, null] }), &mut buf);
+
+        assert_eq!(buf, b"\x0e{\"a\":[1,null]}");
+    }
+
+    #[test]
+    fn it_decodes_json() {
+        let json: Value = Decode::<MySql>::decode(b"\x0b{\"a\": true}").unwrap();
+
+        assert_eq!(json, serde_json::json!({ "a": true }));
+    }
+}
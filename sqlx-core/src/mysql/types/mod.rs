@@ -12,24 +12,35 @@ mod uint;
 #[cfg(feature = "chrono")]
 mod chrono;
 
+#[cfg(feature = "json")]
+mod json;
+
 #[derive(Default, Debug)]
 pub struct MySqlTypeMetadata {
     pub(crate) r#type: Type,
     pub(crate) is_unsigned: bool,
+    // types of other columns that are sent in the same representation as this one
+    pub(crate) compatible: &'static [Type],
 }
 
 impl MySqlTypeMetadata {
     pub(crate) fn new(r#type: Type) -> Self {
+        Self::compatible(r#type, &[])
+    }
+
+    pub(crate) fn unsigned(r#type: Type) -> Self {
         Self {
             r#type,
-            is_unsigned: false,
+            is_unsigned: true,
+            compatible: &[],
         }
     }
 
-    pub(crate) fn unsigned(r#type: Type) -> Self {
+    pub(crate) fn compatible(r#type: Type, compatible: &'static [Type]) -> Self {
         Self {
             r#type,
-            is_unsigned: true,
+            is_unsigned: false,
+            compatible,
         }
     }
 }
@@ -44,6 +55,6 @@ impl HasTypeMetadata for MySql {
 
 impl PartialEq<u8> for MySqlTypeMetadata {
     fn eq(&self, other: &u8) -> bool {
-        &self.r#type.0 == other
+        &self.r#type.0 == other || self.compatible.iter().any(|ty| &ty.0 == other)
     }
 }
@@ -16,10 +16,18 @@ impl HasSqlType<str> for MySql {
     }
 }
 
+// Strings are sent as their UTF-8 bytes, which the server reads in the character set of the
+// connection. This is `utf8mb4` (requested in the handshake and set again with `SET NAMES` on
+// connect) so characters outside of the Basic Multilingual Plane, such as emoji, survive.
 impl Encode<MySql> for str {
     fn encode(&self, buf: &mut Vec<u8>) {
         buf.put_str_lenenc::<LittleEndian>(self);
     }
+
+    fn size_hint(&self) -> usize {
+        // the length is encoded in up to 9 bytes
+        9 + self.len()
+    }
 }
 
 impl HasSqlType<String> for MySql {
@@ -32,6 +40,10 @@ impl Encode<MySql> for String {
     fn encode(&self, buf: &mut Vec<u8>) {
         <str as Encode<MySql>>::encode(self.as_str(), buf)
     }
+
+    fn size_hint(&self) -> usize {
+        <str as Encode<MySql>>::size_hint(self.as_str())
+    }
 }
 
 impl Decode<MySql> for String {
@@ -42,3 +54,41 @@ impl Decode<MySql> for String {
             .to_owned())
     }
 }
+
+// string types with a different representation are encoded and decoded like `str`
+#[cfg(any(feature = "smol_str", feature = "compact_str"))]
+macro_rules! impl_str_like {
+    ($($ty:ty),*) => {
+        $(
+            impl HasSqlType<$ty> for MySql {
+                fn metadata() -> MySqlTypeMetadata {
+                    <MySql as HasSqlType<str>>::metadata()
+                }
+            }
+
+            impl Encode<MySql> for $ty {
+                fn encode(&self, buf: &mut Vec<u8>) {
+                    <str as Encode<MySql>>::encode(self.as_str(), buf)
+                }
+
+                fn size_hint(&self) -> usize {
+                    <str as Encode<MySql>>::size_hint(self.as_str())
+                }
+            }
+
+            impl Decode<MySql> for $ty {
+                fn decode(mut buf: &[u8]) -> Result<Self, DecodeError> {
+                    Ok(<$ty>::from(
+                        buf.get_str_lenenc::<LittleEndian>()?.unwrap_or_default(),
+                    ))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "smol_str")]
+impl_str_like!(smol_str::SmolStr);
+
+#[cfg(feature = "compact_str")]
+impl_str_like!(compact_str::CompactString);
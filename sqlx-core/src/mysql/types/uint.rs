@@ -27,6 +27,8 @@ impl Decode<MySql> for u8 {
     }
 }
 
+// `YEAR` columns are transmitted as 2-byte integers too and decode as `u16` (or `i16`)
+// holding the four-digit year; the zero year `0000` decodes as `0`
 impl HasSqlType<u16> for MySql {
     #[inline]
     fn metadata() -> MySqlTypeMetadata {
@@ -0,0 +1,242 @@
+// Detection of bind parameter placeholders in the style of another database, e.g. `?` in a
+// query for Postgres, so it can be reported before the server fails with a confusing error, and
+// of keywords; string literals, quoted identifiers and comments are skipped.
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum PlaceholderStyle {
+    // `$1`, `$2`, .. (Postgres)
+    #[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+    Dollar,
+
+    // `?` (MySQL)
+    #[cfg_attr(not(feature = "mysql"), allow(dead_code))]
+    QuestionMark,
+}
+
+impl PlaceholderStyle {
+    fn describe(self) -> &'static str {
+        match self {
+            PlaceholderStyle::Dollar => "`$1`, `$2`, ..",
+            PlaceholderStyle::QuestionMark => "`?`",
+        }
+    }
+}
+
+// Returns [crate::Error::PlaceholderStyleMismatch] if `query` uses placeholders of another style
+// than `expected`; string literals, quoted identifiers and comments are skipped.
+//
+// For Postgres, where `?` is also an operator on `jsonb`, a `?` is only taken as a placeholder
+// if the query has no `$N` placeholder.
+pub(crate) fn check(query: &str, expected: PlaceholderStyle) -> crate::Result<()> {
+    let found = match expected {
+        PlaceholderStyle::Dollar => {
+            let mut dollar = false;
+            let mut question_mark = false;
+
+            scan(query, expected, |token| match token {
+                Token::Dollar => dollar = true,
+                Token::QuestionMark => question_mark = true,
+                Token::Word(_) => {}
+            });
+
+            question_mark && !dollar
+        }
+
+        PlaceholderStyle::QuestionMark => {
+            let mut dollar = false;
+
+            scan(query, expected, |token| {
+                dollar |= token == Token::Dollar;
+            });
+
+            dollar
+        }
+    };
+
+    if found {
+        Err(crate::Error::PlaceholderStyleMismatch(expected.describe()))
+    } else {
+        Ok(())
+    }
+}
+
+// Returns `true` if `query` has `keyword` as a whole word, ignoring ASCII case
+#[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+pub(crate) fn has_keyword(query: &str, style: PlaceholderStyle, keyword: &str) -> bool {
+    let mut found = false;
+
+    scan(query, style, |token| {
+        if let Token::Word(word) = token {
+            found |= word.eq_ignore_ascii_case(keyword);
+        }
+    });
+
+    found
+}
+
+#[derive(Debug, PartialEq)]
+enum Token<'q> {
+    Dollar,
+    QuestionMark,
+
+    // a keyword or identifier, e.g. `JOIN` or `joined_at`
+    Word(&'q str),
+}
+
+fn scan<'q>(query: &'q str, style: PlaceholderStyle, mut on_token: impl FnMut(Token<'q>)) {
+    let mysql = style == PlaceholderStyle::QuestionMark;
+    let bytes = query.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ b'\'' | quote @ b'"' | quote @ b'`' => {
+                // backslash escapes are only recognized by MySQL (and `E''` strings in Postgres
+                // which cannot contain a lone quote without them)
+                let escapes = mysql || (quote == b'\'' && i > 0 && bytes[i - 1] | 0x20 == b'e');
+
+                i += 1;
+
+                while i < bytes.len() {
+                    if escapes && bytes[i] == b'\\' {
+                        i += 2;
+                    } else if bytes[i] == quote {
+                        // a doubled quote is an escaped quote
+                        if bytes.get(i + 1) == Some(&quote) {
+                            i += 2;
+                        } else {
+                            break;
+                        }
+                    } else {
+                        i += 1;
+                    }
+                }
+            }
+
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                i = skip_line(bytes, i);
+            }
+
+            b'#' if mysql => {
+                i = skip_line(bytes, i);
+            }
+
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = match query[i + 2..].find("*/") {
+                    Some(end) => i + 2 + end + 1,
+                    None => bytes.len(),
+                };
+            }
+
+            b'?' => on_token(Token::QuestionMark),
+
+            b if b != b'$' && is_identifier(b) => {
+                // an identifier can contain, but not start with, a `$`
+                let end = bytes[i..]
+                    .iter()
+                    .position(|&b| !is_identifier(b))
+                    .map_or(bytes.len(), |len| i + len);
+
+                on_token(Token::Word(&query[i..end]));
+
+                i = end - 1;
+            }
+
+            b'$' if i > 0 && is_identifier(bytes[i - 1]) => {
+                // part of an identifier, e.g. `a$1`
+            }
+
+            b'$' if bytes.get(i + 1).is_some_and(u8::is_ascii_digit) => on_token(Token::Dollar),
+
+            b'$' if !mysql => {
+                // a dollar-quoted string, e.g. `$$text$$` or `$tag$text$tag$`
+                let tag_len = bytes[i + 1..]
+                    .iter()
+                    .position(|&b| !is_identifier(b) || b == b'$')
+                    .map(|len| len + 2);
+
+                if let Some(tag_len) = tag_len {
+                    if bytes.get(i + tag_len - 1) == Some(&b'$') {
+                        let tag = &query[i..i + tag_len];
+
+                        i = match query[i + tag_len..].find(tag) {
+                            Some(end) => i + tag_len + end + tag_len - 1,
+                            None => bytes.len(),
+                        };
+                    }
+                }
+            }
+
+            _ => {}
+        }
+
+        i += 1;
+    }
+}
+
+fn skip_line(bytes: &[u8], i: usize) -> usize {
+    bytes[i..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(bytes.len(), |end| i + end)
+}
+
+fn is_identifier(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$' || b >= 0x80
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, has_keyword, PlaceholderStyle};
+
+    fn is_mismatch(query: &str, expected: PlaceholderStyle) -> bool {
+        match check(query, expected) {
+            Err(crate::Error::PlaceholderStyleMismatch(_)) => true,
+            Err(err) => panic!("unexpected error: {}", err),
+            Ok(()) => false,
+        }
+    }
+
+    #[test]
+    fn it_rejects_question_marks_for_postgres() {
+        let postgres = |query| is_mismatch(query, PlaceholderStyle::Dollar);
+
+        assert!(postgres("SELECT * FROM users WHERE id = ?"));
+        assert!(!postgres("SELECT * FROM users WHERE id = $1"));
+
+        // `jsonb` operators
+        assert!(!postgres("SELECT data ? 'key', data ?| $1 FROM users"));
+
+        assert!(!postgres(
+            "SELECT '?', \"?\", $$?$$, $tag$ ? $tag$ -- ?\n /* ? */"
+        ));
+        assert!(!postgres("SELECT 'it''s ?'"));
+        assert!(postgres("SELECT '\\', ?"));
+        assert!(!postgres("SELECT E'\\' ?'"));
+    }
+
+    #[test]
+    fn it_rejects_dollars_for_mysql() {
+        let mysql = |query| is_mismatch(query, PlaceholderStyle::QuestionMark);
+
+        assert!(mysql("SELECT * FROM users WHERE id = $1"));
+        assert!(!mysql("SELECT * FROM users WHERE id = ?"));
+
+        assert!(!mysql("SELECT '$1', \"$1\", `$1` # $1\n -- $1\n /* $1 */"));
+        assert!(!mysql("SELECT 'it\\'s $1'"));
+        assert!(!mysql("SELECT a$1 FROM t$2"));
+    }
+
+    #[test]
+    fn it_finds_whole_keywords() {
+        let has_join = |query| has_keyword(query, PlaceholderStyle::Dollar, "JOIN");
+
+        assert!(has_join("SELECT * FROM a LEFT JOIN b ON b.id = a.id"));
+        assert!(has_join("SELECT * FROM a\njoin b USING (id)"));
+
+        assert!(!has_join("SELECT joined_at, rejoin, join$1 FROM users"));
+        assert!(!has_join(
+            "SELECT 'join', \"join\", $$ join $$ -- join\n /* join */"
+        ));
+    }
+}
@@ -1,7 +1,12 @@
 use futures_core::{future::BoxFuture, stream::BoxStream};
 use futures_util::StreamExt;
 
-use crate::{describe::Describe, executor::Executor, pool::Pool, Database};
+use crate::{
+    describe::Describe,
+    executor::{CommandTag, Executor},
+    pool::Pool,
+    Database,
+};
 
 impl<DB> Executor for Pool<DB>
 where
@@ -9,7 +14,10 @@ where
 {
     type Database = DB;
 
-    fn send<'e, 'q: 'e>(&'e mut self, commands: &'q str) -> BoxFuture<'e, crate::Result<()>> {
+    fn send<'e, 'q: 'e>(
+        &'e mut self,
+        commands: &'q str,
+    ) -> BoxFuture<'e, crate::Result<Vec<CommandTag>>> {
         Box::pin(async move { <&Pool<DB> as Executor>::send(&mut &*self, commands).await })
     }
 
@@ -21,6 +29,16 @@ where
         Box::pin(async move { <&Pool<DB> as Executor>::execute(&mut &*self, query, args).await })
     }
 
+    fn execute_uncached<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: DB::Arguments,
+    ) -> BoxFuture<'e, crate::Result<u64>> {
+        Box::pin(async move {
+            <&Pool<DB> as Executor>::execute_uncached(&mut &*self, query, args).await
+        })
+    }
+
     fn fetch<'e, 'q: 'e>(
         &'e mut self,
         query: &'q str,
@@ -36,6 +54,38 @@ where
         })
     }
 
+    fn fetch_paged<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: DB::Arguments,
+        fetch_size: u32,
+    ) -> BoxStream<'e, crate::Result<DB::Row>> {
+        Box::pin(async_stream::try_stream! {
+            let mut self_ = &*self;
+            let mut s = <&Pool<DB> as Executor>::fetch_paged(&mut self_, query, args, fetch_size);
+
+            while let Some(row) = s.next().await.transpose()? {
+                yield row;
+            }
+        })
+    }
+
+    fn fetch_uncached<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: DB::Arguments,
+        fetch_size: Option<u32>,
+    ) -> BoxStream<'e, crate::Result<DB::Row>> {
+        Box::pin(async_stream::try_stream! {
+            let mut self_ = &*self;
+            let mut s = <&Pool<DB> as Executor>::fetch_uncached(&mut self_, query, args, fetch_size);
+
+            while let Some(row) = s.next().await.transpose()? {
+                yield row;
+            }
+        })
+    }
+
     fn fetch_optional<'e, 'q: 'e>(
         &'e mut self,
         query: &'q str,
@@ -60,7 +110,10 @@ where
 {
     type Database = DB;
 
-    fn send<'e, 'q: 'e>(&'e mut self, commands: &'q str) -> BoxFuture<'e, crate::Result<()>> {
+    fn send<'e, 'q: 'e>(
+        &'e mut self,
+        commands: &'q str,
+    ) -> BoxFuture<'e, crate::Result<Vec<CommandTag>>> {
         Box::pin(async move { self.acquire().await?.send(commands).await })
     }
 
@@ -72,6 +125,14 @@ where
         Box::pin(async move { self.acquire().await?.execute(query, args).await })
     }
 
+    fn execute_uncached<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: DB::Arguments,
+    ) -> BoxFuture<'e, crate::Result<u64>> {
+        Box::pin(async move { self.acquire().await?.execute_uncached(query, args).await })
+    }
+
     fn fetch<'e, 'q: 'e>(
         &'e mut self,
         query: &'q str,
@@ -87,6 +148,38 @@ where
         })
     }
 
+    fn fetch_paged<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: DB::Arguments,
+        fetch_size: u32,
+    ) -> BoxStream<'e, crate::Result<DB::Row>> {
+        Box::pin(async_stream::try_stream! {
+            let mut live = self.acquire().await?;
+            let mut s = live.fetch_paged(query, args, fetch_size);
+
+            while let Some(row) = s.next().await.transpose()? {
+                yield row;
+            }
+        })
+    }
+
+    fn fetch_uncached<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: DB::Arguments,
+        fetch_size: Option<u32>,
+    ) -> BoxStream<'e, crate::Result<DB::Row>> {
+        Box::pin(async_stream::try_stream! {
+            let mut live = self.acquire().await?;
+            let mut s = live.fetch_uncached(query, args, fetch_size);
+
+            while let Some(row) = s.next().await.transpose()? {
+                yield row;
+            }
+        })
+    }
+
     fn fetch_optional<'e, 'q: 'e>(
         &'e mut self,
         query: &'q str,
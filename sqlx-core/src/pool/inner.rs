@@ -1,29 +1,35 @@
 use std::{
     cmp,
+    collections::VecDeque,
     sync::{
         atomic::{AtomicBool, AtomicU32, Ordering},
-        Arc,
+        Arc, Mutex,
     },
     time::Instant,
 };
 
-use futures_intrusive::channel::shared::{channel, Receiver, Sender};
-use futures_util::future::FutureExt;
-use tokio::{task, time::timeout};
+use futures_intrusive::sync::Semaphore;
+use futures_util::future::{self, Either};
+use tokio::{sync::Notify, task, time::timeout};
 
 use crate::{error::Error, Connection, Database};
 
-use super::{Idle, Options, Raw};
+use super::{Idle, Options, Raw, ReusePolicy};
 
 pub(super) struct SharedPool<DB>
 where
     DB: Database,
 {
     url: String,
-    pool_rx: Receiver<Idle<DB>>,
+    // connections waiting to be acquired, in the order they were released
+    idle: Mutex<VecDeque<Idle<DB>>>,
+    // one permit for every connection in `idle`
+    idle_permits: Semaphore,
     size: AtomicU32,
+    // notified when `size` drops, so a task waiting for a connection can open a new one
+    size_decreased: Notify,
     closed: AtomicBool,
-    options: Options,
+    options: Options<DB>,
 }
 
 impl<DB> SharedPool<DB>
@@ -31,39 +37,60 @@ where
     DB: Database,
     DB::Connection: Connection<Database = DB>,
 {
-    pub(super) async fn new_arc(
-        url: &str,
-        options: Options,
-    ) -> crate::Result<(Arc<Self>, Sender<Idle<DB>>)> {
-        let (pool_tx, pool_rx) = channel(options.max_size as usize);
-
+    pub(super) async fn new_arc(url: &str, options: Options<DB>) -> crate::Result<Arc<Self>> {
         let pool = Arc::new(Self {
             url: url.to_owned(),
-            pool_rx,
+            idle: Mutex::new(VecDeque::with_capacity(options.max_size as usize)),
+            idle_permits: Semaphore::new(true, 0),
             size: AtomicU32::new(0),
+            size_decreased: Notify::new(),
             closed: AtomicBool::new(false),
             options,
         });
 
         for _ in 0..pool.options.min_size {
+            pool.size.fetch_add(1, Ordering::AcqRel);
+
             let raw = pool
                 .new_conn(Instant::now() + pool.options.connect_timeout)
                 .await?;
 
-            let _ = pool_tx
-                .send(Idle {
-                    raw,
-                    since: Instant::now(),
-                })
-                .await;
+            pool.release(raw);
+        }
+
+        conn_reaper(&pool);
+
+        Ok(pool)
+    }
+
+    // return a checked out connection to the pool
+    pub(super) fn release(&self, raw: Raw<DB>) {
+        self.idle.lock().unwrap().push_back(Idle {
+            raw,
+            since: Instant::now(),
+        });
+
+        self.idle_permits.release(1);
+    }
+
+    // take the next idle connection; a permit must have been acquired for it
+    fn pop_idle(&self) -> Idle<DB> {
+        let mut idle = self.idle.lock().unwrap();
+
+        match self.options.reuse_policy {
+            ReusePolicy::Fifo => idle.pop_front(),
+            ReusePolicy::Lifo => idle.pop_back(),
         }
+        .expect("(bug) idle permit acquired without an idle connection")
+    }
 
-        conn_reaper(&pool, &pool_tx);
+    fn try_pop_idle(&self) -> Option<Idle<DB>> {
+        self.idle_permits.try_acquire(1)?.disarm();
 
-        Ok((pool, pool_tx))
+        Some(self.pop_idle())
     }
 
-    pub fn options(&self) -> &Options {
+    pub fn options(&self) -> &Options<DB> {
         &self.options
     }
 
@@ -71,6 +98,30 @@ where
         self.size.load(Ordering::Acquire)
     }
 
+    // a connection was closed, or failed to open, so it will not be returned to the pool
+    pub(super) fn forget(&self) {
+        self.size.fetch_sub(1, Ordering::AcqRel);
+        self.size_decreased.notify();
+    }
+
+    // wait for a connection to be released, or for one to be forgotten so there is room for a
+    // new one, which returns `None`
+    async fn wait_for_idle(&self) -> Option<Idle<DB>> {
+        let permit = self.idle_permits.acquire(1);
+        let size_decreased = self.size_decreased.notified();
+
+        futures_util::pin_mut!(permit, size_decreased);
+
+        match future::select(permit, size_decreased).await {
+            Either::Left((mut permit, _)) => {
+                permit.disarm();
+                Some(self.pop_idle())
+            }
+
+            Either::Right(_) => None,
+        }
+    }
+
     pub(super) async fn close(&self) {
         self.closed.store(true, Ordering::Release);
 
@@ -78,14 +129,10 @@ where
             // don't block on the receiver because we own one Sender so it should never return
             // `None`; a `select!()` would also work but that produces more complicated code
             // and a timeout isn't necessarily appropriate
-            match self.pool_rx.receive().now_or_never() {
-                Some(Some(idle)) => {
+            match self.try_pop_idle() {
+                Some(idle) => {
                     idle.close().await;
-                    self.size.fetch_sub(1, Ordering::AcqRel);
-                }
-                Some(None) => {
-                    log::warn!("was not able to close all connections");
-                    break;
+                    self.forget();
                 }
                 None => task::yield_now().await,
             }
@@ -98,7 +145,7 @@ where
             return None;
         }
 
-        Some(self.pool_rx.receive().now_or_never()??.raw)
+        Some(self.try_pop_idle()?.raw)
     }
 
     pub(super) async fn acquire(&self) -> crate::Result<Raw<DB>> {
@@ -122,16 +169,15 @@ where
                     .ok_or(Error::PoolTimedOut)?;
 
                 // don't sleep forever
-                let mut idle = match timeout(max_wait, self.pool_rx.receive()).await {
+                let mut idle = match timeout(max_wait, self.wait_for_idle()).await {
                     Ok(Some(idle)) => idle,
-                    Ok(None) => panic!("this isn't possible, we own a `pool_tx`"),
-                    // try our acquire logic again
-                    Err(_) => continue,
+                    // try our acquire logic again, to open a new connection or time out
+                    Ok(None) | Err(_) => continue,
                 };
 
                 if self.closed.load(Ordering::Acquire) {
                     idle.close().await;
-                    self.size.fetch_sub(1, Ordering::AcqRel);
+                    self.forget();
                     return Err(Error::PoolClosed);
                 }
 
@@ -167,17 +213,32 @@ where
     async fn new_conn(&self, deadline: Instant) -> crate::Result<Raw<DB>> {
         while Instant::now() < deadline {
             if self.closed.load(Ordering::Acquire) {
-                self.size.fetch_sub(1, Ordering::AcqRel);
+                self.forget();
                 return Err(Error::PoolClosed);
             }
 
             // result here is `Result<Result<DB, Error>, TimeoutError>`
             match timeout(deadline - Instant::now(), DB::Connection::open(&self.url)).await {
-                Ok(Ok(inner)) => {
+                Ok(Ok(mut inner)) => {
+                    // the callback counts towards the timeout of opening the connection
+                    if let Some(after_connect) = &self.options.after_connect {
+                        let max_wait = deadline.saturating_duration_since(Instant::now());
+
+                        let res = timeout(max_wait, after_connect(&mut inner))
+                            .await
+                            .unwrap_or(Err(Error::PoolTimedOut));
+
+                        if let Err(e) = res {
+                            let _ = inner.close().await;
+                            self.forget();
+                            return Err(e);
+                        }
+                    }
+
                     return Ok(Raw {
                         inner,
                         created: Instant::now(),
-                    })
+                    });
                 }
                 // error while connecting, this should definitely be logged
                 Ok(Err(e)) => log::warn!("error establishing a connection: {}", e),
@@ -186,7 +247,7 @@ where
             }
         }
 
-        self.size.fetch_sub(1, Ordering::AcqRel);
+        self.forget();
         Err(Error::PoolTimedOut)
     }
 }
@@ -200,15 +261,15 @@ where
     }
 }
 
-fn should_reap<DB: Database>(idle: &Idle<DB>, options: &Options) -> bool {
-    // check if idle connection was within max lifetime (or not set)
-    options.max_lifetime.map_or(true, |max| idle.raw.created.elapsed() < max)
-        // and if connection wasn't idle too long (or not set)
-        && options.idle_timeout.map_or(true, |timeout| idle.since.elapsed() < timeout)
+fn should_reap<DB: Database>(idle: &Idle<DB>, options: &Options<DB>) -> bool {
+    // check if idle connection outlived its max lifetime (if set)
+    options.max_lifetime.map_or(false, |max| idle.raw.created.elapsed() >= max)
+        // or if connection was idle too long (if set)
+        || options.idle_timeout.map_or(false, |timeout| idle.since.elapsed() >= timeout)
 }
 
 /// if `max_lifetime` or `idle_timeout` is set, spawn a task that reaps senescent connections
-fn conn_reaper<DB: Database>(pool: &Arc<SharedPool<DB>>, pool_tx: &Sender<Idle<DB>>)
+fn conn_reaper<DB: Database>(pool: &Arc<SharedPool<DB>>)
 where
     DB::Connection: Connection<Database = DB>,
 {
@@ -221,7 +282,6 @@ where
     };
 
     let pool = pool.clone();
-    let pool_tx = pool_tx.clone();
 
     task::spawn(async move {
         while !pool.closed.load(Ordering::Acquire) {
@@ -229,22 +289,40 @@ where
             let max_reaped = pool
                 .size
                 .load(Ordering::Acquire)
-                .saturating_sub(pool.options.min_size);
+                .saturating_sub(pool.options.min_size) as usize;
+
+            // only connections waiting in the queue; holding their permits keeps them from
+            // being acquired while we look through them
+            let held = cmp::min(max_reaped, pool.idle_permits.permits());
+
+            let reap = match pool.idle_permits.try_acquire(held) {
+                Some(mut permits) => {
+                    permits.disarm();
+
+                    let mut idle = pool.idle.lock().unwrap();
+                    let mut reap = Vec::new();
+                    let mut i = 0;
+
+                    while reap.len() < held && i < idle.len() {
+                        if should_reap(&idle[i], &pool.options) {
+                            reap.extend(idle.remove(i));
+                        } else {
+                            i += 1;
+                        }
+                    }
 
-            // collect connections to reap
-            let (reap, keep) = (0..max_reaped)
-                // only connections waiting in the queue
-                .filter_map(|_| pool.pool_rx.receive().now_or_never()?)
-                .partition::<Vec<_>, _>(|conn| should_reap(conn, &pool.options));
+                    // the permits of the connections that are kept
+                    pool.idle_permits.release(held - reap.len());
 
-            for conn in keep {
-                // return these connections to the pool first
-                let _ = pool_tx.send(conn).await;
-            }
+                    reap
+                }
+
+                None => Vec::new(),
+            };
 
             for conn in reap {
                 conn.close().await;
-                pool.size.fetch_sub(1, Ordering::AcqRel);
+                pool.forget();
             }
 
             tokio::time::delay_for(period).await;
@@ -6,14 +6,12 @@ use std::{
     time::{Duration, Instant},
 };
 
-use futures_intrusive::channel::shared::Sender;
-use tokio::task::LocalSet;
-
-use crate::Database;
+use crate::connection::internal::ConnectionState;
+use crate::{Connection, Database};
 
 use self::inner::SharedPool;
-pub use self::options::Builder;
 use self::options::Options;
+pub use self::options::{Builder, ReusePolicy};
 
 mod executor;
 mod inner;
@@ -25,12 +23,16 @@ where
     DB: Database,
 {
     inner: Arc<SharedPool<DB>>,
-    pool_tx: Sender<Idle<DB>>,
 }
 
-struct Connection<DB: Database> {
+/// A connection checked out from a [Pool].
+///
+/// Holds a reference to the pool it came from, so it is `'static` and may be moved into
+/// spawned tasks. The connection is returned to the pool when this is dropped.
+pub struct PoolConnection<DB: Database> {
     raw: Option<Raw<DB>>,
-    pool_tx: Sender<Idle<DB>>,
+    // keeps the shared pool alive while this connection is checked out
+    pool: Arc<SharedPool<DB>>,
 }
 
 struct Raw<DB: Database> {
@@ -53,10 +55,10 @@ where
         Self::builder().build(url).await
     }
 
-    async fn with_options(url: &str, options: Options) -> crate::Result<Self> {
-        let (inner, pool_tx) = SharedPool::new_arc(url, options).await?;
+    async fn with_options(url: &str, options: Options<DB>) -> crate::Result<Self> {
+        let inner = SharedPool::new_arc(url, options).await?;
 
-        Ok(Pool { inner, pool_tx })
+        Ok(Pool { inner })
     }
 
     /// Returns a [Builder] to configure a new connection pool.
@@ -68,9 +70,16 @@ where
     ///
     /// Waits for at most the configured connection timeout before returning an error.
     pub async fn acquire(&self) -> crate::Result<impl DerefMut<Target = DB::Connection>> {
-        self.inner.acquire().await.map(|conn| Connection {
+        self.acquire_owned().await
+    }
+
+    /// Retrieves a connection from the pool that is not tied to the lifetime of the [Pool].
+    ///
+    /// Waits for at most the configured connection timeout before returning an error.
+    pub async fn acquire_owned(&self) -> crate::Result<PoolConnection<DB>> {
+        self.inner.acquire().await.map(|conn| PoolConnection {
             raw: Some(conn),
-            pool_tx: self.pool_tx.clone(),
+            pool: Arc::clone(&self.inner),
         })
     }
 
@@ -78,9 +87,9 @@ where
     ///
     /// Returns `None` immediately if there are no idle connections available in the pool.
     pub fn try_acquire(&self) -> Option<impl DerefMut<Target = DB::Connection>> {
-        self.inner.try_acquire().map(|conn| Connection {
+        self.inner.try_acquire().map(|conn| PoolConnection {
             raw: Some(conn),
-            pool_tx: self.pool_tx.clone(),
+            pool: Arc::clone(&self.inner),
         })
     }
 
@@ -121,6 +130,11 @@ where
     pub fn idle_timeout(&self) -> Option<Duration> {
         self.inner.options().idle_timeout
     }
+
+    /// Returns the configured order in which idle connections are reused.
+    pub fn reuse_policy(&self) -> ReusePolicy {
+        self.inner.options().reuse_policy
+    }
 }
 
 /// Returns a new [Pool] tied to the same shared connection pool.
@@ -131,14 +145,13 @@ where
     fn clone(&self) -> Self {
         Self {
             inner: Arc::clone(&self.inner),
-            pool_tx: self.pool_tx.clone(),
         }
     }
 }
 
 const DEREF_ERR: &str = "(bug) connection already released to pool";
 
-impl<DB: Database> Deref for Connection<DB> {
+impl<DB: Database> Deref for PoolConnection<DB> {
     type Target = DB::Connection;
 
     fn deref(&self) -> &Self::Target {
@@ -146,19 +159,24 @@ impl<DB: Database> Deref for Connection<DB> {
     }
 }
 
-impl<DB: Database> DerefMut for Connection<DB> {
+impl<DB: Database> DerefMut for PoolConnection<DB> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.raw.as_mut().expect(DEREF_ERR).inner
     }
 }
 
-impl<DB: Database> Drop for Connection<DB> {
+impl<DB: Database> Drop for PoolConnection<DB> {
     fn drop(&mut self) {
-        if let Some(conn) = self.raw.take() {
-            let _ = LocalSet::new().run_until(self.pool_tx.send(Idle {
-                raw: conn,
-                since: Instant::now(),
-            }));
+        if let Some(mut conn) = self.raw.take() {
+            if conn.inner.is_closed() {
+                self.pool.forget();
+                return;
+            }
+
+            conn.inner.reset();
+            conn.inner.shrink_buffers();
+
+            self.pool.release(conn);
         }
     }
 }
@@ -1,4 +1,6 @@
-use std::{marker::PhantomData, time::Duration};
+use std::time::Duration;
+
+use futures_core::future::BoxFuture;
 
 use crate::Database;
 
@@ -9,8 +11,7 @@ pub struct Builder<DB>
 where
     DB: Database,
 {
-    phantom: PhantomData<DB>,
-    options: Options,
+    options: Options<DB>,
 }
 
 impl<DB> Builder<DB>
@@ -22,7 +23,6 @@ where
     /// See the source of this method for current defaults.
     pub fn new() -> Self {
         Self {
-            phantom: PhantomData,
             options: Options {
                 // pool a maximum of 10 connections to the same database
                 max_size: 10,
@@ -36,6 +36,10 @@ where
                 max_lifetime: Some(Duration::from_secs(1800)),
                 // don't reap connections based on idle time
                 idle_timeout: None,
+                // keep a small set of connections warm
+                reuse_policy: ReusePolicy::Lifo,
+                // no session setup
+                after_connect: None,
             },
         }
     }
@@ -92,6 +96,42 @@ where
         self
     }
 
+    /// Set the order in which idle connections are handed out by [Pool::acquire].
+    ///
+    /// Defaults to [ReusePolicy::Lifo].
+    pub fn reuse_policy(mut self, reuse_policy: ReusePolicy) -> Self {
+        self.options.reuse_policy = reuse_policy;
+        self
+    }
+
+    /// Set a callback that is run on every new connection before it is first used.
+    ///
+    /// This is also run on the connections that are opened to replace closed or reaped ones, so
+    /// it is the place for session state like `SET ROLE` or other per-connection settings. An
+    /// error returned from the callback closes the connection and is returned by [Pool::acquire].
+    /// The callback must finish within [connect_timeout](Builder::connect_timeout) together with
+    /// opening the connection, or the pool times out.
+    ///
+    /// ```rust,ignore
+    /// let pool = PgPool::builder()
+    ///     .after_connect(|conn| Box::pin(async move {
+    ///         conn.send("SET ROLE tenant").await?;
+    ///         Ok(())
+    ///     }))
+    ///     .build(&url)
+    ///     .await?;
+    /// ```
+    pub fn after_connect<F>(mut self, callback: F) -> Self
+    where
+        F: for<'c> Fn(&'c mut DB::Connection) -> BoxFuture<'c, crate::Result<()>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.options.after_connect = Some(Box::new(callback));
+        self
+    }
+
     /// Spin up the connection pool.
     ///
     /// If [min_size] was set to a non-zero value, that many connections will be immediately
@@ -110,10 +150,31 @@ where
     }
 }
 
-pub(crate) struct Options {
+type AfterConnect<DB> = dyn for<'c> Fn(&'c mut <DB as Database>::Connection) -> BoxFuture<'c, crate::Result<()>>
+    + Send
+    + Sync;
+
+pub(crate) struct Options<DB>
+where
+    DB: Database,
+{
     pub max_size: u32,
     pub connect_timeout: Duration,
     pub min_size: u32,
     pub max_lifetime: Option<Duration>,
     pub idle_timeout: Option<Duration>,
+    pub reuse_policy: ReusePolicy,
+    pub after_connect: Option<Box<AfterConnect<DB>>>,
+}
+
+/// The order in which idle connections are reused by a [Pool].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ReusePolicy {
+    /// Hand out the connection that has been idle the longest, spreading use evenly
+    /// over all connections.
+    Fifo,
+
+    /// Hand out the connection that was returned most recently, keeping a small set of
+    /// connections in use under bursty load; the others may then be reaped by [idle_timeout].
+    Lifo,
 }
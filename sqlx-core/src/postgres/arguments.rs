@@ -1,7 +1,9 @@
+use std::fmt::{self, Write};
+
 use byteorder::{ByteOrder, NetworkEndian};
 
-use crate::arguments::Arguments;
-use crate::encode::{Encode, IsNull};
+use crate::arguments::{internal, Arguments};
+use crate::encode::{DynEncode, Encode, EncodeError, IsNull};
 use crate::io::BufMut;
 use crate::types::HasSqlType;
 use crate::Postgres;
@@ -13,8 +15,13 @@ pub struct PgArguments {
 
     // Write buffer for serializing bind values
     pub(super) values: Vec<u8>,
+
+    // The error of the first value that could not be encoded, returned when the query is run
+    pub(super) error: Option<EncodeError>,
 }
 
+impl internal::Sealed for PgArguments {}
+
 impl Arguments for PgArguments {
     type Database = super::Postgres;
 
@@ -36,15 +43,37 @@ impl Arguments for PgArguments {
         Self::Database: HasSqlType<T>,
         T: Encode<Self::Database>,
     {
+        self.push(<Postgres as HasSqlType<T>>::metadata().oid, &value);
+    }
+
+    fn add_dyn(&mut self, value: &dyn DynEncode<Self::Database>) {
+        self.push(value.type_metadata().oid, value);
+    }
+
+    fn format_placeholder<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        write!(writer, "${}", self.types.len() + 1)
+    }
+}
+
+impl PgArguments {
+    fn push<T: Encode<Postgres> + ?Sized>(&mut self, oid: u32, value: &T) {
         // TODO: When/if we receive types that do _not_ support BINARY, we need to check here
         // TODO: There is no need to be explicit unless we are expecting mixed BINARY / TEXT
 
-        self.types.push(<Postgres as HasSqlType<T>>::metadata().oid);
+        self.types.push(oid);
 
         let pos = self.values.len();
 
         self.values.put_i32::<NetworkEndian>(0);
 
+        if let Err(err) = value.check() {
+            self.error.get_or_insert(err);
+
+            // the query is not sent, so the value is written as NULL only to keep the rest in place
+            NetworkEndian::write_i32(&mut self.values[pos..], -1);
+            return;
+        }
+
         let len = if let IsNull::No = value.encode_nullable(&mut self.values) {
             (self.values.len() - pos - 4) as i32
         } else {
@@ -57,4 +86,19 @@ impl Arguments for PgArguments {
         // Write-back the len to the beginning of this frame (not including the len of len)
         NetworkEndian::write_i32(&mut self.values[pos..], len as i32);
     }
+
+    // adds a value that is already encoded in the binary format, or `None` for NULL
+    #[cfg(feature = "any")]
+    pub(crate) fn push_raw(&mut self, oid: u32, value: Option<&[u8]>) {
+        self.types.push(oid);
+
+        match value {
+            Some(value) => {
+                self.values.put_i32::<NetworkEndian>(value.len() as i32);
+                self.values.extend_from_slice(value);
+            }
+
+            None => self.values.put_i32::<NetworkEndian>(-1),
+        }
+    }
 }
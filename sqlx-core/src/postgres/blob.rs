@@ -0,0 +1,112 @@
+use std::convert::TryFrom;
+
+use bytes::Bytes;
+use futures_core::stream::BoxStream;
+
+use crate::arguments::{Arguments, IntoArguments};
+use crate::executor::Executor;
+use crate::postgres::{PgArguments, PgConnection, Postgres};
+use crate::row::Row;
+
+impl PgConnection {
+    /// Read the `bytea` value selected by `query` in chunks of at most `chunk_size` bytes,
+    /// instead of decoding all of it into one `Vec<u8>`.
+    ///
+    /// `query` must select one row with the value in its first column. It is run with `args`
+    /// once for every chunk, of which only the chunk is sent by the server with `substring`.
+    /// Run it in a transaction with the `REPEATABLE READ` isolation level for every chunk to
+    /// be read from the same version of the value.
+    ///
+    /// The server only reads the part of a value it needs for each chunk if the value is not
+    /// compressed, e.g. for a column with `SET STORAGE EXTERNAL`.
+    ///
+    /// Returns [crate::Error::NotFound] if `query` returns no rows.
+    ///
+    /// ```ignore
+    /// let mut data = conn.read_bytea("SELECT data FROM files WHERE id = $1", (id,), 1 << 20);
+    ///
+    /// while let Some(chunk) = data.try_next().await? {
+    ///     file.write_all(&chunk).await?;
+    /// }
+    /// ```
+    pub fn read_bytea<'e, A>(
+        &'e mut self,
+        query: &str,
+        args: A,
+        chunk_size: usize,
+    ) -> BoxStream<'e, crate::Result<Bytes>>
+    where
+        A: IntoArguments<Postgres>,
+    {
+        let args = args.into_arguments();
+
+        // the offset and length of the chunk follow the arguments of `query`
+        let query = format!(
+            "SELECT substring(value FROM ${}::int4 FOR ${}) FROM ({}) AS chunked (value)",
+            args.len() + 1,
+            args.len() + 2,
+            query
+        );
+
+        // `substring` counts from 1
+        self.read_chunks(query, args, 1, chunk_size)
+    }
+
+    /// Read the large object `oid` in chunks of at most `chunk_size` bytes, with `lo_get`.
+    ///
+    /// Run it in a transaction with the `REPEATABLE READ` isolation level for every chunk to
+    /// be read from the same version of the object.
+    pub fn read_large_object(
+        &mut self,
+        oid: u32,
+        chunk_size: usize,
+    ) -> BoxStream<'_, crate::Result<Bytes>> {
+        let mut args = PgArguments::default();
+        args.add(oid);
+
+        self.read_chunks("SELECT lo_get($1, $2, $3)".into(), args, 0, chunk_size)
+    }
+
+    // Runs `query` with `args` and the offset and length of each chunk, until the value ends
+    fn read_chunks(
+        &mut self,
+        query: String,
+        args: PgArguments,
+        mut offset: i64,
+        chunk_size: usize,
+    ) -> BoxStream<'_, crate::Result<Bytes>> {
+        let chunk_size = i32::try_from(chunk_size).unwrap_or(i32::MAX).max(1);
+
+        Box::pin(async_stream::try_stream! {
+            loop {
+                let mut chunk_args = PgArguments {
+                    types: args.types.clone(),
+                    values: args.values.clone(),
+                    error: args.error.clone(),
+                };
+
+                chunk_args.add(offset);
+                chunk_args.add(chunk_size);
+
+                let row = self
+                    .fetch_optional(&query, chunk_args)
+                    .await?
+                    .ok_or(crate::Error::NotFound)?;
+
+                let chunk: Vec<u8> = row.try_get(0)?;
+                let len = chunk.len();
+
+                if len > 0 {
+                    yield Bytes::from(chunk);
+                }
+
+                // the last chunk is the only one that is shorter
+                if len < chunk_size as usize {
+                    break;
+                }
+
+                offset += len as i64;
+            }
+        })
+    }
+}
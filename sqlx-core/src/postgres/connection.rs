@@ -1,24 +1,50 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::convert::TryInto;
-use std::net::Shutdown;
+use std::path::Path;
 
 use byteorder::NetworkEndian;
+use bytes::Bytes;
 use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 use tokio::net::TcpStream;
 
+use crate::arguments::Arguments;
 use crate::cache::StatementCache;
+use crate::connection::internal::ConnectionState;
 use crate::connection::Connection;
+use crate::executor::CommandTag;
 use crate::io::{Buf, BufStream};
 use crate::postgres::protocol::{self, Decode, Encode, Message, StatementId};
-use crate::postgres::PgError;
+use crate::postgres::{PgArguments, PgError, PgRow};
+use crate::rewrite::SqlRewriter;
 use crate::url::Url;
 
+// A bidirectional byte stream that the protocol can run over
+pub(super) trait AsyncStream: AsyncRead + AsyncWrite + Send + Unpin + 'static {}
+
+impl<S> AsyncStream for S where S: AsyncRead + AsyncWrite + Send + Unpin + 'static {}
+
 /// An asynchronous connection to a [Postgres] database.
 ///
 /// The connection string expected by [Connection::open] should be a PostgreSQL connection
 /// string, as documented at
 /// <https://www.postgresql.org/docs/12/libpq-connect.html#LIBPQ-CONNSTRING>
+///
+/// Of its parameters, `options` is passed on to the server, e.g.
+/// `postgres://localhost/app?options=-c%20statement_timeout%3D5000`. The `search_path`
+/// parameter sets the schema search path of the connection, e.g.
+/// `postgres://localhost/app?search_path=tenant1`. On Unix, a `host` parameter holding the
+/// directory of a Unix-domain socket connects through the socket of the server in it, e.g.
+/// `postgres://localhost/app?host=/var/run/postgresql`.
+///
+/// With `require_ssl_for_password=true`, opening the connection fails with
+/// [crate::Error::PasswordRequiresSsl] instead of sending the password (in cleartext or as an
+/// MD5 hash) if the server asks for it. TLS is not supported yet, so this only allows
+/// authentication methods that don't need a password, e.g. `trust` or `peer`.
 pub struct PgConnection {
-    pub(super) stream: BufStream<TcpStream>,
+    pub(super) stream: BufStream<Box<dyn AsyncStream>>,
 
     // Map of query to statement id
     pub(super) statement_cache: StatementCache<StatementId>,
@@ -34,6 +60,38 @@ pub struct PgConnection {
 
     // Is there a query in progress; are we ready to continue
     pub(super) ready: bool,
+
+    // Has the server been left without a Sync by a paged fetch; see [fetch_rows]
+    pub(super) needs_sync: bool,
+
+    // Was a transaction dropped before it was committed or rolled back
+    pub(super) pending_rollback: bool,
+
+    // Was a `COPY ... FROM STDIN` dropped before it was finished, so it is failed
+    pub(super) pending_copy_fail: bool,
+
+    // Run-time parameters most recently reported by the server
+    parameter_statuses: HashMap<String, String>,
+
+    // Applied to the SQL of queries before they are sent; see [set_sql_rewriter]
+    pub(super) sql_rewriter: SqlRewriter,
+
+    // Received on the channels the connection listens on; see [take_notifications]
+    notifications: Vec<PgNotification>,
+}
+
+/// A notification received on a channel a [PgConnection] listens on; see
+/// [PgConnection::take_notifications].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgNotification {
+    /// The process ID of the session that sent the notification.
+    pub process_id: u32,
+
+    /// The channel the notification was sent on.
+    pub channel: String,
+
+    /// The payload of the notification.
+    pub payload: String,
 }
 
 impl PgConnection {
@@ -43,9 +101,29 @@ impl PgConnection {
         let username = url.username().unwrap_or("postgres");
         let database = url.database().unwrap_or("postgres");
 
+        // TLS is not supported yet so no connection is ever encrypted
+        let password_allowed = !matches!(
+            url.param("require_ssl_for_password").as_deref(),
+            Some("true") | Some("1")
+        );
+
+        // Command-line options for the backend, e.g. `-c search_path=tenant1`
+        let mut options = url.param("options").map(Cow::into_owned);
+
+        if let Some(search_path) = url.param("search_path") {
+            // Spaces within an option are escaped with a backslash
+            let search_path = search_path.replace('\\', "\\\\").replace(' ', "\\ ");
+            let option = format!("-c search_path={}", search_path);
+
+            options = Some(match options {
+                Some(options) => format!("{} {}", options, option),
+                None => option,
+            });
+        }
+
         // See this doc for more runtime parameters
         // https://www.postgresql.org/docs/12/runtime-config-client.html
-        let params = &[
+        let mut params = vec![
             ("user", username),
             ("database", database),
             // Sets the display format for date and time values,
@@ -62,7 +140,11 @@ impl PgConnection {
             ("client_encoding", "UTF-8"),
         ];
 
-        protocol::StartupMessage { params }.encode(self.stream.buffer_mut());
+        if let Some(options) = &options {
+            params.push(("options", options));
+        }
+
+        protocol::StartupMessage { params: &params }.encode(self.stream.buffer_mut());
         self.stream.flush().await?;
 
         while let Some(message) = self.receive().await? {
@@ -73,6 +155,13 @@ impl PgConnection {
                             // Do nothing. No password is needed to continue.
                         }
 
+                        protocol::Authentication::ClearTextPassword
+                        | protocol::Authentication::Md5Password { .. }
+                            if !password_allowed =>
+                        {
+                            return Err(crate::Error::PasswordRequiresSsl);
+                        }
+
                         protocol::Authentication::ClearTextPassword => {
                             protocol::PasswordMessage::ClearText(
                                 url.password().unwrap_or_default(),
@@ -110,7 +199,7 @@ impl PgConnection {
 
                 Message::ReadyForQuery(_) => {
                     // Connection fully established and ready to receive queries.
-                    break;
+                    return Ok(());
                 }
 
                 message => {
@@ -119,7 +208,8 @@ impl PgConnection {
             }
         }
 
-        Ok(())
+        // Connection was (unexpectedly) closed
+        Err(crate::Error::connection_closed())
     }
 
     // https://www.postgresql.org/docs/devel/protocol-flow.html#id-1.10.5.7.10
@@ -127,7 +217,7 @@ impl PgConnection {
         protocol::Terminate.encode(self.stream.buffer_mut());
 
         self.stream.flush().await?;
-        self.stream.stream.shutdown(Shutdown::Both)?;
+        self.stream.stream.shutdown().await?;
 
         Ok(())
     }
@@ -141,9 +231,9 @@ impl PgConnection {
             let id = header.get_u8()?;
             let len = (header.get_u32::<NetworkEndian>()? - 4) as usize;
 
-            // Read the message body
-            self.stream.consume(5);
-            let body = ret_if_none!(self.stream.peek(len).await?);
+            // Read the message body; nothing is consumed until the whole message is buffered,
+            // so a receive that is cancelled can be resumed
+            let body = &ret_if_none!(self.stream.peek(5 + len).await?)[5..];
 
             let message = match id {
                 b'N' | b'E' => Message::Response(Box::new(protocol::Response::decode(body)?)),
@@ -167,17 +257,24 @@ impl PgConnection {
                     protocol::ParameterDescription::decode(body)?,
                 )),
                 b'T' => Message::RowDescription(Box::new(protocol::RowDescription::decode(body)?)),
+                b'G' => Message::CopyInResponse(Box::new(protocol::CopyInResponse::decode(body)?)),
+                b'H' => {
+                    Message::CopyOutResponse(Box::new(protocol::CopyOutResponse::decode(body)?))
+                }
+                b'd' => Message::CopyData(Bytes::copy_from_slice(body)),
+                b'c' => Message::CopyDone,
 
                 id => {
                     return Err(protocol_err!("received unknown message id: {:?}", id).into());
                 }
             };
 
-            self.stream.consume(len);
+            self.stream.consume(5 + len);
 
             match message {
-                Message::ParameterStatus(_body) => {
-                    // TODO: not sure what to do with these yet
+                Message::ParameterStatus(body) => {
+                    self.parameter_statuses
+                        .insert(body.name.into(), body.value.into());
                 }
 
                 Message::Response(body) => {
@@ -190,6 +287,16 @@ impl PgConnection {
                     }
                 }
 
+                // A connection that listens on a channel is notified between the messages of
+                // any query; the notifications are kept until they are taken
+                Message::NotificationResponse(body) => {
+                    self.notifications.push(PgNotification {
+                        process_id: body.pid,
+                        channel: body.channel_name,
+                        payload: body.message,
+                    });
+                }
+
                 message => {
                     return Ok(Some(message));
                 }
@@ -199,9 +306,139 @@ impl PgConnection {
 }
 
 impl PgConnection {
-    pub(super) async fn open(url: crate::Result<Url>) -> crate::Result<Self> {
+    pub(crate) async fn open(url: crate::Result<Url>) -> crate::Result<Self> {
         let url = url?;
+
+        // like libpq, a `host` starting with a slash is the directory of a Unix-domain socket
+        #[cfg(unix)]
+        {
+            if let Some(dir) = url.param("host").filter(|host| host.starts_with('/')) {
+                let path = format!("{}/.s.PGSQL.{}", dir.trim_end_matches('/'), url.port(5432));
+                let stream = tokio::net::UnixStream::connect(path).await?;
+
+                return Self::new(Box::new(stream), url).await;
+            }
+        }
+
         let stream = TcpStream::connect((url.host(), url.port(5432))).await?;
+
+        Self::new(Box::new(stream), url).await
+    }
+
+    /// Establish a new connection over an already connected `stream`.
+    ///
+    /// This allows the connection to be dialed by other means, such as through a SOCKS
+    /// proxy or an SSH tunnel. Only the credentials and the database of `url` are used;
+    /// its host and port are ignored.
+    pub async fn connect_stream<S, T>(stream: S, url: T) -> crate::Result<Self>
+    where
+        S: AsyncRead + AsyncWrite + Send + Unpin + 'static,
+        T: TryInto<Url, Error = crate::Error>,
+    {
+        Self::new(Box::new(stream), url.try_into()?).await
+    }
+
+    /// Returns the run-time parameters reported by the server, such as `TimeZone`,
+    /// `client_encoding` or `server_version`.
+    ///
+    /// These are sent during startup and again whenever one of them changes.
+    pub fn parameter_statuses(&self) -> &HashMap<String, String> {
+        &self.parameter_statuses
+    }
+
+    /// Sets a soft limit on the memory held by the buffer for incoming messages.
+    ///
+    /// The buffer grows to hold the largest message received, e.g. a row with a big value, and
+    /// is otherwise kept for the life of the connection. With a limit, the buffer is shrunk back
+    /// to its initial size of 8 KiB once a message larger than `max` bytes has been processed.
+    /// Use [crate::pool::Builder::after_connect] to set this on the connections of a pool.
+    pub fn set_read_buffer_max(&mut self, max: usize) {
+        self.stream.set_rbuf_max(max);
+    }
+
+    /// Sets a function that rewrites the SQL of every query run on this connection before it is
+    /// sent, e.g. to qualify tables with the schema of a tenant.
+    ///
+    /// It is applied to queries with and without bind parameters, to [send], to `COPY` and to
+    /// statements explicitly [prepared](PgConnection::prepare); the prepared statements of the
+    /// connection are cached by the rewritten SQL. It is cleared when the connection is returned
+    /// to a [Pool](crate::Pool). The queries of the query macros are checked at
+    /// compile-time before they are rewritten, so the rewritten SQL must keep the same
+    /// parameters and output columns.
+    ///
+    /// ```rust,ignore
+    /// conn.set_sql_rewriter(|sql| sql.replace("{schema}", "tenant_42").into());
+    /// ```
+    ///
+    /// [send]: crate::Executor::send
+    pub fn set_sql_rewriter<F>(&mut self, rewrite: F)
+    where
+        F: for<'a> Fn(&'a str) -> Cow<'a, str> + Send + Sync + 'static,
+    {
+        self.sql_rewriter.set(rewrite);
+    }
+
+    /// Runs the SQL commands in the file at `path` as one batch, e.g. for a migration.
+    ///
+    /// The file is read at run-time and sent with the simple query protocol, so it may contain
+    /// several statements separated by semicolons but no bind parameters. Unless the file
+    /// contains its own transaction control, the statements run in an implicit transaction that
+    /// is rolled back on the first error. Returns the total number of rows affected.
+    ///
+    /// A file that cannot be read is reported as [crate::Error::Io]; the
+    /// [position](crate::error::DatabaseError::position) of a database error is the character
+    /// offset of the error in the file.
+    pub async fn execute_file(&mut self, path: impl AsRef<Path>) -> crate::Result<u64> {
+        let commands = tokio::fs::read_to_string(path).await?;
+
+        Ok(crate::Executor::send(self, &commands)
+            .await?
+            .iter()
+            .map(CommandTag::rows_affected)
+            .sum())
+    }
+
+    /// Runs the SQL commands in `commands` with the simple query protocol and returns the rows
+    /// of all of them, e.g. to read the results of a batch of statements.
+    ///
+    /// The simple protocol takes no bind parameters and sends every value in the text format,
+    /// which is decoded with [Decode::decode_text](crate::decode::Decode::decode_text).
+    /// Rows of commands with different columns are returned one after the other.
+    pub fn fetch_simple<'e>(
+        &'e mut self,
+        commands: &'e str,
+    ) -> BoxStream<'e, crate::Result<PgRow>> {
+        let commands = self.sql_rewriter.rewrite(commands);
+
+        self.fetch_simple_rows(commands)
+    }
+
+    /// Sends a notification with `payload` to the sessions listening on `channel`, as with
+    /// `NOTIFY`.
+    ///
+    /// The channel and payload are bound as parameters of `pg_notify`, so they are never
+    /// interpreted as SQL. A notification sent in a transaction is only delivered once the
+    /// transaction is committed.
+    pub async fn notify(&mut self, channel: &str, payload: &str) -> crate::Result<()> {
+        let mut args = PgArguments::default();
+        args.add(channel);
+        args.add(payload);
+
+        crate::Executor::execute(self, "SELECT pg_notify($1, $2)", args).await?;
+
+        Ok(())
+    }
+
+    /// Takes the notifications received on the channels this connection listens on, with
+    /// `LISTEN`, since they were last taken, oldest first.
+    ///
+    /// Notifications are only received while the connection runs a query, so one that waits for
+    /// them has to run a query, e.g. [ping](Connection::ping), before they are taken.
+    pub fn take_notifications(&mut self) -> Vec<PgNotification> {
+        std::mem::take(&mut self.notifications)
+    }
+
+    async fn new(stream: Box<dyn AsyncStream>, url: Url) -> crate::Result<Self> {
         let mut self_ = Self {
             stream: BufStream::new(stream),
             process_id: 0,
@@ -210,6 +447,12 @@ impl PgConnection {
             next_statement_id: 1,
             statement_cache: StatementCache::new(),
             ready: true,
+            needs_sync: false,
+            pending_rollback: false,
+            pending_copy_fail: false,
+            parameter_statuses: HashMap::new(),
+            sql_rewriter: SqlRewriter::default(),
+            notifications: Vec::new(),
         };
 
         self_.startup(url).await?;
@@ -230,4 +473,22 @@ impl Connection for PgConnection {
     fn close(self) -> BoxFuture<'static, crate::Result<()>> {
         Box::pin(self.terminate())
     }
+
+    fn is_closed(&self) -> bool {
+        self.stream.is_closed()
+    }
+
+    fn shrink_buffers(&mut self) {
+        self.stream.shrink();
+    }
+}
+
+impl ConnectionState for PgConnection {
+    fn start_rollback(&mut self) {
+        self.pending_rollback = true;
+    }
+
+    fn reset(&mut self) {
+        self.sql_rewriter.clear();
+    }
 }
@@ -0,0 +1,322 @@
+use byteorder::NetworkEndian;
+use bytes::Bytes;
+use futures_core::stream::BoxStream;
+
+use crate::arguments::{Arguments, IntoArguments};
+use crate::io::BufMut;
+use crate::postgres::protocol::{self, Encode, Message};
+use crate::postgres::{PgConnection, Postgres};
+
+// Signature, flags and header extension length of the binary `COPY` format
+// https://www.postgresql.org/docs/current/sql-copy.html#id-1.9.3.55.9.4.5
+const BINARY_HEADER: &[u8] = b"PGCOPY\n\xff\r\n\0\0\0\0\0\0\0\0\0";
+
+// Copy data is flushed to the server once this much has been buffered
+const FLUSH_THRESHOLD: usize = 8192;
+
+impl PgConnection {
+    /// Execute a `COPY ... TO STDOUT` statement, returning a stream of the data sent
+    /// by the server.
+    ///
+    /// Each item is the payload of one `CopyData` message; for the text and CSV formats
+    /// this is one row.
+    ///
+    /// ```ignore
+    /// let mut data = conn.copy_out("COPY users TO STDOUT WITH CSV HEADER").await?;
+    ///
+    /// while let Some(chunk) = data.try_next().await? {
+    ///     // ..
+    /// }
+    /// ```
+    pub async fn copy_out<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+    ) -> crate::Result<BoxStream<'e, crate::Result<Bytes>>> {
+        self.wait_until_ready().await?;
+
+        let query = self.sql_rewriter.rewrite(query);
+        protocol::Query(&query).encode(self.stream.buffer_mut());
+
+        self.ready = false;
+        self.stream.flush().await?;
+
+        match self.receive().await? {
+            Some(Message::CopyOutResponse(_)) => {}
+
+            Some(message) => {
+                return Err(
+                    protocol_err!("expected CopyOutResponse; received {:?}", message).into(),
+                );
+            }
+
+            None => {
+                // Connection was (unexpectedly) closed
+                return Err(crate::Error::connection_closed());
+            }
+        }
+
+        Ok(Box::pin(async_stream::try_stream! {
+            while let Some(message) = self.receive().await? {
+                match message {
+                    Message::CopyData(data) => {
+                        yield data;
+                    }
+
+                    Message::CopyDone | Message::CommandComplete(_) => {}
+
+                    Message::ReadyForQuery(_) => {
+                        self.ready = true;
+                        break;
+                    }
+
+                    message => {
+                        let err: crate::Error =
+                            protocol_err!("received unexpected message: {:?}", message).into();
+
+                        Err(err)?;
+                    }
+                }
+            }
+        }))
+    }
+
+    /// Execute a `COPY ... FROM STDIN` statement, returning a [PgCopyIn] to send the data
+    /// to the server with.
+    ///
+    /// [PgCopyIn::finish] must be called once all data has been sent.
+    ///
+    /// ```ignore
+    /// let mut copy = conn
+    ///     .copy_in("COPY users (id, name) FROM STDIN WITH (FORMAT binary)")
+    ///     .await?;
+    ///
+    /// copy.write_row((&id, &name)).await?;
+    ///
+    /// let rows = copy.finish().await?;
+    /// ```
+    pub async fn copy_in(&mut self, query: &str) -> crate::Result<PgCopyIn<'_>> {
+        self.wait_until_ready().await?;
+
+        let query = self.sql_rewriter.rewrite(query);
+        protocol::Query(&query).encode(self.stream.buffer_mut());
+
+        self.ready = false;
+        self.stream.flush().await?;
+
+        match self.receive().await? {
+            Some(Message::CopyInResponse(_)) => {}
+
+            Some(message) => {
+                return Err(
+                    protocol_err!("expected CopyInResponse; received {:?}", message).into(),
+                );
+            }
+
+            None => {
+                // Connection was (unexpectedly) closed
+                return Err(crate::Error::connection_closed());
+            }
+        }
+
+        Ok(PgCopyIn {
+            conn: self,
+            header: false,
+            done: false,
+            progress: PgCopyProgress::default(),
+            on_progress: None,
+        })
+    }
+}
+
+/// An in-progress `COPY ... FROM STDIN`.
+///
+/// Returned by [PgConnection::copy_in]. If it is dropped before [PgCopyIn::finish] is called
+/// the copy is aborted.
+pub struct PgCopyIn<'c> {
+    conn: &'c mut PgConnection,
+
+    // whether the binary header was sent by `write_row`
+    header: bool,
+
+    done: bool,
+
+    progress: PgCopyProgress,
+    on_progress: Option<OnProgress<'c>>,
+}
+
+/// The running totals of an in-progress `COPY ... FROM STDIN`, passed to the callback set with
+/// [PgCopyIn::on_progress].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct PgCopyProgress {
+    /// The number of bytes of copy data sent so far.
+    pub bytes: u64,
+
+    /// The number of rows sent so far with [PgCopyIn::write_row]; rows in data passed to
+    /// [PgCopyIn::send] are not counted.
+    pub rows: u64,
+}
+
+struct OnProgress<'c> {
+    every: u64,
+    next: u64,
+    callback: Box<dyn FnMut(PgCopyProgress) + Send + 'c>,
+}
+
+impl<'c> PgCopyIn<'c> {
+    /// Call `callback` with the running totals of the copy every time another `every_bytes`
+    /// bytes of data have been sent, and once more when the copy is finished.
+    ///
+    /// The callback is run inline as data is written, so it should return quickly, e.g. by
+    /// updating a progress bar or sending the totals over a channel, rather than block.
+    pub fn on_progress<F>(mut self, every_bytes: u64, callback: F) -> Self
+    where
+        F: FnMut(PgCopyProgress) + Send + 'c,
+    {
+        let every = every_bytes.max(1);
+
+        self.on_progress = Some(OnProgress {
+            every,
+            next: self.progress.bytes + every,
+            callback: Box::new(callback),
+        });
+
+        self
+    }
+}
+
+impl PgCopyIn<'_> {
+    /// Send raw data in the format of the `COPY` statement.
+    ///
+    /// Data does not have to be aligned with rows.
+    pub async fn send(&mut self, data: impl AsRef<[u8]>) -> crate::Result<()> {
+        let data = data.as_ref();
+
+        protocol::CopyData(data).encode(self.conn.stream.buffer_mut());
+
+        self.progress.bytes += data.len() as u64;
+
+        if let Some(on_progress) = &mut self.on_progress {
+            if self.progress.bytes >= on_progress.next {
+                on_progress.next = self.progress.bytes + on_progress.every;
+
+                (on_progress.callback)(self.progress);
+            }
+        }
+
+        if self.conn.stream.buffer_mut().len() >= FLUSH_THRESHOLD {
+            self.conn.stream.flush().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Send one row of a binary `COPY` (`WITH (FORMAT binary)`), encoding each field of
+    /// the tuple with its [crate::encode::Encode] implementation.
+    ///
+    /// `None` fields are sent as `NULL`. The fields must match the types of the columns
+    /// exactly as no conversion is done by the server.
+    pub async fn write_row<A>(&mut self, row: A) -> crate::Result<()>
+    where
+        A: IntoArguments<Postgres>,
+    {
+        let args = row.into_arguments();
+
+        if let Some(err) = args.error {
+            return Err(err.into());
+        }
+
+        let mut data = Vec::with_capacity(BINARY_HEADER.len() + 2 + args.values.len());
+
+        if !self.header {
+            data.extend_from_slice(BINARY_HEADER);
+            self.header = true;
+        }
+
+        // field count, then the length-prefixed values
+        data.put_i16::<NetworkEndian>(args.len() as i16);
+        data.extend_from_slice(&args.values);
+
+        self.progress.rows += 1;
+
+        self.send(data).await
+    }
+
+    /// Complete the copy, returning the number of rows that were copied.
+    pub async fn finish(mut self) -> crate::Result<u64> {
+        if self.header {
+            // file trailer of the binary format
+            let mut trailer = Vec::new();
+            trailer.put_i16::<NetworkEndian>(-1);
+
+            self.send(trailer).await?;
+        }
+
+        if let Some(on_progress) = &mut self.on_progress {
+            (on_progress.callback)(self.progress);
+        }
+
+        self.done = true;
+
+        protocol::CopyDone.encode(self.conn.stream.buffer_mut());
+
+        self.conn.stream.flush().await?;
+
+        let mut rows = 0;
+
+        while let Some(message) = self.conn.receive().await? {
+            match message {
+                Message::CommandComplete(body) => {
+                    rows = body.affected_rows;
+                }
+
+                Message::ReadyForQuery(_) => {
+                    self.conn.ready = true;
+
+                    return Ok(rows);
+                }
+
+                message => {
+                    return Err(protocol_err!("received unexpected message: {:?}", message).into());
+                }
+            }
+        }
+
+        // Connection was (unexpectedly) closed
+        Err(crate::Error::connection_closed())
+    }
+
+    /// Abort the copy; `message` is reported by the server as the cause of the failure.
+    pub async fn abort(mut self, message: &str) -> crate::Result<()> {
+        self.done = true;
+
+        protocol::CopyFail(message).encode(self.conn.stream.buffer_mut());
+
+        self.conn.stream.flush().await?;
+
+        // the server acknowledges by failing the `COPY`
+        match self.conn.receive().await {
+            Err(crate::Error::Database(_)) => {}
+
+            Err(err) => return Err(err),
+
+            Ok(Some(message)) => {
+                return Err(protocol_err!("received unexpected message: {:?}", message).into());
+            }
+
+            Ok(None) => return Err(crate::Error::connection_closed()),
+        }
+
+        self.conn.wait_until_ready().await
+    }
+}
+
+impl Drop for PgCopyIn<'_> {
+    fn drop(&mut self) {
+        if !self.done {
+            // sent before the next message to the server; the error it responds with is
+            // consumed by the next use of the connection
+            protocol::CopyFail("COPY was not finished").encode(self.conn.stream.buffer_mut());
+            self.conn.pending_copy_fail = true;
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::error::Error as StdError;
+
 use crate::error::DatabaseError;
 use crate::postgres::protocol::Response;
 
@@ -8,6 +10,10 @@ impl DatabaseError for PgError {
         &self.0.message
     }
 
+    fn as_std_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
+        self
+    }
+
     fn details(&self) -> Option<&str> {
         self.0.detail.as_ref().map(|s| &**s)
     }
@@ -27,6 +33,10 @@ impl DatabaseError for PgError {
     fn constraint_name(&self) -> Option<&str> {
         self.0.constraint.as_ref().map(|s| &**s)
     }
+
+    fn position(&self) -> Option<usize> {
+        self.0.position
+    }
 }
 
 impl_fmt_error!(PgError);
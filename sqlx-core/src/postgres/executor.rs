@@ -1,27 +1,49 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
-use std::io;
 use std::sync::Arc;
 
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
+use futures_util::TryStreamExt;
 
+use crate::arguments::Arguments;
 use crate::describe::{Column, Describe};
+use crate::executor::CommandTag;
+use crate::placeholders::{self, PlaceholderStyle};
 use crate::postgres::protocol::{self, Encode, Message, StatementId};
 use crate::postgres::types::TypeFormat;
-use crate::postgres::{PgArguments, PgRow, Postgres};
+use crate::postgres::{PgArguments, PgError, PgRow, PgStatement, Postgres};
+use crate::row::Row;
+
+// OIDs below this are assigned to builtin objects
+// https://github.com/postgres/postgres/blob/master/src/include/access/transam.h
+const FIRST_NORMAL_OID: u32 = 16384;
+
+const UNNAMED_STATEMENT: StatementId = StatementId(0);
 
 #[derive(Debug)]
 enum Step {
-    Command(u64),
+    Command(protocol::CommandComplete),
     NoData,
     Row(protocol::DataRow),
+    Suspended,
     ParamDesc(Box<protocol::ParameterDescription>),
     RowDesc(Box<protocol::RowDescription>),
 }
 
 impl super::PgConnection {
-    fn write_prepare(&mut self, query: &str, args: &PgArguments) -> StatementId {
-        if let Some(&id) = self.statement_cache.get(query) {
+    fn write_prepare(&mut self, query: &str, args: &PgArguments, persistent: bool) -> StatementId {
+        if !persistent {
+            // The unnamed statement is replaced by the next one and never cached
+            protocol::Parse {
+                statement: UNNAMED_STATEMENT,
+                query,
+                param_types: &args.types,
+            }
+            .encode(self.stream.buffer_mut());
+
+            UNNAMED_STATEMENT
+        } else if let Some(&id) = self.statement_cache.get(query) {
             id
         } else {
             let id = StatementId(self.next_statement_id);
@@ -40,6 +62,20 @@ impl super::PgConnection {
         }
     }
 
+    // `?` is only rejected in queries with arguments as it is also an operator on `jsonb`;
+    // a statement that is already prepared was checked before
+    fn check_arguments(&self, query: &str, args: &PgArguments) -> crate::Result<()> {
+        if let Some(err) = &args.error {
+            return Err(err.clone().into());
+        }
+
+        if args.types.is_empty() || self.statement_cache.get(query).is_some() {
+            return Ok(());
+        }
+
+        placeholders::check(query, PlaceholderStyle::Dollar)
+    }
+
     fn write_describe(&mut self, d: protocol::Describe) {
         d.encode(self.stream.buffer_mut())
     }
@@ -65,35 +101,80 @@ impl super::PgConnection {
         protocol::Sync.encode(self.stream.buffer_mut());
     }
 
-    async fn wait_until_ready(&mut self) -> crate::Result<()> {
-        if !self.ready {
-            while let Some(message) = self.receive().await? {
-                match message {
-                    Message::ReadyForQuery(_) => {
-                        self.ready = true;
-                        break;
-                    }
+    fn write_flush(&mut self) {
+        protocol::Flush.encode(self.stream.buffer_mut());
+    }
 
-                    _ => {
-                        // Drain the stream
-                    }
+    // Must be called before any message of a query is written; the previous query may have
+    // been dropped before all of its messages were sent or all of its results were received
+    pub(super) async fn wait_until_ready(&mut self) -> crate::Result<()> {
+        if self.needs_sync {
+            // A paged fetch was not run to completion
+            self.write_sync();
+            self.needs_sync = false;
+        }
+
+        // Send what is left of a flush that was cancelled
+        self.stream.flush().await?;
+
+        if self.pending_copy_fail {
+            // The error the server fails a dropped COPY with is expected, and it is followed
+            // by ReadyForQuery
+            self.pending_copy_fail = false;
+
+            if let Err(crate::Error::Database(_)) = self.drain().await {
+                self.drain().await?;
+            }
+        }
+
+        loop {
+            if !self.ready {
+                self.drain().await?;
+            }
+
+            if !self.pending_rollback {
+                return Ok(());
+            }
+
+            // A transaction was dropped before it was committed or rolled back
+            protocol::Query("ROLLBACK").encode(self.stream.buffer_mut());
+
+            self.ready = false;
+            self.pending_rollback = false;
+            self.stream.flush().await?;
+        }
+    }
+
+    // Receive the messages of the query in progress until ReadyForQuery
+    async fn drain(&mut self) -> crate::Result<()> {
+        while let Some(message) = self.receive().await? {
+            match message {
+                Message::ReadyForQuery(_) => {
+                    self.ready = true;
+                    return Ok(());
+                }
+
+                _ => {
+                    // Drain the stream
                 }
             }
         }
 
-        Ok(())
+        // Connection was (unexpectedly) closed
+        Err(crate::Error::connection_closed())
     }
 
     async fn step(&mut self) -> crate::Result<Option<Step>> {
         while let Some(message) = self.receive().await? {
             match message {
-                Message::BindComplete
-                | Message::ParseComplete
-                | Message::PortalSuspended
-                | Message::CloseComplete => {}
+                Message::BindComplete | Message::ParseComplete | Message::CloseComplete => {}
+
+                Message::PortalSuspended => {
+                    return Ok(Some(Step::Suspended));
+                }
 
                 Message::CommandComplete(body) => {
-                    return Ok(Some(Step::Command(body.affected_rows)));
+                    return Ok(Some(Step::Command(body)));
                 }
 
                 Message::NoData => {
@@ -125,65 +206,120 @@ impl super::PgConnection {
         }
 
         // Connection was (unexpectedly) closed
-        Err(io::Error::from(io::ErrorKind::ConnectionAborted).into())
+        Err(crate::Error::connection_closed())
     }
 }
 
 impl super::PgConnection {
-    async fn send<'e, 'q: 'e>(&'e mut self, command: &'q str) -> crate::Result<()> {
-        protocol::Query(command).encode(self.stream.buffer_mut());
-
+    async fn send<'e, 'q: 'e>(&'e mut self, command: &'q str) -> crate::Result<Vec<CommandTag>> {
         self.wait_until_ready().await?;
 
-        self.stream.flush().await?;
+        protocol::Query(command).encode(self.stream.buffer_mut());
+
         self.ready = false;
+        self.stream.flush().await?;
 
-        while let Some(_step) = self.step().await? {
-            // Drain the stream until ReadyForQuery
+        let mut tags = Vec::new();
+
+        // Drain the stream until ReadyForQuery, there is a CommandComplete for every command
+        while let Some(step) = self.step().await? {
+            if let Step::Command(body) = step {
+                tags.push(CommandTag::new(body.command, body.affected_rows));
+            }
         }
 
-        Ok(())
+        Ok(tags)
+    }
+
+    // Run the commands with the simple query protocol, returning the rows of all of them
+    pub(super) fn fetch_simple_rows<'e>(
+        &'e mut self,
+        commands: Cow<'e, str>,
+    ) -> BoxStream<'e, crate::Result<PgRow>> {
+        Box::pin(async_stream::try_stream! {
+            self.wait_until_ready().await?;
+
+            protocol::Query(&commands).encode(self.stream.buffer_mut());
+
+            self.ready = false;
+            self.stream.flush().await?;
+
+            let mut columns = Arc::default();
+
+            while let Some(step) = self.step().await? {
+                match step {
+                    // every command that returns rows is described first
+                    Step::RowDesc(desc) => columns = Arc::new(column_indices(&desc)),
+
+                    Step::Row(data) => {
+                        yield PgRow {
+                            data,
+                            columns: Arc::clone(&columns),
+                            format: TypeFormat::Text,
+                        };
+                    }
+
+                    _ => {}
+                }
+            }
+        })
     }
 
     async fn execute<'e, 'q: 'e>(
         &'e mut self,
         query: &'q str,
         args: PgArguments,
+        persistent: bool,
     ) -> crate::Result<u64> {
-        let statement = self.write_prepare(query, &args);
+        self.check_arguments(query, &args)?;
+
+        self.wait_until_ready().await?;
+
+        let statement = self.write_prepare(query, &args, persistent);
 
         self.write_bind("", statement, &args);
         self.write_execute("", 1);
         self.write_sync();
 
-        self.wait_until_ready().await?;
-
-        self.stream.flush().await?;
         self.ready = false;
+        self.stream.flush().await?;
 
         let mut affected = 0;
 
         while let Some(step) = self.step().await? {
-            if let Step::Command(cnt) = step {
-                affected = cnt;
+            if let Step::Command(body) = step {
+                affected = body.affected_rows;
             }
         }
 
         Ok(affected)
     }
 
-    // Initial part of [fetch]; write message to stream
-    fn write_fetch(&mut self, query: &str, args: &PgArguments) -> StatementId {
-        let statement = self.write_prepare(query, &args);
+    // Initial part of [fetch_rows]; write message to stream
+    fn write_fetch(
+        &mut self,
+        query: &str,
+        args: &PgArguments,
+        limit: i32,
+        persistent: bool,
+    ) -> StatementId {
+        let statement = self.write_prepare(query, &args, persistent);
 
         self.write_bind("", statement, &args);
 
-        if !self.statement_cache.has_columns(statement) {
+        if statement == UNNAMED_STATEMENT || !self.statement_cache.has_columns(statement) {
             self.write_describe(protocol::Describe::Portal(""));
         }
 
-        self.write_execute("", 0);
-        self.write_sync();
+        self.write_execute("", limit);
+
+        if limit == 0 {
+            self.write_sync();
+        } else {
+            // The unnamed portal does not outlive a Sync; it is sent once the portal
+            // has been run to completion
+            self.write_flush();
+        }
 
         statement
     }
@@ -192,7 +328,7 @@ impl super::PgConnection {
         &mut self,
         statement: StatementId,
     ) -> crate::Result<Arc<HashMap<Box<str>, usize>>> {
-        if !self.statement_cache.has_columns(statement) {
+        if statement == UNNAMED_STATEMENT || !self.statement_cache.has_columns(statement) {
             let desc: Option<_> = 'outer: loop {
                 while let Some(step) = self.step().await? {
                     match step {
@@ -207,16 +343,10 @@ impl super::PgConnection {
                 unreachable!();
             };
 
-            let mut columns = HashMap::new();
+            let columns = desc.as_deref().map(column_indices).unwrap_or_default();
 
-            if let Some(desc) = desc {
-                columns.reserve(desc.fields.len());
-
-                for (index, field) in desc.fields.iter().enumerate() {
-                    if let Some(name) = &field.name {
-                        columns.insert(name.clone(), index);
-                    }
-                }
+            if statement == UNNAMED_STATEMENT {
+                return Ok(Arc::new(columns));
             }
 
             self.statement_cache.put_columns(statement, columns);
@@ -225,24 +355,53 @@ impl super::PgConnection {
         Ok(self.statement_cache.get_columns(statement))
     }
 
-    fn fetch<'e, 'q: 'e>(
+    // Fetch the rows of the query, `limit` at a time; 0 fetches all rows at once
+    fn fetch_rows<'e, 'q: 'e>(
         &'e mut self,
-        query: &'q str,
+        query: Cow<'q, str>,
         args: PgArguments,
+        limit: i32,
+        persistent: bool,
     ) -> BoxStream<'e, crate::Result<PgRow>> {
         Box::pin(async_stream::try_stream! {
-            let statement = self.write_fetch(query, &args);
+            self.check_arguments(&query, &args)?;
 
             self.wait_until_ready().await?;
 
-            self.stream.flush().await?;
+            let statement = self.write_fetch(&query, &args, limit, persistent);
+
             self.ready = false;
+            self.needs_sync = limit != 0;
+            self.stream.flush().await?;
 
             let columns = self.get_columns(statement).await?;
 
             while let Some(step) = self.step().await? {
-                if let Step::Row(data) = step {
-                    yield PgRow { data, columns: Arc::clone(&columns) };
+                match step {
+                    Step::Row(data) => {
+                        yield PgRow {
+                            data,
+                            columns: Arc::clone(&columns),
+                            format: TypeFormat::Binary,
+                        };
+                    }
+
+                    Step::Suspended => {
+                        // Request the next page of rows
+                        self.write_execute("", limit);
+                        self.write_flush();
+
+                        self.stream.flush().await?;
+                    }
+
+                    Step::Command(_) if self.needs_sync => {
+                        self.write_sync();
+                        self.needs_sync = false;
+
+                        self.stream.flush().await?;
+                    }
+
+                    _ => {}
                 }
             }
 
@@ -250,59 +409,486 @@ impl super::PgConnection {
         })
     }
 
+    // The statement keeps the SQL before it is rewritten, as its query is rewritten again
+    pub(super) async fn prepare_statement(&mut self, sql: &str) -> crate::Result<PgStatement> {
+        let query = self.sql_rewriter.rewrite(sql);
+
+        self.wait_until_ready().await?;
+
+        let statement = self.write_prepare(&query, &Default::default(), true);
+        let describe = !self.statement_cache.has_columns(statement);
+
+        if describe {
+            self.write_describe(protocol::Describe::Statement(statement));
+        }
+
+        self.write_sync();
+
+        self.ready = false;
+        self.stream.flush().await?;
+
+        let res = async {
+            if describe {
+                self.get_columns(statement).await?;
+            }
+
+            while let Some(_step) = self.step().await? {
+                // Drain the stream until ReadyForQuery
+            }
+
+            Ok(())
+        }
+        .await;
+
+        if let Err(err) = res {
+            // The statement was not created; don't try to use it later
+            self.statement_cache.remove(&query);
+
+            return Err(err);
+        }
+
+        Ok(PgStatement::new(statement, sql))
+    }
+
+    pub(super) async fn close_statement(&mut self, statement: PgStatement) -> crate::Result<()> {
+        let query = self.sql_rewriter.rewrite(statement.sql());
+
+        self.wait_until_ready().await?;
+
+        if self.statement_cache.get(&query) == Some(&statement.id) {
+            self.statement_cache.remove(&query);
+        }
+
+        protocol::Close::Statement(statement.id).encode(self.stream.buffer_mut());
+        self.write_sync();
+
+        self.ready = false;
+        self.stream.flush().await?;
+
+        while let Some(_step) = self.step().await? {
+            // Drain the stream until ReadyForQuery
+        }
+
+        Ok(())
+    }
+
+    pub(super) async fn close_cached_statements(&mut self) -> crate::Result<()> {
+        if self.statement_cache.is_empty() {
+            return Ok(());
+        }
+
+        self.wait_until_ready().await?;
+
+        for statement in self.statement_cache.clear() {
+            protocol::Close::Statement(statement).encode(self.stream.buffer_mut());
+        }
+
+        self.write_sync();
+
+        self.ready = false;
+        self.stream.flush().await?;
+
+        while let Some(_step) = self.step().await? {
+            // Drain the stream until ReadyForQuery
+        }
+
+        Ok(())
+    }
+
     async fn describe<'e, 'q: 'e>(
         &'e mut self,
         query: &'q str,
     ) -> crate::Result<Describe<Postgres>> {
-        let statement = self.write_prepare(query, &Default::default());
+        let (param_types, fields) = match self.describe_statement(query, &[], true).await {
+            Err(err) if ambiguous_parameter(&err).is_some() => {
+                let param_types = self.infer_param_types(query, err).await?;
+
+                self.describe_statement(query, &param_types, true).await?
+            }
+
+            res => res?,
+        };
+
+        let mut result_columns = fields
+            .into_iter()
+            // TODO: Should [Column] just wrap [protocol::Field] ?
+            .map(|field| Column {
+                name: field.name,
+                table_id: field.table_id,
+                type_id: field.type_id,
+                type_name: None,
+                type_is_enum: None,
+                format: Some(field.type_format as i16),
+                type_size: Some(field.type_size),
+                type_modifier: Some(field.type_mod),
+                display_size: None,
+                nullable: None,
+            })
+            .collect::<Vec<_>>();
+
+        self.resolve_type_names(&mut result_columns).await?;
+
+        if !result_columns.is_empty() && (has_join(query) || has_json_operator(query)) {
+            self.resolve_nullable_columns(query, param_types.len(), &mut result_columns)
+                .await;
+        }
+
+        Ok(Describe {
+            param_types,
+            result_columns: result_columns.into_boxed_slice(),
+        })
+    }
+
+    // Returns the types of the parameters and the fields of the result of the statement
+    async fn describe_statement(
+        &mut self,
+        query: &str,
+        param_types: &[u32],
+        persistent: bool,
+    ) -> crate::Result<(Box<[u32]>, Vec<protocol::Field>)> {
+        self.wait_until_ready().await?;
+
+        let args = PgArguments {
+            types: param_types.to_vec(),
+            ..Default::default()
+        };
+
+        let statement = self.write_prepare(query, &args, persistent);
 
         self.write_describe(protocol::Describe::Statement(statement));
         self.write_sync();
 
+        self.ready = false;
         self.stream.flush().await?;
-        self.wait_until_ready().await?;
 
-        let params = match self.step().await? {
-            Some(Step::ParamDesc(desc)) => desc,
+        let res = async {
+            let params = match self.step().await? {
+                Some(Step::ParamDesc(desc)) => desc,
 
-            step => {
-                return Err(
-                    protocol_err!("expected ParameterDescription; received {:?}", step).into(),
-                );
+                step => {
+                    return Err(protocol_err!(
+                        "expected ParameterDescription; received {:?}",
+                        step
+                    )
+                    .into());
+                }
+            };
+
+            let fields = match self.step().await? {
+                Some(Step::RowDesc(desc)) => desc.fields.into_vec(),
+
+                // the statement returns no rows, e.g. an `INSERT` without `RETURNING`
+                Some(Step::NoData) => Vec::new(),
+
+                step => {
+                    return Err(
+                        protocol_err!("expected RowDescription; received {:?}", step).into(),
+                    );
+                }
+            };
+
+            Ok((params.ids, fields))
+        }
+        .await;
+
+        if res.is_err() && persistent {
+            // The statement was not created; don't try to use it later
+            self.statement_cache.remove(query);
+        }
+
+        res
+    }
+
+    // Postgres deduces the type of a parameter from the expressions it is used in, e.g. the
+    // other arguments of `COALESCE($1, name)`, but rejects a statement where the parameter is
+    // used before its type is deduced, e.g. in `$1 IS NULL OR name = $1`.
+    //
+    // Each such use is replaced with `NULL` until the types of the parameters can be deduced
+    // from their other uses; `err` is returned if they cannot.
+    async fn infer_param_types(
+        &mut self,
+        query: &str,
+        err: crate::Error,
+    ) -> crate::Result<Box<[u32]>> {
+        let mut query = query.to_owned();
+        let mut position = ambiguous_parameter(&err);
+
+        while let Some(replaced) = position.and_then(|pos| replace_parameter(&query, pos)) {
+            query = replaced;
+
+            match self.describe_statement(&query, &[], false).await {
+                Ok((param_types, _)) => return Ok(param_types),
+
+                Err(err) => position = ambiguous_parameter(&err),
+            }
+        }
+
+        Err(err)
+    }
+
+    // Look up the names of types that are not builtin, as their OIDs differ between databases,
+    // and whether they are enums
+    async fn resolve_type_names(&mut self, columns: &mut [Column<Postgres>]) -> crate::Result<()> {
+        let oids: Vec<i64> = columns
+            .iter()
+            .filter(|column| column.type_id >= FIRST_NORMAL_OID)
+            .map(|column| i64::from(column.type_id))
+            .collect();
+
+        if oids.is_empty() {
+            return Ok(());
+        }
+
+        let mut args = PgArguments::default();
+        args.add(oids);
+
+        let rows: Vec<PgRow> = self
+            .fetch_rows(
+                "SELECT oid::int8, typname::text, typtype = 'e' FROM pg_catalog.pg_type WHERE oid = ANY($1::int8[]::oid[])".into(),
+                args,
+                0,
+                true,
+            )
+            .try_collect()
+            .await?;
+
+        for row in rows {
+            let oid = row.get::<i64, _>(0) as u32;
+            let name: String = row.get(1);
+            let is_enum: bool = row.get(2);
+
+            for column in columns.iter_mut().filter(|column| column.type_id == oid) {
+                column.type_name = Some(name.clone().into_boxed_str());
+                column.type_is_enum = Some(is_enum);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Postgres does not report whether the columns of a result may be `NULL`; a column of a
+    // table with a `NOT NULL` constraint is still `NULL` when it is on the nullable side of an
+    // outer join, and a field of a JSON document is `NULL` when it is missing. Those columns are
+    // found in the plan of the statement, which is left unknown if it cannot be explained.
+    async fn resolve_nullable_columns(
+        &mut self,
+        query: &str,
+        num_params: usize,
+        columns: &mut [Column<Postgres>],
+    ) {
+        let statement = match self.statement_cache.get(query) {
+            Some(&statement) => statement,
+            None => return,
+        };
+
+        let (outputs, nullable) = match self.explain_statement(statement, num_params).await {
+            Ok(plan) => plan,
+            Err(err) => {
+                log::debug!("failed to explain {:?}: {}", query, err);
+                return;
             }
         };
 
-        let result = match self.step().await? {
-            Some(Step::RowDesc(desc)) => desc,
+        for (column, output) in columns.iter_mut().zip(&outputs) {
+            if is_json_field(output) {
+                column.nullable = Some(true);
+                continue;
+            }
 
-            step => {
-                return Err(protocol_err!("expected RowDescription; received {:?}", step).into());
+            // an expression of the nullable side, e.g. a constant of a subquery, is wrapped in
+            // parentheses above the join
+            let unwrapped = output
+                .strip_prefix('(')
+                .and_then(|output| output.strip_suffix(')'));
+
+            if nullable
+                .iter()
+                .any(|expr| expr == output || Some(&**expr) == unwrapped)
+            {
+                column.nullable = Some(true);
             }
+        }
+    }
+
+    // Returns the outputs of the top node of the plan of a statement, which are the columns of
+    // the result followed by any columns it is only sorted by, and the outputs of the nullable
+    // sides of its outer joins.
+    //
+    // A generic plan is requested for statements with parameters, as the plan for `NULL`
+    // arguments is usually reduced to returning no rows at all; the `plan_cache_mode` of the
+    // session is restored afterwards
+    async fn explain_statement(
+        &mut self,
+        statement: StatementId,
+        num_params: usize,
+    ) -> crate::Result<(Vec<String>, Vec<String>)> {
+        let mut sql = format!("EXPLAIN (VERBOSE, FORMAT JSON) EXECUTE {}", statement);
+        let mut plan_cache_mode = None;
+
+        if num_params > 0 {
+            sql.push('(');
+            sql.push_str(&vec!["NULL"; num_params].join(", "));
+            sql.push(')');
+
+            plan_cache_mode = Some(
+                self.set_plan_cache_mode("force_generic_plan".to_owned())
+                    .await?,
+            );
+        }
+
+        let res: crate::Result<Vec<PgRow>> = self
+            .fetch_rows(sql.into(), Default::default(), 0, false)
+            .try_collect()
+            .await;
+
+        if let Some(mode) = plan_cache_mode {
+            self.set_plan_cache_mode(mode).await?;
+        }
+
+        let plan: String = match res?.first() {
+            Some(row) => row.try_get(0)?,
+            None => return Err(protocol_err!("EXPLAIN returned no rows").into()),
         };
 
-        Ok(Describe {
-            param_types: params.ids,
-            result_columns: result
-                .fields
-                .into_vec()
-                .into_iter()
-                // TODO: Should [Column] just wrap [protocol::Field] ?
-                .map(|field| Column {
-                    name: field.name,
-                    table_id: field.table_id,
-                    type_id: field.type_id,
-                })
-                .collect::<Vec<_>>()
-                .into_boxed_slice(),
-        })
+        // the plan is walked by the server so that no JSON parser is needed here
+        let mut args = PgArguments::default();
+        args.add(plan);
+
+        let row = self
+            .fetch_rows(EXPLAIN_OUTPUTS.into(), args, 0, false)
+            .try_next()
+            .await?
+            .ok_or_else(|| protocol_err!("EXPLAIN outputs returned no rows"))?;
+
+        Ok((row.try_get(0)?, row.try_get(1)?))
     }
+
+    // Sets `plan_cache_mode` for the session, returning its previous value
+    async fn set_plan_cache_mode(&mut self, mode: String) -> crate::Result<String> {
+        let previous = self
+            .fetch_rows(
+                "SELECT current_setting('plan_cache_mode')".into(),
+                Default::default(),
+                0,
+                false,
+            )
+            .try_next()
+            .await?
+            .ok_or_else(|| protocol_err!("current_setting returned no rows"))?
+            .try_get(0)?;
+
+        let mut args = PgArguments::default();
+        args.add(mode);
+
+        let _: Vec<PgRow> = self
+            .fetch_rows(
+                "SELECT set_config('plan_cache_mode', $1, false)".into(),
+                args,
+                0,
+                false,
+            )
+            .try_collect()
+            .await?;
+
+        Ok(previous)
+    }
+}
+
+// The index of every named column of a result
+fn column_indices(desc: &protocol::RowDescription) -> HashMap<Box<str>, usize> {
+    let mut columns = HashMap::with_capacity(desc.fields.len());
+
+    for (index, field) in desc.fields.iter().enumerate() {
+        if let Some(name) = &field.name {
+            columns.insert(name.clone(), index);
+        }
+    }
+
+    columns
+}
+
+// The outputs of the top node of a plan in the JSON format of `EXPLAIN` and of the children of
+// its outer joins on their nullable side
+const EXPLAIN_OUTPUTS: &str = "\
+WITH RECURSIVE nodes (node, nullable) AS ( \
+    SELECT $1::jsonb -> 0 -> 'Plan', false \
+  UNION ALL \
+    SELECT child, (node ->> 'Join Type', child ->> 'Parent Relationship') IN ( \
+        ('Left', 'Inner'), ('Right', 'Outer'), ('Full', 'Inner'), ('Full', 'Outer') \
+    ) \
+    FROM nodes, jsonb_array_elements(coalesce(node -> 'Plans', '[]')) child \
+) \
+SELECT \
+    ARRAY( \
+        SELECT output \
+        FROM jsonb_array_elements_text($1::jsonb -> 0 -> 'Plan' -> 'Output') \
+            WITH ORDINALITY outputs (output, position) \
+        ORDER BY position \
+    ), \
+    ARRAY(SELECT jsonb_array_elements_text(node -> 'Output') FROM nodes WHERE nullable)";
+
+// Outer joins are written with `JOIN`, the plan is not looked up for queries without it
+fn has_join(query: &str) -> bool {
+    placeholders::has_keyword(query, PlaceholderStyle::Dollar, "JOIN")
+}
+
+// The fields of JSON documents are extracted with `->`, `->>`, `#>` or `#>>`, the plan is not
+// looked up for them in queries without any of those
+fn has_json_operator(query: &str) -> bool {
+    query.contains("->") || query.contains("#>")
+}
+
+// Whether an output of a plan is a field extracted from a JSON document, e.g.
+// `(t.data ->> 'name'::text)`, which is `NULL` if the document does not have that field
+fn is_json_field(output: &str) -> bool {
+    let output = match output
+        .strip_prefix('(')
+        .and_then(|output| output.strip_suffix(')'))
+    {
+        Some(output) => output,
+        None => return false,
+    };
+
+    // only an operator outside of any parentheses or literals extracts the field
+    let mut depth = 0_usize;
+    let mut quote = None;
+
+    for (i, c) in output.char_indices() {
+        match (c, quote) {
+            (_, Some(q)) if c == q => quote = None,
+            (_, Some(_)) => {}
+
+            ('\'', None) | ('"', None) => quote = Some(c),
+            ('(', None) => depth += 1,
+            (')', None) => depth = depth.saturating_sub(1),
+
+            (' ', None) if depth == 0 => {
+                let operator = output[i + 1..].split(' ').next().unwrap_or_default();
+
+                if ["->", "->>", "#>", "#>>"].contains(&operator) {
+                    return true;
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    false
 }
 
 impl crate::Executor for super::PgConnection {
     type Database = super::Postgres;
 
-    fn send<'e, 'q: 'e>(&'e mut self, query: &'q str) -> BoxFuture<'e, crate::Result<()>> {
-        Box::pin(self.send(query))
+    fn send<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+    ) -> BoxFuture<'e, crate::Result<Vec<CommandTag>>> {
+        Box::pin(async move {
+            let query = self.sql_rewriter.rewrite(query);
+
+            self.send(&query).await
+        })
     }
 
     fn execute<'e, 'q: 'e>(
@@ -310,7 +896,23 @@ impl crate::Executor for super::PgConnection {
         query: &'q str,
         args: PgArguments,
     ) -> BoxFuture<'e, crate::Result<u64>> {
-        Box::pin(self.execute(query, args))
+        Box::pin(async move {
+            let query = self.sql_rewriter.rewrite(query);
+
+            self.execute(&query, args, true).await
+        })
+    }
+
+    fn execute_uncached<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: PgArguments,
+    ) -> BoxFuture<'e, crate::Result<u64>> {
+        Box::pin(async move {
+            let query = self.sql_rewriter.rewrite(query);
+
+            self.execute(&query, args, false).await
+        })
     }
 
     fn fetch<'e, 'q: 'e>(
@@ -318,13 +920,78 @@ impl crate::Executor for super::PgConnection {
         query: &'q str,
         args: PgArguments,
     ) -> BoxStream<'e, crate::Result<PgRow>> {
-        self.fetch(query, args)
+        let query = self.sql_rewriter.rewrite(query);
+
+        self.fetch_rows(query, args, 0, true)
+    }
+
+    fn fetch_paged<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: PgArguments,
+        fetch_size: u32,
+    ) -> BoxStream<'e, crate::Result<PgRow>> {
+        let query = self.sql_rewriter.rewrite(query);
+
+        self.fetch_rows(query, args, fetch_limit(fetch_size), true)
+    }
+
+    fn fetch_uncached<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: PgArguments,
+        fetch_size: Option<u32>,
+    ) -> BoxStream<'e, crate::Result<PgRow>> {
+        let query = self.sql_rewriter.rewrite(query);
+
+        self.fetch_rows(query, args, fetch_size.map_or(0, fetch_limit), false)
     }
 
     fn describe<'e, 'q: 'e>(
         &'e mut self,
         query: &'q str,
     ) -> BoxFuture<'e, crate::Result<Describe<Self::Database>>> {
-        Box::pin(self.describe(query))
+        Box::pin(async move {
+            let query = self.sql_rewriter.rewrite(query);
+
+            self.describe(&query).await
+        })
+    }
+}
+
+// The position of a parameter whose type was deduced after it was used
+fn ambiguous_parameter(err: &crate::Error) -> Option<usize> {
+    // ambiguous_parameter
+    const AMBIGUOUS_PARAMETER: &str = "42P08";
+
+    match err {
+        crate::Error::Database(err) => {
+            let err = err.as_std_error().downcast_ref::<PgError>()?;
+
+            if &*err.0.code == AMBIGUOUS_PARAMETER {
+                err.0.position
+            } else {
+                None
+            }
+        }
+
+        _ => None,
     }
 }
+
+// Replace the parameter at the 1-based character position of an error with `NULL`
+fn replace_parameter(query: &str, position: usize) -> Option<String> {
+    let (start, _) = query.char_indices().nth(position.checked_sub(1)?)?;
+    let digits = query[start..].strip_prefix('$')?;
+    let len = 1 + digits.bytes().take_while(u8::is_ascii_digit).count();
+
+    if len == 1 {
+        return None;
+    }
+
+    Some(format!("{}NULL{}", &query[..start], &query[start + len..]))
+}
+
+fn fetch_limit(fetch_size: u32) -> i32 {
+    fetch_size.min(i32::MAX as u32) as i32
+}
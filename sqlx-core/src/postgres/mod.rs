@@ -3,21 +3,26 @@
 use std::convert::TryInto;
 
 pub use arguments::PgArguments;
-pub use connection::PgConnection;
+pub use connection::{PgConnection, PgNotification};
+pub use copy::{PgCopyIn, PgCopyProgress};
 pub use database::Postgres;
 pub use error::PgError;
 pub use row::PgRow;
+pub use statement::PgStatement;
 
 use crate::url::Url;
 
 mod arguments;
+mod blob;
 mod connection;
+mod copy;
 mod database;
 mod error;
 mod executor;
 mod protocol;
 mod row;
-mod types;
+mod statement;
+pub mod types;
 
 /// An alias for [`Pool`], specialized for **Postgres**.
 pub type PgPool = super::Pool<Postgres>;
@@ -1,9 +1,9 @@
 use crate::io::BufMut;
-use crate::postgres::protocol::Encode;
-use byteorder::NetworkEndian;
+use crate::postgres::protocol::{Encode, StatementId};
+use byteorder::{ByteOrder, NetworkEndian};
 
 pub enum Close<'a> {
-    Statement(&'a str),
+    Statement(StatementId),
     Portal(&'a str),
 }
 
@@ -11,22 +11,31 @@ impl Encode for Close<'_> {
     fn encode(&self, buf: &mut Vec<u8>) {
         buf.push(b'C');
 
-        let (kind, name) = match self {
-            Close::Statement(name) => (b'S', name),
-            Close::Portal(name) => (b'P', name),
-        };
+        let pos = buf.len();
+        buf.put_i32::<NetworkEndian>(0); // skip over len
+
+        match self {
+            Close::Statement(id) => {
+                buf.push(b'S');
+                id.encode(buf);
+            }
 
-        // len + kind + nul + len(string)
-        buf.put_i32::<NetworkEndian>((4 + 1 + 1 + name.len()) as i32);
+            Close::Portal(name) => {
+                buf.push(b'P');
+                buf.put_str_nul(name);
+            }
+        };
 
-        buf.push(kind);
-        buf.put_str_nul(name);
+        // Write-back the len to the beginning of this frame
+        let len = buf.len() - pos;
+        NetworkEndian::write_i32(&mut buf[pos..], len as i32);
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::{Close, Encode};
+    use crate::postgres::protocol::StatementId;
 
     #[test]
     fn it_encodes_close_portal() {
@@ -41,10 +50,10 @@ mod test {
     #[test]
     fn it_encodes_close_statement() {
         let mut buf = Vec::new();
-        let m = Close::Statement("__sqlx_s_1");
+        let m = Close::Statement(StatementId(1));
 
         m.encode(&mut buf);
 
-        assert_eq!(buf, b"C\0\0\0\x10S__sqlx_s_1\0");
+        assert_eq!(buf, b"C\x00\x00\x00\x18S__sqlx_statement_1\x00");
     }
 }
@@ -4,6 +4,8 @@ use std::io;
 
 #[derive(Debug)]
 pub struct CommandComplete {
+    // The command of the tag without its counts, e.g. `INSERT` for `INSERT 0 1`
+    pub command: Box<str>,
     pub affected_rows: u64,
 }
 
@@ -13,14 +15,18 @@ impl Decode for CommandComplete {
         // If it can't be parsed, the tag is probably "CREATE TABLE" or something
         // and we should return 0 rows
 
-        let rows = buf
-            .get_str_nul()?
+        let tag = buf.get_str_nul()?;
+
+        let rows = tag
             .rsplit(' ')
             .next()
             .and_then(|s| s.parse().ok())
             .unwrap_or(0);
 
+        let command = tag.trim_end_matches(|c: char| c.is_ascii_digit() || c == ' ');
+
         Ok(Self {
+            command: command.into(),
             affected_rows: rows,
         })
     }
@@ -39,6 +45,7 @@ mod tests {
     fn it_decodes_command_complete_for_insert() {
         let message = CommandComplete::decode(COMMAND_COMPLETE_INSERT).unwrap();
 
+        assert_eq!(&*message.command, "INSERT");
         assert_eq!(message.affected_rows, 1);
     }
 
@@ -46,6 +53,7 @@ mod tests {
     fn it_decodes_command_complete_for_update() {
         let message = CommandComplete::decode(COMMAND_COMPLETE_UPDATE).unwrap();
 
+        assert_eq!(&*message.command, "UPDATE");
         assert_eq!(message.affected_rows, 512);
     }
 
@@ -53,6 +61,7 @@ mod tests {
     fn it_decodes_command_complete_for_begin() {
         let message = CommandComplete::decode(COMMAND_COMPLETE_BEGIN).unwrap();
 
+        assert_eq!(&*message.command, "BEGIN");
         assert_eq!(message.affected_rows, 0);
     }
 
@@ -60,6 +69,7 @@ mod tests {
     fn it_decodes_command_complete_for_create_table() {
         let message = CommandComplete::decode(COMMAND_COMPLETE_CREATE_TABLE).unwrap();
 
+        assert_eq!(&*message.command, "CREATE TABLE");
         assert_eq!(message.affected_rows, 0);
     }
 }
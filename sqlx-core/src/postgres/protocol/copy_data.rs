@@ -0,0 +1,33 @@
+use crate::io::BufMut;
+use crate::postgres::protocol::Encode;
+use byteorder::NetworkEndian;
+
+pub struct CopyData<'a>(pub &'a [u8]);
+
+impl Encode for CopyData<'_> {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(b'd');
+
+        // len + data
+        buf.put_i32::<NetworkEndian>((4 + self.0.len()) as i32);
+
+        buf.extend_from_slice(self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CopyData, Encode};
+
+    const COPY_DATA: &[u8] = b"d\0\0\0\x081\tab";
+
+    #[test]
+    fn it_encodes_copy_data() {
+        let mut buf = Vec::new();
+        let m = CopyData(b"1\tab");
+
+        m.encode(&mut buf);
+
+        assert_eq!(buf, COPY_DATA);
+    }
+}
@@ -0,0 +1,13 @@
+use crate::io::BufMut;
+use crate::postgres::protocol::Encode;
+use byteorder::NetworkEndian;
+
+pub struct CopyDone;
+
+impl Encode for CopyDone {
+    #[inline]
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(b'c');
+        buf.put_i32::<NetworkEndian>(4);
+    }
+}
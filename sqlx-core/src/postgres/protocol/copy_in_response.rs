@@ -0,0 +1,48 @@
+use crate::io::Buf;
+use crate::postgres::protocol::Decode;
+use crate::postgres::types::TypeFormat;
+use byteorder::NetworkEndian;
+
+/// `CopyInResponse` is sent when the backend begins a `COPY ... FROM STDIN`.
+#[derive(Debug)]
+pub struct CopyInResponse {
+    /// The overall format of the copy data (text or binary).
+    pub format: TypeFormat,
+
+    /// The format of each column. All must be text if the overall format is text.
+    pub column_formats: Box<[TypeFormat]>,
+}
+
+impl Decode for CopyInResponse {
+    fn decode(mut buf: &[u8]) -> crate::Result<Self> {
+        let format = (buf.get_u8()? as i16).into();
+        let cnt = buf.get_u16::<NetworkEndian>()? as usize;
+        let mut column_formats = Vec::with_capacity(cnt);
+
+        for _ in 0..cnt {
+            column_formats.push(buf.get_i16::<NetworkEndian>()?.into());
+        }
+
+        Ok(Self {
+            format,
+            column_formats: column_formats.into_boxed_slice(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CopyInResponse, Decode};
+    use crate::postgres::types::TypeFormat;
+
+    const COPY_IN_RESPONSE: &[u8] = b"\x01\x00\x02\x00\x01\x00\x01";
+
+    #[test]
+    fn it_decodes_copy_in_response() {
+        let message = CopyInResponse::decode(COPY_IN_RESPONSE).unwrap();
+
+        assert_eq!(message.format, TypeFormat::Binary);
+        assert_eq!(message.column_formats.len(), 2);
+        assert_eq!(message.column_formats[1], TypeFormat::Binary);
+    }
+}
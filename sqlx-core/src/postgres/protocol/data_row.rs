@@ -41,6 +41,9 @@ impl Decode for DataRow {
 
             if size == -1 {
                 values.push(None);
+
+                // skip over the length of the next value
+                index += 4;
             } else {
                 values.push(Some((index)..(index + (size as u32))));
 
@@ -96,4 +99,13 @@ mod tests {
             "DataRow([Some(b\"1\"), Some(b\"2\"), Some(b\"3\")])"
         );
     }
+
+    #[test]
+    fn it_decodes_data_row_with_null() {
+        let m = DataRow::decode(b"\0\x03\0\0\0\0\xff\xff\xff\xff\0\0\0\x013").unwrap();
+
+        assert_eq!(m.get(0), Some(&b""[..]));
+        assert_eq!(m.get(1), None);
+        assert_eq!(m.get(2), Some(&b"3"[..]));
+    }
 }
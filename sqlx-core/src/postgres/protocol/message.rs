@@ -1,6 +1,9 @@
+use bytes::Bytes;
+
 use crate::postgres::protocol::{
-    Authentication, BackendKeyData, CommandComplete, DataRow, NotificationResponse,
-    ParameterDescription, ParameterStatus, ReadyForQuery, Response, RowDescription,
+    Authentication, BackendKeyData, CommandComplete, CopyInResponse, CopyOutResponse, DataRow,
+    NotificationResponse, ParameterDescription, ParameterStatus, ReadyForQuery, Response,
+    RowDescription,
 };
 
 #[derive(Debug)]
@@ -21,4 +24,8 @@ pub enum Message {
     PortalSuspended,
     ParameterDescription(Box<ParameterDescription>),
     RowDescription(Box<RowDescription>),
+    CopyInResponse(Box<CopyInResponse>),
+    CopyOutResponse(Box<CopyOutResponse>),
+    CopyData(Bytes),
+    CopyDone,
 }
@@ -8,6 +8,9 @@
 mod bind;
 mod cancel_request;
 mod close;
+mod copy_data;
+mod copy_done;
+mod copy_fail;
 mod describe;
 mod encode;
 mod execute;
@@ -23,6 +26,9 @@ mod terminate;
 pub use bind::Bind;
 pub use cancel_request::CancelRequest;
 pub use close::Close;
+pub use copy_data::CopyData;
+pub use copy_done::CopyDone;
+pub use copy_fail::CopyFail;
 pub use describe::Describe;
 pub use encode::Encode;
 pub use execute::Execute;
@@ -38,6 +44,8 @@ pub use terminate::Terminate;
 mod authentication;
 mod backend_key_data;
 mod command_complete;
+mod copy_in_response;
+mod copy_out_response;
 mod data_row;
 mod decode;
 mod notification_response;
@@ -52,6 +60,8 @@ mod message;
 pub use authentication::Authentication;
 pub use backend_key_data::BackendKeyData;
 pub use command_complete::CommandComplete;
+pub use copy_in_response::CopyInResponse;
+pub use copy_out_response::CopyOutResponse;
 pub use data_row::DataRow;
 pub use decode::Decode;
 pub use message::Message;
@@ -170,7 +170,7 @@ impl Decode for Response {
                     internal_query = Some(field_value.into());
                 }
 
-                b'w' => {
+                b'W' => {
                     where_ = Some(field_value.into());
                 }
 
@@ -276,4 +276,19 @@ mod tests {
             "extension \"uuid-ossp\" already exists, skipping"
         );
     }
+
+    #[test]
+    fn it_decodes_response_with_where() {
+        let message = Response::decode(
+            b"SERROR\0VERROR\0C22P02\0Minvalid input syntax for type integer: \"x\"\0\
+              WCOPY copied, line 1, column id: \"x\"\0\0",
+        )
+        .unwrap();
+
+        assert_matches!(message.severity, Severity::Error);
+        assert_eq!(
+            &*message.where_.unwrap(),
+            "COPY copied, line 1, column id: \"x\""
+        );
+    }
 }
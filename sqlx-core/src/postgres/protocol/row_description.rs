@@ -56,7 +56,7 @@ impl Decode for RowDescription {
 
 #[cfg(test)]
 mod test {
-    use super::{Decode, RowDescription};
+    use super::{Decode, RowDescription, TypeFormat};
 
     #[test]
     fn it_decodes_row_description() {
@@ -79,9 +79,9 @@ mod test {
             0_u8, 0_u8, 0_u8, 0_u8, // table_id
             0_u8, 0_u8, // attr_num
             0_u8, 0_u8, 5_u8, 0_u8, // type_id
-            0_u8, 0_u8, // type_size
-            0_u8, 0_u8, 0_u8, 0_u8, // type_mod
-            0_u8, 0_u8 // format_code
+            0_u8, 4_u8, // type_size
+            255_u8, 255_u8, 255_u8, 255_u8, // type_mod
+            0_u8, 1_u8 // format_code
         };
 
         let desc = RowDescription::decode(&buf).unwrap();
@@ -89,6 +89,9 @@ mod test {
         assert_eq!(desc.fields.len(), 2);
         assert_eq!(desc.fields[0].type_id, 0x0000_0000);
         assert_eq!(desc.fields[1].type_id, 0x0000_0500);
+        assert_eq!(desc.fields[1].type_size, 4);
+        assert_eq!(desc.fields[1].type_mod, -1);
+        assert_eq!(desc.fields[1].type_format, TypeFormat::Binary);
     }
 
     #[test]
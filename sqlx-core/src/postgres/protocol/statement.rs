@@ -1,3 +1,5 @@
+use std::fmt::{self, Display};
+
 use crate::io::BufMut;
 use crate::postgres::protocol::Encode;
 
@@ -7,12 +9,17 @@ pub struct StatementId(pub u32);
 impl Encode for StatementId {
     fn encode(&self, buf: &mut Vec<u8>) {
         if self.0 != 0 {
-            buf.put_str("__sqlx_statement_");
-
             // TODO: Use [itoa]
-            buf.put_str_nul(&self.0.to_string());
+            buf.put_str_nul(&self.to_string());
         } else {
             buf.put_str_nul("");
         }
     }
 }
+
+// The name of the statement, e.g. to `EXECUTE` it
+impl Display for StatementId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "__sqlx_statement_{}", self.0)
+    }
+}
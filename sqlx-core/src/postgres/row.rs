@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use crate::decode::Decode;
 use crate::postgres::protocol::DataRow;
+use crate::postgres::types::TypeFormat;
 use crate::postgres::Postgres;
 use crate::row::{Row, RowIndex};
 use crate::types::HasSqlType;
@@ -10,22 +11,42 @@ use crate::types::HasSqlType;
 pub struct PgRow {
     pub(super) data: DataRow,
     pub(super) columns: Arc<HashMap<Box<str>, usize>>,
+    // rows of simple queries are sent in the text format
+    pub(super) format: TypeFormat,
 }
 
-impl Row for PgRow {
-    type Database = Postgres;
+impl PgRow {
+    pub(crate) fn column_index(&self, name: &str) -> crate::Result<usize> {
+        self.columns
+            .get(name)
+            .copied()
+            .ok_or_else(|| crate::Error::ColumnNotFound(name.into()))
+    }
 
-    fn len(&self) -> usize {
-        self.data.len()
+    // the value at `index` as it was received, or `None` for NULL
+    #[cfg(feature = "any")]
+    pub(crate) fn get_raw(&self, index: usize) -> Option<&[u8]> {
+        self.data.get(index)
     }
 
-    fn get<T, I>(&self, index: I) -> T
+    fn decode<T>(&self, index: usize) -> crate::Result<T>
     where
-        Self::Database: HasSqlType<T>,
-        I: RowIndex<Self>,
-        T: Decode<Self::Database>,
+        T: Decode<Postgres>,
     {
-        index.try_get(self).unwrap()
+        let raw = self.data.get(index);
+
+        Ok(match (self.format, raw) {
+            (TypeFormat::Text, Some(raw)) => T::decode_text(raw)?,
+            _ => T::decode_nullable(raw)?,
+        })
+    }
+}
+
+impl Row for PgRow {
+    type Database = Postgres;
+
+    fn len(&self) -> usize {
+        self.data.len()
     }
 }
 
@@ -35,7 +56,7 @@ impl RowIndex<PgRow> for usize {
         <PgRow as Row>::Database: HasSqlType<T>,
         T: Decode<<PgRow as Row>::Database>,
     {
-        Ok(Decode::decode_nullable(row.data.get(*self))?)
+        row.decode(*self)
     }
 }
 
@@ -45,13 +66,7 @@ impl RowIndex<PgRow> for &'_ str {
         <PgRow as Row>::Database: HasSqlType<T>,
         T: Decode<<PgRow as Row>::Database>,
     {
-        let index = row
-            .columns
-            .get(*self)
-            .ok_or_else(|| crate::Error::ColumnNotFound((*self).into()))?;
-        let value = Decode::decode_nullable(row.data.get(*index))?;
-
-        Ok(value)
+        row.decode(row.column_index(self)?)
     }
 }
 
@@ -0,0 +1,74 @@
+use crate::postgres::protocol::StatementId;
+use crate::postgres::{PgConnection, Postgres};
+use crate::query::{query, Query};
+
+/// A statement explicitly prepared with [PgConnection::prepare].
+///
+/// The statement lives on the connection it was prepared on until [PgStatement::close]
+/// is called or the connection is closed.
+pub struct PgStatement {
+    pub(super) id: StatementId,
+    query: Box<str>,
+}
+
+impl PgStatement {
+    pub(super) fn new(id: StatementId, query: &str) -> Self {
+        Self {
+            id,
+            query: query.into(),
+        }
+    }
+
+    /// Returns the SQL of the statement.
+    pub fn sql(&self) -> &str {
+        &self.query
+    }
+
+    /// Creates a [Query] which executes this statement.
+    ///
+    /// Executing the query on the connection the statement was prepared on reuses the
+    /// prepared statement instead of parsing the SQL again.
+    pub fn query(&self) -> Query<'_, Postgres> {
+        query(&self.query)
+    }
+
+    /// Deallocates the statement on the server.
+    pub async fn close(self, conn: &mut PgConnection) -> crate::Result<()> {
+        conn.close_statement(self).await
+    }
+}
+
+impl PgConnection {
+    /// Prepares a statement to be executed any number of times with different arguments.
+    ///
+    /// Statements are also prepared and cached automatically when queries are executed;
+    /// this allows preparing a statement ahead of time and controlling when it is
+    /// deallocated.
+    ///
+    /// ```rust,ignore
+    /// let statement = conn.prepare("SELECT $1::int4 + 1").await?;
+    ///
+    /// for i in 0..10 {
+    ///     let row = statement.query().bind(i).fetch_one(&mut conn).await?;
+    /// }
+    ///
+    /// statement.close(&mut conn).await?;
+    /// ```
+    pub async fn prepare(&mut self, query: &str) -> crate::Result<PgStatement> {
+        self.prepare_statement(query).await
+    }
+
+    /// Returns the number of prepared statements cached on this connection.
+    pub fn cached_statements_len(&self) -> usize {
+        self.statement_cache.len()
+    }
+
+    /// Deallocates all cached prepared statements on the server, e.g. after a schema change
+    /// invalidated their plans.
+    ///
+    /// This includes statements returned by [prepare](PgConnection::prepare); executing them
+    /// afterwards prepares them again.
+    pub async fn clear_cached_statements(&mut self) -> crate::Result<()> {
+        self.close_cached_statements().await
+    }
+}
@@ -0,0 +1,298 @@
+use byteorder::{ByteOrder, NetworkEndian};
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::{Encode, EncodeError, IsNull};
+use crate::io::{Buf, BufMut};
+use crate::postgres::types::PgTypeMetadata;
+use crate::postgres::Postgres;
+use crate::types::HasSqlType;
+
+/// Marker for types that may be sent and received as elements of a Postgres array.
+///
+/// This cannot be implemented outside of SQLx.
+pub trait PgArrayElement: private::Sealed {}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_array_element {
+    ($($ty:ty),*) => {
+        $(
+            impl private::Sealed for $ty {}
+            impl PgArrayElement for $ty {}
+        )*
+    };
+}
+
+impl_array_element!(bool, i16, i32, i64, u32, f32, f64, String);
+
+#[cfg(feature = "uuid")]
+impl_array_element!(uuid::Uuid);
+
+#[cfg(feature = "ipnetwork")]
+impl_array_element!(ipnetwork::IpNetwork, std::net::IpAddr);
+
+#[cfg(feature = "decimal")]
+impl_array_element!(rust_decimal::Decimal);
+
+#[cfg(feature = "smol_str")]
+impl_array_element!(smol_str::SmolStr);
+
+#[cfg(feature = "compact_str")]
+impl_array_element!(compact_str::CompactString);
+
+#[cfg(feature = "chrono")]
+impl_array_element!(
+    chrono::NaiveDate,
+    chrono::NaiveTime,
+    chrono::NaiveDateTime,
+    chrono::DateTime<chrono::Utc>,
+    chrono::Duration
+);
+
+impl private::Sealed for &'_ str {}
+impl PgArrayElement for &'_ str {}
+
+impl<T: PgArrayElement> private::Sealed for Option<T> {}
+impl<T: PgArrayElement> PgArrayElement for Option<T> {}
+
+impl<T> HasSqlType<[T]> for Postgres
+where
+    T: PgArrayElement,
+    Postgres: HasSqlType<T>,
+{
+    fn metadata() -> PgTypeMetadata {
+        PgTypeMetadata::binary(<Postgres as HasSqlType<T>>::metadata().array_oid, 0)
+    }
+}
+
+impl<T> HasSqlType<Vec<T>> for Postgres
+where
+    T: PgArrayElement,
+    Postgres: HasSqlType<T>,
+{
+    fn metadata() -> PgTypeMetadata {
+        <Postgres as HasSqlType<[T]>>::metadata()
+    }
+}
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/arrayfuncs.c
+// `array_send` and `array_recv`
+impl<T> Encode<Postgres> for [T]
+where
+    T: PgArrayElement + Encode<Postgres>,
+    Postgres: HasSqlType<T>,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        // number of dimensions; an empty array has none
+        buf.put_i32::<NetworkEndian>(if self.is_empty() { 0 } else { 1 });
+
+        // flags; written back below if any element was NULL
+        let flags = buf.len();
+        buf.put_i32::<NetworkEndian>(0);
+
+        buf.put_u32::<NetworkEndian>(<Postgres as HasSqlType<T>>::metadata().oid);
+
+        if self.is_empty() {
+            return;
+        }
+
+        // length and lower bound of the single dimension
+        buf.put_i32::<NetworkEndian>(self.len() as i32);
+        buf.put_i32::<NetworkEndian>(1);
+
+        let mut has_null = false;
+
+        for element in self {
+            let pos = buf.len();
+            buf.put_i32::<NetworkEndian>(0);
+
+            let len = if let IsNull::No = element.encode_nullable(buf) {
+                (buf.len() - pos - 4) as i32
+            } else {
+                has_null = true;
+                -1
+            };
+
+            NetworkEndian::write_i32(&mut buf[pos..], len);
+        }
+
+        if has_null {
+            NetworkEndian::write_i32(&mut buf[flags..], 1);
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        // header, dimension and a length prefix for every element
+        20 + self.iter().map(|el| 4 + el.size_hint()).sum::<usize>()
+    }
+
+    fn check(&self) -> Result<(), EncodeError> {
+        self.iter().try_for_each(Encode::check)
+    }
+}
+
+impl<T> Encode<Postgres> for Vec<T>
+where
+    T: PgArrayElement + Encode<Postgres>,
+    Postgres: HasSqlType<T>,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <[T] as Encode<Postgres>>::encode(self, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <[T] as Encode<Postgres>>::size_hint(self)
+    }
+
+    fn check(&self) -> Result<(), EncodeError> {
+        <[T] as Encode<Postgres>>::check(self)
+    }
+}
+
+impl<T> HasSqlType<Vec<Vec<T>>> for Postgres
+where
+    T: PgArrayElement,
+    Postgres: HasSqlType<T>,
+{
+    // Postgres has no distinct type for arrays of arrays, `int4[][]` is just `int4[]`
+    fn metadata() -> PgTypeMetadata {
+        <Postgres as HasSqlType<[T]>>::metadata()
+    }
+}
+
+impl<T> Decode<Postgres> for Vec<T>
+where
+    T: PgArrayElement + Decode<Postgres>,
+    Postgres: HasSqlType<T>,
+{
+    fn decode(mut buf: &[u8]) -> Result<Self, DecodeError> {
+        let len = match decode_dimensions(&mut buf, 1)?.as_slice() {
+            [len] => *len,
+            _ => return Ok(Vec::new()),
+        };
+
+        (0..len).map(|_| decode_element(&mut buf)).collect()
+    }
+}
+
+impl<T> Decode<Postgres> for Vec<Vec<T>>
+where
+    T: PgArrayElement + Decode<Postgres>,
+    Postgres: HasSqlType<T>,
+{
+    fn decode(mut buf: &[u8]) -> Result<Self, DecodeError> {
+        let (rows, columns) = match decode_dimensions(&mut buf, 2)?.as_slice() {
+            [rows, columns] => (*rows, *columns),
+            _ => return Ok(Vec::new()),
+        };
+
+        // the elements are stored in row-major order
+        (0..rows)
+            .map(|_| (0..columns).map(|_| decode_element(&mut buf)).collect())
+            .collect()
+    }
+}
+
+// Reads the header of an array, returning the length of every dimension or nothing
+// for an empty array.
+fn decode_dimensions(buf: &mut &[u8], expected: i32) -> Result<Vec<usize>, DecodeError> {
+    let ndim = buf.get_i32::<NetworkEndian>()?;
+
+    // flags (has NULL elements) and the OID of the elements
+    buf.advance(8);
+
+    if ndim == 0 {
+        return Ok(Vec::new());
+    }
+
+    if ndim != expected {
+        return Err(DecodeError::Message(Box::new(format!(
+            "expected an array of {} dimension(s) but received {} dimensions",
+            expected, ndim
+        ))));
+    }
+
+    (0..ndim)
+        .map(|_| {
+            let len = buf.get_i32::<NetworkEndian>()? as usize;
+
+            // lower bound of the dimension
+            buf.advance(4);
+
+            Ok(len)
+        })
+        .collect()
+}
+
+fn decode_element<T>(buf: &mut &[u8]) -> Result<T, DecodeError>
+where
+    T: Decode<Postgres>,
+{
+    let size = buf.get_i32::<NetworkEndian>()?;
+
+    if size < 0 {
+        T::decode_null()
+    } else {
+        T::decode(buf.get_bytes(size as usize)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decode, Encode, Postgres};
+
+    const INT4_ARRAY: &[u8] =
+        b"\0\0\0\x01\0\0\0\0\0\0\0\x17\0\0\0\x03\0\0\0\x01\0\0\0\x04\0\0\0\x01\0\0\0\x04\0\0\0\x02\0\0\0\x04\0\0\0\x03";
+
+    #[test]
+    fn it_encodes_slice() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&[1_i32, 2, 3][..], &mut buf);
+
+        assert_eq!(buf, INT4_ARRAY);
+    }
+
+    #[test]
+    fn it_encodes_empty_slice() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&[0_i32; 0][..], &mut buf);
+
+        assert_eq!(buf, b"\0\0\0\0\0\0\0\0\0\0\0\x17");
+    }
+
+    #[test]
+    fn it_encodes_null_elements() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&[Some(1_i32), None][..], &mut buf);
+
+        assert_eq!(
+            buf,
+            &b"\0\0\0\x01\0\0\0\x01\0\0\0\x17\0\0\0\x02\0\0\0\x01\0\0\0\x04\0\0\0\x01\xff\xff\xff\xff"[..]
+        );
+    }
+
+    #[test]
+    fn it_decodes_vec() {
+        let values: Vec<i32> = Decode::<Postgres>::decode(INT4_ARRAY).unwrap();
+
+        assert_eq!(values, [1, 2, 3]);
+    }
+
+    // '{{1,2},{3,4}}'::int4[][]
+    const INT4_ARRAY_2D: &[u8] = b"\0\0\0\x02\0\0\0\0\0\0\0\x17\0\0\0\x02\0\0\0\x01\0\0\0\x02\0\0\0\x01\0\0\0\x04\0\0\0\x01\0\0\0\x04\0\0\0\x02\0\0\0\x04\0\0\0\x03\0\0\0\x04\0\0\0\x04";
+
+    #[test]
+    fn it_decodes_nested_vec() {
+        let values: Vec<Vec<i32>> = Decode::<Postgres>::decode(INT4_ARRAY_2D).unwrap();
+
+        assert_eq!(values, [[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn it_rejects_mismatched_dimensions() {
+        assert!(<Vec<Vec<i32>> as Decode<Postgres>>::decode(INT4_ARRAY).is_err());
+        assert!(<Vec<i32> as Decode<Postgres>>::decode(INT4_ARRAY_2D).is_err());
+    }
+}
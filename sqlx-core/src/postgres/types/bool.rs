@@ -6,7 +6,7 @@ use crate::types::HasSqlType;
 
 impl HasSqlType<bool> for Postgres {
     fn metadata() -> PgTypeMetadata {
-        PgTypeMetadata::binary(16, 100)
+        PgTypeMetadata::binary(16, 1000)
     }
 }
 
@@ -16,13 +16,61 @@ impl Encode<Postgres> for bool {
     }
 }
 
+// Rows are normally received in the binary format, a single `0` or `1` byte, but the TEXT
+// format used by the simple query protocol sends `t` or `f`.
+
 impl Decode<Postgres> for bool {
     fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
-        match buf.len() {
-            0 => Err(DecodeError::Message(Box::new(
-                "Expected minimum 1 byte but received none.",
-            ))),
-            _ => Ok(buf[0] != 0),
+        match buf {
+            [value] => Ok(*value != 0),
+
+            _ => Err(DecodeError::Message(Box::new(format!(
+                "expected 1 byte for boolean, received {}",
+                buf.len()
+            )))),
         }
     }
+
+    fn decode_text(buf: &[u8]) -> Result<Self, DecodeError> {
+        match buf {
+            b"t" | b"true" => Ok(true),
+            b"f" | b"false" => Ok(false),
+
+            _ => Err(DecodeError::Message(Box::new(format!(
+                "unexpected value {:?} for boolean",
+                String::from_utf8_lossy(buf)
+            )))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decode, Postgres};
+
+    #[test]
+    fn it_decodes_binary_bool() {
+        assert!(<bool as Decode<Postgres>>::decode(&[1]).unwrap());
+        assert!(!<bool as Decode<Postgres>>::decode(&[0]).unwrap());
+        assert!(<bool as Decode<Postgres>>::decode(&[]).is_err());
+        assert!(<bool as Decode<Postgres>>::decode(b"true").is_err());
+    }
+
+    #[test]
+    fn it_decodes_text_bool() {
+        assert!(<bool as Decode<Postgres>>::decode_text(b"t").unwrap());
+        assert!(!<bool as Decode<Postgres>>::decode_text(b"f").unwrap());
+        assert!(<bool as Decode<Postgres>>::decode_text(b"true").unwrap());
+        assert!(!<bool as Decode<Postgres>>::decode_text(b"false").unwrap());
+        assert!(<bool as Decode<Postgres>>::decode_text(b"maybe").is_err());
+
+        assert_eq!(
+            <Option<bool> as Decode<Postgres>>::decode_text(b"f").unwrap(),
+            Some(false)
+        );
+        assert_eq!(
+            <Option<bool> as Decode<Postgres>>::decode_nullable(None).unwrap(),
+            None
+        );
+    }
 }
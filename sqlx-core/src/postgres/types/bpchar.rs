@@ -0,0 +1,82 @@
+use std::fmt::{self, Display};
+use std::ops::Deref;
+use std::str;
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::postgres::types::PgTypeMetadata;
+use crate::postgres::Postgres;
+use crate::types::HasSqlType;
+
+/// A value of `CHAR(n)` (`BPCHAR`) without its trailing spaces.
+///
+/// Values of `CHAR(n)` are padded with spaces to `n` characters, which are ignored when they are
+/// compared in SQL but are kept when decoding into a `String`, so that e.g. `'ab'::char(3)`
+/// decodes to `"ab "`. Decoding into `PgBpChar` trims the padding instead, e.g. with
+/// `query!(r#"SELECT code as "code: PgBpChar" FROM countries"#)`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PgBpChar(pub String);
+
+impl From<String> for PgBpChar {
+    fn from(s: String) -> Self {
+        PgBpChar(s)
+    }
+}
+
+impl From<&'_ str> for PgBpChar {
+    fn from(s: &str) -> Self {
+        PgBpChar(s.to_owned())
+    }
+}
+
+impl Deref for PgBpChar {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for PgBpChar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl HasSqlType<PgBpChar> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        PgTypeMetadata::binary(1042, 1014)
+    }
+}
+
+impl Encode<Postgres> for PgBpChar {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.0.as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl Decode<Postgres> for PgBpChar {
+    fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
+        Ok(PgBpChar(
+            str::from_utf8(buf)?.trim_end_matches(' ').to_owned(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decode, PgBpChar, Postgres};
+
+    #[test]
+    fn it_trims_bpchar() {
+        let decode = |buf| <PgBpChar as Decode<Postgres>>::decode(buf).unwrap();
+
+        assert_eq!(decode(b"ab  "), PgBpChar::from("ab"));
+        assert_eq!(decode(b" a b"), PgBpChar::from(" a b"));
+        assert_eq!(decode(b"   "), PgBpChar::from(""));
+    }
+}
@@ -1,9 +1,13 @@
 use crate::decode::{Decode, DecodeError};
-use crate::encode::Encode;
+use crate::encode::{Encode, EncodeError};
+use crate::io::Buf;
 use crate::postgres::types::PgTypeMetadata;
 use crate::postgres::Postgres;
 use crate::types::HasSqlType;
-use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use byteorder::NetworkEndian;
+use chrono::{
+    DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
+};
 use std::convert::TryInto;
 use std::mem;
 
@@ -34,10 +38,24 @@ where
     }
 }
 
+impl HasSqlType<Duration> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        PgTypeMetadata::binary(1186, 1187)
+    }
+}
+
 impl Decode<Postgres> for NaiveTime {
     fn decode(raw: &[u8]) -> Result<Self, DecodeError> {
         let micros: i64 = Decode::<Postgres>::decode(raw)?;
 
+        // `24:00:00` is a valid `time` but would wrap around to midnight
+        if !(0..Duration::days(1).num_microseconds().unwrap()).contains(&micros) {
+            return Err(DecodeError::Message(Box::new(format!(
+                "Postgres time out of range for NaiveTime: {:?}",
+                micros
+            ))));
+        }
+
         Ok(NaiveTime::from_hms(0, 0, 0) + Duration::microseconds(micros))
     }
 }
@@ -60,7 +78,15 @@ impl Decode<Postgres> for NaiveDate {
     fn decode(raw: &[u8]) -> Result<Self, DecodeError> {
         let days: i32 = Decode::<Postgres>::decode(raw)?;
 
-        Ok(NaiveDate::from_ymd(2000, 1, 1) + Duration::days(days as i64))
+        // e.g. `infinity`, which is sent as the largest offset
+        NaiveDate::from_ymd(2000, 1, 1)
+            .checked_add_signed(Duration::days(days as i64))
+            .ok_or_else(|| {
+                DecodeError::Message(Box::new(format!(
+                    "Postgres date out of range for NaiveDate: {:?}",
+                    days
+                )))
+            })
     }
 }
 
@@ -99,7 +125,13 @@ impl Decode<Postgres> for NaiveDateTime {
 
 impl Encode<Postgres> for NaiveDateTime {
     fn encode(&self, buf: &mut Vec<u8>) {
-        let micros = self
+        // Postgres stores microseconds; the nanoseconds are truncated here as a negative
+        // `Duration` is truncated towards the epoch, moving a time before it forward
+        let truncated = self
+            .with_nanosecond(self.nanosecond() / 1_000 * 1_000)
+            .expect("truncating the nanoseconds cannot overflow");
+
+        let micros = truncated
             .signed_duration_since(postgres_epoch().naive_utc())
             .num_microseconds()
             .unwrap_or_else(|| panic!("NaiveDateTime out of range for Postgres: {:?}", self));
@@ -112,6 +144,10 @@ impl Encode<Postgres> for NaiveDateTime {
     }
 }
 
+// A `timestamptz` is sent as microseconds since the Postgres epoch in UTC regardless of the
+// `TimeZone` of the session, which only affects the text format. Decoding as `DateTime<Local>`
+// converts to the time zone of this process, not of the session; the session time zone is
+// available from `PgConnection::parameter_statuses` to convert a `DateTime<Utc>` explicitly.
 impl Decode<Postgres> for DateTime<Utc> {
     fn decode(raw: &[u8]) -> Result<Self, DecodeError> {
         let date_time = Decode::<Postgres>::decode(raw)?;
@@ -139,6 +175,51 @@ where
     }
 }
 
+// An interval is sent as microseconds, days and months. A `Duration` has no notion of the
+// calendar so it is always encoded as microseconds only, leaving days and months at zero.
+impl Encode<Postgres> for Duration {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        // rejected by `check` if out of range
+        let micros = self.num_microseconds().unwrap_or_default();
+
+        Encode::<Postgres>::encode(&micros, buf);
+        Encode::<Postgres>::encode(&0_i32, buf);
+        Encode::<Postgres>::encode(&0_i32, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        2 * mem::size_of::<i64>()
+    }
+
+    fn check(&self) -> Result<(), EncodeError> {
+        match self.num_microseconds() {
+            Some(_) => Ok(()),
+            None => Err(EncodeError::new(format_args!(
+                "Duration out of range for a Postgres interval: {:?}",
+                self
+            ))),
+        }
+    }
+}
+
+impl Decode<Postgres> for Duration {
+    fn decode(mut raw: &[u8]) -> Result<Self, DecodeError> {
+        let micros = raw.get_u64::<NetworkEndian>()? as i64;
+        let days = raw.get_i32::<NetworkEndian>()?;
+        let months = raw.get_i32::<NetworkEndian>()?;
+
+        // the length of a month depends on the date it is applied to
+        if months != 0 {
+            return Err(DecodeError::Message(Box::new(format!(
+                "Postgres interval with {} months cannot be represented as Duration",
+                months
+            ))));
+        }
+
+        Ok(Duration::microseconds(micros) + Duration::days(days as i64))
+    }
+}
+
 fn postgres_epoch() -> DateTime<Utc> {
     Utc.ymd(2000, 1, 1).and_hms(0, 0, 0)
 }
@@ -183,6 +264,21 @@ fn test_decode_datetime() {
     assert_eq!(date.to_string(), "2019-12-11 11:01:05");
 }
 
+#[test]
+fn test_encode_datetime_before_epoch() {
+    let mut buf = Vec::new();
+
+    // half a microsecond before the epoch is still in 1999
+    let date = postgres_epoch().naive_utc() - Duration::nanoseconds(500);
+    Encode::<Postgres>::encode(&date, &mut buf);
+    assert_eq!(buf, (-1i64).to_be_bytes());
+    buf.clear();
+
+    let date: NaiveDateTime = "1969-07-20T20:17:40.1234569".parse().unwrap();
+    Encode::<Postgres>::encode(&date, &mut buf);
+    assert_eq!(buf, (-960_867_739_876_544i64).to_be_bytes());
+}
+
 #[test]
 fn test_encode_date() {
     let mut buf = Vec::new();
@@ -217,4 +313,51 @@ fn test_decode_date() {
     let buf = 7284i32.to_be_bytes();
     let date: NaiveDate = Decode::<Postgres>::decode(&buf).unwrap();
     assert_eq!(date.to_string(), "2019-12-11");
+
+    let buf = (-36524i32).to_be_bytes();
+    let date: NaiveDate = Decode::<Postgres>::decode(&buf).unwrap();
+    assert_eq!(date.to_string(), "1900-01-01");
+
+    // `infinity`
+    let buf = i32::MAX.to_be_bytes();
+    assert!(<NaiveDate as Decode<Postgres>>::decode(&buf).is_err());
+}
+
+#[test]
+fn test_decode_time() {
+    let buf = 86_399_999_999i64.to_be_bytes();
+    let time: NaiveTime = Decode::<Postgres>::decode(&buf).unwrap();
+    assert_eq!(time.to_string(), "23:59:59.999999");
+
+    // `24:00:00`
+    let buf = 86_400_000_000i64.to_be_bytes();
+    assert!(<NaiveTime as Decode<Postgres>>::decode(&buf).is_err());
+}
+
+#[test]
+fn test_encode_duration() {
+    let mut buf = Vec::new();
+
+    Encode::<Postgres>::encode(&Duration::hours(1), &mut buf);
+    assert_eq!(&buf[..8], 3_600_000_000i64.to_be_bytes());
+    assert_eq!(&buf[8..], [0; 8]);
+
+    assert!(Encode::<Postgres>::check(&Duration::hours(1)).is_ok());
+    // more microseconds than fit in an `i64`
+    assert!(Encode::<Postgres>::check(&Duration::max_value()).is_err());
+}
+
+#[test]
+fn test_decode_duration() {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&1_000_000i64.to_be_bytes());
+    buf.extend_from_slice(&2i32.to_be_bytes());
+    buf.extend_from_slice(&0i32.to_be_bytes());
+
+    let duration: Duration = Decode::<Postgres>::decode(&buf).unwrap();
+    assert_eq!(duration, Duration::days(2) + Duration::seconds(1));
+
+    // 1 month
+    buf[12..].copy_from_slice(&1i32.to_be_bytes());
+    assert!(<Duration as Decode<Postgres>>::decode(&buf).is_err());
 }
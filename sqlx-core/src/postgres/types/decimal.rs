@@ -0,0 +1,172 @@
+use byteorder::NetworkEndian;
+use rust_decimal::Decimal;
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::io::{Buf, BufMut};
+use crate::postgres::types::PgTypeMetadata;
+use crate::postgres::Postgres;
+use crate::types::HasSqlType;
+
+impl HasSqlType<Decimal> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        PgTypeMetadata::binary(1700, 1231)
+    }
+}
+
+// A numeric is sent as its number of base-10000 digits, the weight (the exponent of 10000) of the
+// first digit, the sign, the display scale (the number of decimal digits after the point) and
+// then the digits themselves.
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/numeric.c
+// `numeric_send` and `numeric_recv`
+const SIGN_POSITIVE: u16 = 0x0000;
+const SIGN_NEGATIVE: u16 = 0x4000;
+
+impl Encode<Postgres> for Decimal {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let scale = self.scale();
+        let mut mantissa = self.mantissa().unsigned_abs();
+
+        // align the digits on the decimal point so every base-10000 digit is either fully
+        // before or fully after it
+        let padding = (4 - scale % 4) % 4;
+        mantissa *= 10_u128.pow(padding);
+
+        let mut digits = Vec::new();
+
+        while mantissa != 0 {
+            digits.push((mantissa % 10_000) as i16);
+            mantissa /= 10_000;
+        }
+
+        digits.reverse();
+
+        let weight = digits.len() as i16 - ((scale + padding) / 4) as i16 - 1;
+
+        // trailing zeros are implied by the weight
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        buf.put_i16::<NetworkEndian>(digits.len() as i16);
+        buf.put_i16::<NetworkEndian>(if digits.is_empty() { 0 } else { weight });
+        buf.put_u16::<NetworkEndian>(if self.is_sign_negative() && !digits.is_empty() {
+            SIGN_NEGATIVE
+        } else {
+            SIGN_POSITIVE
+        });
+        buf.put_u16::<NetworkEndian>(scale as u16);
+
+        for digit in digits {
+            buf.put_i16::<NetworkEndian>(digit);
+        }
+    }
+}
+
+impl Decode<Postgres> for Decimal {
+    fn decode(mut buf: &[u8]) -> Result<Self, DecodeError> {
+        let num_digits = buf.get_i16::<NetworkEndian>()?;
+        let weight = buf.get_i16::<NetworkEndian>()?;
+        let sign = buf.get_u16::<NetworkEndian>()?;
+        let scale = buf.get_u16::<NetworkEndian>()? as u32;
+
+        if sign != SIGN_POSITIVE && sign != SIGN_NEGATIVE {
+            // `NaN`, `Infinity` and `-Infinity`
+            return Err(DecodeError::Message(Box::new(
+                "a NUMERIC that is not a finite number cannot be decoded as a Decimal",
+            )));
+        }
+
+        let overflow = || {
+            DecodeError::Message(Box::new(format!(
+                "NUMERIC value does not fit in a Decimal (at most 28 digits and a scale of {})",
+                Decimal::MAX_SCALE
+            )))
+        };
+
+        let mut mantissa: i128 = 0;
+
+        for _ in 0..num_digits {
+            let digit = buf.get_i16::<NetworkEndian>()?;
+
+            mantissa = mantissa
+                .checked_mul(10_000)
+                .and_then(|mantissa| mantissa.checked_add(digit as i128))
+                .ok_or_else(overflow)?;
+        }
+
+        // the exponent of 10 of the last digit
+        let exponent = 4 * (weight as i32 - num_digits as i32 + 1);
+
+        // shift the mantissa to the display scale; digits past it are always zero
+        let shift = exponent + scale as i32;
+
+        if shift >= 0 {
+            mantissa = 10_i128
+                .checked_pow(shift as u32)
+                .and_then(|factor| mantissa.checked_mul(factor))
+                .ok_or_else(overflow)?;
+        } else {
+            mantissa /= 10_i128.checked_pow(-shift as u32).ok_or_else(overflow)?;
+        }
+
+        if sign == SIGN_NEGATIVE {
+            mantissa = -mantissa;
+        }
+
+        Decimal::try_from_i128_with_scale(mantissa, scale).map_err(|_| overflow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decimal, Decode, Encode, Postgres};
+    use std::str::FromStr;
+
+    // 12345.678
+    const NUMERIC: &[u8] = b"\0\x03\0\x01\0\0\0\x03\0\x01\x09\x29\x1a\x7c";
+
+    // -0.00001
+    const NUMERIC_SMALL: &[u8] = b"\0\x01\xff\xfe\x40\0\0\x05\x03\xe8";
+
+    // 100000.00
+    const NUMERIC_TRAILING_ZEROS: &[u8] = b"\0\x01\0\x01\0\0\0\x02\0\x0a";
+
+    fn decimal(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn encode(value: Decimal) -> Vec<u8> {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&value, &mut buf);
+
+        buf
+    }
+
+    #[test]
+    fn it_encodes_decimal() {
+        assert_eq!(encode(decimal("12345.678")), NUMERIC);
+        assert_eq!(encode(decimal("-0.00001")), NUMERIC_SMALL);
+        assert_eq!(encode(decimal("100000.00")), NUMERIC_TRAILING_ZEROS);
+        assert_eq!(encode(decimal("0.0")), b"\0\0\0\0\0\0\0\x01");
+    }
+
+    #[test]
+    fn it_decodes_decimal() {
+        let decode = |buf| <Decimal as Decode<Postgres>>::decode(buf).unwrap();
+
+        assert_eq!(decode(NUMERIC).to_string(), "12345.678");
+        assert_eq!(decode(NUMERIC_SMALL).to_string(), "-0.00001");
+        assert_eq!(decode(NUMERIC_TRAILING_ZEROS).to_string(), "100000.00");
+        assert_eq!(decode(b"\0\0\0\0\0\0\0\x01").to_string(), "0.0");
+    }
+
+    #[test]
+    fn it_rejects_decimal_overflow() {
+        // 10^32
+        assert!(<Decimal as Decode<Postgres>>::decode(b"\0\x01\0\x08\0\0\0\0\0\x01").is_err());
+
+        // NaN
+        assert!(<Decimal as Decode<Postgres>>::decode(b"\0\0\0\0\xc0\0\0\0").is_err());
+    }
+}
@@ -3,6 +3,28 @@ use crate::encode::Encode;
 use crate::postgres::types::PgTypeMetadata;
 use crate::postgres::Postgres;
 use crate::types::HasSqlType;
+use std::str::{self, FromStr};
+
+// Results of prepared statements are requested in the binary format, an IEEE 754
+// representation that includes the special values. Rows of simple queries are sent as text,
+// where those are spelled `Infinity`, `-Infinity` and `NaN`.
+
+fn decode_text<T: FromStr>(buf: &[u8]) -> Result<T, DecodeError> {
+    let text = str::from_utf8(buf)?;
+
+    let parsed = match text {
+        "Infinity" => "inf".parse(),
+        "-Infinity" => "-inf".parse(),
+        _ => text.parse(),
+    };
+
+    parsed.map_err(|_| {
+        DecodeError::Message(Box::new(format!(
+            "invalid floating point number: {:?}",
+            text
+        )))
+    })
+}
 
 impl HasSqlType<f32> for Postgres {
     fn metadata() -> PgTypeMetadata {
@@ -22,6 +44,10 @@ impl Decode<Postgres> for f32 {
             <i32 as Decode<Postgres>>::decode(buf)? as u32
         ))
     }
+
+    fn decode_text(buf: &[u8]) -> Result<Self, DecodeError> {
+        decode_text(buf)
+    }
 }
 
 impl HasSqlType<f64> for Postgres {
@@ -42,4 +68,34 @@ impl Decode<Postgres> for f64 {
             <i64 as Decode<Postgres>>::decode(buf)? as u64
         ))
     }
+
+    fn decode_text(buf: &[u8]) -> Result<Self, DecodeError> {
+        decode_text(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_decodes_text() {
+        assert_eq!(<f32 as Decode<Postgres>>::decode_text(b"1.5").unwrap(), 1.5);
+        assert_eq!(
+            <f64 as Decode<Postgres>>::decode_text(b"-2e-05").unwrap(),
+            -2e-05
+        );
+        assert_eq!(
+            <f64 as Decode<Postgres>>::decode_text(b"Infinity").unwrap(),
+            f64::INFINITY
+        );
+        assert_eq!(
+            <f32 as Decode<Postgres>>::decode_text(b"-Infinity").unwrap(),
+            f32::NEG_INFINITY
+        );
+        assert!(<f64 as Decode<Postgres>>::decode_text(b"NaN")
+            .unwrap()
+            .is_nan());
+        assert!(<f64 as Decode<Postgres>>::decode_text(b"one").is_err());
+    }
 }
@@ -0,0 +1,53 @@
+use geo_types::Point;
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::postgres::types::{PgPoint, PgTypeMetadata};
+use crate::postgres::Postgres;
+use crate::types::HasSqlType;
+
+// `point` is sent and received like a `PgPoint`
+
+impl HasSqlType<Point<f64>> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        <Postgres as HasSqlType<PgPoint>>::metadata()
+    }
+}
+
+impl Encode<Postgres> for Point<f64> {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        Encode::<Postgres>::encode(&PgPoint::new(self.x(), self.y()), buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        Encode::<Postgres>::size_hint(&PgPoint::default())
+    }
+}
+
+impl Decode<Postgres> for Point<f64> {
+    fn decode(raw: &[u8]) -> Result<Self, DecodeError> {
+        let point: PgPoint = Decode::<Postgres>::decode(raw)?;
+
+        Ok(Point::new(point.x, point.y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decode, Encode, PgPoint, Point, Postgres};
+
+    #[test]
+    fn it_round_trips_point() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&Point::new(1.5, -2.0), &mut buf);
+
+        let mut expected = Vec::new();
+        Encode::<Postgres>::encode(&PgPoint::new(1.5, -2.0), &mut expected);
+
+        assert_eq!(buf, expected);
+
+        let point: Point<f64> = Decode::<Postgres>::decode(&buf).unwrap();
+
+        assert_eq!(point, Point::new(1.5, -2.0));
+    }
+}
@@ -58,3 +58,22 @@ impl Decode<Postgres> for i64 {
         Ok(NetworkEndian::read_i64(buf))
     }
 }
+
+// `oid`, which is unsigned
+impl HasSqlType<u32> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        PgTypeMetadata::binary(26, 1028)
+    }
+}
+
+impl Encode<Postgres> for u32 {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.to_be_bytes());
+    }
+}
+
+impl Decode<Postgres> for u32 {
+    fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
+        Ok(NetworkEndian::read_u32(buf))
+    }
+}
@@ -0,0 +1,90 @@
+use std::mem;
+
+use byteorder::NetworkEndian;
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::io::Buf;
+use crate::postgres::types::PgTypeMetadata;
+use crate::postgres::Postgres;
+use crate::types::HasSqlType;
+
+/// A value of `INTERVAL`, kept as the separate components Postgres stores it with.
+///
+/// The length of a month or a day depends on the date the interval is applied to, so the
+/// components are not normalized into each other and each may have its own sign, e.g.
+/// `-1 mon 5 days -03:00:00`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PgInterval {
+    pub months: i32,
+    pub days: i32,
+    pub microseconds: i64,
+}
+
+impl PgInterval {
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        Self {
+            months,
+            days,
+            microseconds,
+        }
+    }
+}
+
+impl HasSqlType<PgInterval> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        PgTypeMetadata::binary(1186, 1187)
+    }
+}
+
+// An interval is sent as the microseconds, then the days and then the months.
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/timestamp.c
+// `interval_send` and `interval_recv`
+impl Encode<Postgres> for PgInterval {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        Encode::<Postgres>::encode(&self.microseconds, buf);
+        Encode::<Postgres>::encode(&self.days, buf);
+        Encode::<Postgres>::encode(&self.months, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        2 * mem::size_of::<i64>()
+    }
+}
+
+impl Decode<Postgres> for PgInterval {
+    fn decode(mut raw: &[u8]) -> Result<Self, DecodeError> {
+        let microseconds = raw.get_u64::<NetworkEndian>()? as i64;
+        let days = raw.get_i32::<NetworkEndian>()?;
+        let months = raw.get_i32::<NetworkEndian>()?;
+
+        Ok(Self {
+            months,
+            days,
+            microseconds,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decode, Encode, PgInterval, Postgres};
+
+    // -1 mon 5 days -03:00:00
+    const INTERVAL: &[u8] = b"\xff\xff\xff\xfd\x7c\x45\x14\0\0\0\0\x05\xff\xff\xff\xff";
+
+    #[test]
+    fn it_encodes_interval() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&PgInterval::new(-1, 5, -3 * 3_600_000_000), &mut buf);
+
+        assert_eq!(buf, INTERVAL);
+    }
+
+    #[test]
+    fn it_decodes_interval() {
+        let interval: PgInterval = Decode::<Postgres>::decode(INTERVAL).unwrap();
+
+        assert_eq!(interval, PgInterval::new(-1, 5, -3 * 3_600_000_000));
+    }
+}
@@ -0,0 +1,157 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use ipnetwork::IpNetwork;
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::postgres::types::PgTypeMetadata;
+use crate::postgres::Postgres;
+use crate::types::HasSqlType;
+
+// `inet` and `cidr` are sent as the address family, the netmask length, whether the value is
+// a `cidr`, the number of bytes of the address and the address in network order
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/network.c
+
+// `AF_INET` and `AF_INET6` as defined by Postgres, independently of the platform
+const PGSQL_AF_INET: u8 = 2;
+const PGSQL_AF_INET6: u8 = 3;
+
+// `inet` holds a host or a network, `cidr` always holds a network; both are received as an
+// `IpNetwork` while an `IpAddr` is only received from a host
+impl HasSqlType<IpNetwork> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        PgTypeMetadata::binary_compatible(869, 1041, &[650])
+    }
+}
+
+impl HasSqlType<IpAddr> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        PgTypeMetadata::binary(869, 1041)
+    }
+}
+
+impl Encode<Postgres> for IpNetwork {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            IpNetwork::V4(net) => {
+                buf.extend_from_slice(&[PGSQL_AF_INET, net.prefix(), 0, 4]);
+                buf.extend_from_slice(&net.ip().octets());
+            }
+
+            IpNetwork::V6(net) => {
+                buf.extend_from_slice(&[PGSQL_AF_INET6, net.prefix(), 0, 16]);
+                buf.extend_from_slice(&net.ip().octets());
+            }
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        match self {
+            IpNetwork::V4(_) => 8,
+            IpNetwork::V6(_) => 20,
+        }
+    }
+}
+
+impl Encode<Postgres> for IpAddr {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        IpNetwork::from(*self).encode(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        IpNetwork::from(*self).size_hint()
+    }
+}
+
+impl Decode<Postgres> for IpNetwork {
+    fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
+        let (ip, prefix) = match buf {
+            [PGSQL_AF_INET, prefix, _, 4, addr @ ..] if addr.len() == 4 => {
+                let mut octets = [0; 4];
+                octets.copy_from_slice(addr);
+
+                (IpAddr::V4(Ipv4Addr::from(octets)), *prefix)
+            }
+
+            [PGSQL_AF_INET6, prefix, _, 16, addr @ ..] if addr.len() == 16 => {
+                let mut octets = [0; 16];
+                octets.copy_from_slice(addr);
+
+                (IpAddr::V6(Ipv6Addr::from(octets)), *prefix)
+            }
+
+            _ => {
+                return Err(DecodeError::Message(Box::new(format!(
+                    "invalid inet or cidr value of {} bytes",
+                    buf.len()
+                ))));
+            }
+        };
+
+        IpNetwork::new(ip, prefix).map_err(|err| DecodeError::Message(Box::new(err)))
+    }
+}
+
+impl Decode<Postgres> for IpAddr {
+    fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
+        let net = IpNetwork::decode(buf)?;
+
+        // the netmask of a network would be lost
+        if net.prefix() != IpNetwork::from(net.ip()).prefix() {
+            return Err(DecodeError::Message(Box::new(format!(
+                "expected a host address but received the network {}; decode it as an IpNetwork",
+                net
+            ))));
+        }
+
+        Ok(net.ip())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decode, Encode, IpAddr, IpNetwork, Postgres};
+
+    const HOST: &[u8] = &[2, 32, 0, 4, 192, 168, 0, 1];
+    const NETWORK: &[u8] = &[2, 24, 1, 4, 192, 168, 0, 0];
+
+    #[test]
+    fn it_encodes_inet() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&"192.168.0.1".parse::<IpAddr>().unwrap(), &mut buf);
+
+        assert_eq!(buf, HOST);
+
+        buf.clear();
+        Encode::<Postgres>::encode(&"192.168.0.0/24".parse::<IpNetwork>().unwrap(), &mut buf);
+
+        assert_eq!(buf, &[2, 24, 0, 4, 192, 168, 0, 0]);
+
+        buf.clear();
+        Encode::<Postgres>::encode(&"::1/128".parse::<IpNetwork>().unwrap(), &mut buf);
+
+        assert_eq!(&buf[..4], &[3, 128, 0, 16]);
+        assert_eq!(buf.len(), 20);
+    }
+
+    #[test]
+    fn it_decodes_inet() {
+        let decode = |buf| <IpNetwork as Decode<Postgres>>::decode(buf);
+
+        assert_eq!(decode(HOST).unwrap(), "192.168.0.1/32".parse().unwrap());
+        assert_eq!(decode(NETWORK).unwrap(), "192.168.0.0/24".parse().unwrap());
+        assert!(decode(&HOST[..7]).is_err());
+        assert!(decode(&[2, 33, 0, 4, 192, 168, 0, 1]).is_err());
+    }
+
+    #[test]
+    fn it_decodes_inet_host() {
+        let decode = |buf| <IpAddr as Decode<Postgres>>::decode(buf);
+
+        assert_eq!(
+            decode(HOST).unwrap(),
+            "192.168.0.1".parse::<IpAddr>().unwrap()
+        );
+        assert!(decode(NETWORK).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use serde_json::Value;
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::postgres::types::PgTypeMetadata;
+use crate::postgres::Postgres;
+use crate::types::HasSqlType;
+
+// version of the binary format of `jsonb`
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/jsonb.c
+const JSONB_VERSION: u8 = 1;
+
+impl HasSqlType<Value> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        // sent as JSONB; JSON (114) is received as plain text
+        PgTypeMetadata::binary_compatible(3802, 3807, &[114])
+    }
+}
+
+impl Encode<Postgres> for Value {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(JSONB_VERSION);
+
+        serde_json::to_writer(buf, self).expect("a JSON value is always serializable");
+    }
+}
+
+impl Decode<Postgres> for Value {
+    fn decode(mut raw: &[u8]) -> Result<Self, DecodeError> {
+        // JSON text never starts with the version byte of JSONB
+        if raw.first() == Some(&JSONB_VERSION) {
+            raw = &raw[1..];
+        }
+
+        Ok(serde_json::from_slice(raw)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decode, Encode, Postgres, Value};
+
+    #[test]
+    fn it_encodes_jsonb() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&serde_json::json!({ "a": [1, null] }), &mut buf);
+
+        assert_eq!(buf, b"\x01{\"a\":[1,null]}");
+    }
+
+    #[test]
+    fn it_decodes_json_and_jsonb() {
+        let json: Value = Decode::<Postgres>::decode(b"{\"a\": true}").unwrap();
+        let jsonb: Value = Decode::<Postgres>::decode(b"\x01{\"a\": true}").unwrap();
+
+        assert_eq!(json, serde_json::json!({ "a": true }));
+        assert_eq!(jsonb, json);
+    }
+}
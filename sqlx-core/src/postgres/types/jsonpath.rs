@@ -0,0 +1,91 @@
+use std::fmt::{self, Display};
+use std::str;
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::postgres::types::PgTypeMetadata;
+use crate::postgres::Postgres;
+use crate::types::HasSqlType;
+
+/// An SQL/JSON path expression, i.e. a value of `JSONPATH` (Postgres 12+), e.g.
+/// `$.items[*] ? (@.price > 10)`.
+///
+/// The expression is kept as its text; it is parsed and validated by the server.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PgJsonPath(pub String);
+
+impl From<String> for PgJsonPath {
+    fn from(path: String) -> Self {
+        PgJsonPath(path)
+    }
+}
+
+impl From<&'_ str> for PgJsonPath {
+    fn from(path: &str) -> Self {
+        PgJsonPath(path.to_owned())
+    }
+}
+
+impl Display for PgJsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// version of the binary format of `jsonpath`
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/jsonpath.c
+const VERSION: u8 = 1;
+
+impl HasSqlType<PgJsonPath> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        PgTypeMetadata::binary(4072, 4073)
+    }
+}
+
+impl Encode<Postgres> for PgJsonPath {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(VERSION);
+        buf.extend_from_slice(self.0.as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        1 + self.0.len()
+    }
+}
+
+impl Decode<Postgres> for PgJsonPath {
+    fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
+        match buf.split_first() {
+            Some((&VERSION, path)) => Ok(PgJsonPath(str::from_utf8(path)?.to_owned())),
+
+            Some((version, _)) => Err(DecodeError::Message(Box::new(format!(
+                "unsupported jsonpath format version {}",
+                version
+            )))),
+
+            None => Err(DecodeError::Message(Box::new("empty jsonpath value"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decode, Encode, PgJsonPath, Postgres};
+
+    #[test]
+    fn it_encodes_jsonpath() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&PgJsonPath::from("$.a[*]"), &mut buf);
+
+        assert_eq!(buf, b"\x01$.a[*]");
+    }
+
+    #[test]
+    fn it_decodes_jsonpath() {
+        let path: PgJsonPath = Decode::<Postgres>::decode(b"\x01$.a[*]").unwrap();
+
+        assert_eq!(path, PgJsonPath::from("$.a[*]"));
+        assert!(<PgJsonPath as Decode<Postgres>>::decode(b"\x02$").is_err());
+        assert!(<PgJsonPath as Decode<Postgres>>::decode(b"").is_err());
+    }
+}
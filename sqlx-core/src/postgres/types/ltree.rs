@@ -0,0 +1,98 @@
+use std::fmt::{self, Display};
+use std::str;
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::postgres::types::PgTypeMetadata;
+use crate::postgres::Postgres;
+use crate::types::HasSqlType;
+
+/// A label path of the `ltree` extension, e.g. `Top.Science.Astronomy`.
+///
+/// `ltree` is not a builtin type so it has no fixed OID; the server infers the type of bound
+/// values from the query, so they may need an explicit cast (`$1::ltree`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PgLTree(pub String);
+
+impl PgLTree {
+    /// Returns an iterator over the labels of the path.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.0.split('.').filter(|label| !label.is_empty())
+    }
+}
+
+impl From<String> for PgLTree {
+    fn from(path: String) -> Self {
+        PgLTree(path)
+    }
+}
+
+impl From<&'_ str> for PgLTree {
+    fn from(path: &str) -> Self {
+        PgLTree(path.to_owned())
+    }
+}
+
+impl Display for PgLTree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// version of the binary format of `ltree`
+// https://github.com/postgres/postgres/blob/master/contrib/ltree/ltree_io.c
+const VERSION: u8 = 1;
+
+impl HasSqlType<PgLTree> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        // leave the type unspecified for the server to infer
+        PgTypeMetadata::binary(0, 0)
+    }
+}
+
+impl Encode<Postgres> for PgLTree {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(VERSION);
+        buf.extend_from_slice(self.0.as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        1 + self.0.len()
+    }
+}
+
+impl Decode<Postgres> for PgLTree {
+    fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
+        match buf.split_first() {
+            Some((&VERSION, path)) => Ok(PgLTree(str::from_utf8(path)?.to_owned())),
+
+            Some((version, _)) => Err(DecodeError::Message(Box::new(format!(
+                "unsupported ltree format version {}",
+                version
+            )))),
+
+            None => Err(DecodeError::Message(Box::new("empty ltree value"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decode, Encode, PgLTree, Postgres};
+
+    #[test]
+    fn it_encodes_ltree() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&PgLTree::from("Top.Science"), &mut buf);
+
+        assert_eq!(buf, b"\x01Top.Science");
+    }
+
+    #[test]
+    fn it_decodes_ltree() {
+        let path: PgLTree = Decode::<Postgres>::decode(b"\x01Top.Science").unwrap();
+
+        assert_eq!(path.labels().collect::<Vec<_>>(), ["Top", "Science"]);
+        assert_eq!(PgLTree::default().labels().count(), 0);
+    }
+}
@@ -0,0 +1,65 @@
+use std::convert::TryInto;
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::postgres::types::PgTypeMetadata;
+use crate::postgres::Postgres;
+use crate::types::HasSqlType;
+
+// `macaddr` and `macaddr8` are sent as their bytes in network order
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/mac.c
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/mac8.c
+
+impl HasSqlType<[u8; 6]> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        PgTypeMetadata::binary(829, 1040)
+    }
+}
+
+impl HasSqlType<[u8; 8]> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        PgTypeMetadata::binary(774, 775)
+    }
+}
+
+impl Encode<Postgres> for [u8; 6] {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self);
+    }
+
+    fn size_hint(&self) -> usize {
+        6
+    }
+}
+
+impl Encode<Postgres> for [u8; 8] {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self);
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl Decode<Postgres> for [u8; 6] {
+    fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
+        buf.try_into().map_err(|_| {
+            DecodeError::Message(Box::new(format!(
+                "expected 6 bytes for macaddr but received {}",
+                buf.len()
+            )))
+        })
+    }
+}
+
+impl Decode<Postgres> for [u8; 8] {
+    fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
+        buf.try_into().map_err(|_| {
+            DecodeError::Message(Box::new(format!(
+                "expected 8 bytes for macaddr8 but received {}",
+                buf.len()
+            )))
+        })
+    }
+}
@@ -1,16 +1,51 @@
+//! Postgres-specific types and the mapping of Rust types to Postgres types.
+
+pub use self::bpchar::PgBpChar;
+pub use self::interval::PgInterval;
+pub use self::jsonpath::PgJsonPath;
+pub use self::ltree::PgLTree;
+pub use self::point::PgPoint;
+pub use self::range::PgRange;
+
+#[cfg(feature = "chrono")]
+pub use self::time_tz::PgTimeTz;
+
+mod array;
 mod bool;
+mod bpchar;
 mod bytes;
 mod float;
 mod int;
+mod interval;
+mod jsonpath;
+mod ltree;
+mod macaddr;
+mod point;
+mod range;
 mod str;
 
 #[cfg(feature = "chrono")]
 mod chrono;
 
+#[cfg(feature = "chrono")]
+mod time_tz;
+
+#[cfg(feature = "json")]
+mod json;
+
+#[cfg(feature = "decimal")]
+mod decimal;
+
 #[cfg(feature = "uuid")]
 mod uuid;
 
-#[derive(Debug, Copy, Clone)]
+#[cfg(feature = "ipnetwork")]
+mod ipnetwork;
+
+#[cfg(feature = "geo")]
+mod geo;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
 #[repr(i16)]
 pub enum TypeFormat {
     Text = 0,
@@ -46,20 +81,27 @@ pub struct PgTypeMetadata {
     pub(crate) format: TypeFormat,
     pub(crate) oid: u32,
     pub(crate) array_oid: u32,
+    // OIDs of other types that share the same binary representation as this one
+    pub(crate) compatible: &'static [u32],
 }
 
 impl PgTypeMetadata {
     const fn binary(oid: u32, array_oid: u32) -> Self {
+        Self::binary_compatible(oid, array_oid, &[])
+    }
+
+    const fn binary_compatible(oid: u32, array_oid: u32, compatible: &'static [u32]) -> Self {
         Self {
             format: TypeFormat::Binary,
             oid,
             array_oid,
+            compatible,
         }
     }
 }
 
 impl PartialEq<u32> for PgTypeMetadata {
     fn eq(&self, other: &u32) -> bool {
-        self.oid == *other || self.array_oid == *other
+        self.oid == *other || self.compatible.contains(other)
     }
 }
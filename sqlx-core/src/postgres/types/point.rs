@@ -0,0 +1,83 @@
+use std::mem;
+
+use byteorder::NetworkEndian;
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::io::Buf;
+use crate::postgres::types::PgTypeMetadata;
+use crate::postgres::Postgres;
+use crate::types::HasSqlType;
+
+/// A point on a plane, i.e. a value of `POINT`.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct PgPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl PgPoint {
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl HasSqlType<PgPoint> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        PgTypeMetadata::binary(600, 1017)
+    }
+}
+
+// A point is sent as its two coordinates as `float8`
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/geo_ops.c
+// `point_send` and `point_recv`
+impl Encode<Postgres> for PgPoint {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        Encode::<Postgres>::encode(&self.x, buf);
+        Encode::<Postgres>::encode(&self.y, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        2 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<Postgres> for PgPoint {
+    fn decode(mut raw: &[u8]) -> Result<Self, DecodeError> {
+        if raw.len() != 16 {
+            return Err(DecodeError::Message(Box::new(format!(
+                "expected 16 bytes for a point but received {}",
+                raw.len()
+            ))));
+        }
+
+        let x = f64::from_bits(raw.get_u64::<NetworkEndian>()?);
+        let y = f64::from_bits(raw.get_u64::<NetworkEndian>()?);
+
+        Ok(Self { x, y })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decode, Encode, PgPoint, Postgres};
+
+    // (1.5,-2)
+    const POINT: &[u8] = b"\x3f\xf8\0\0\0\0\0\0\xc0\0\0\0\0\0\0\0";
+
+    #[test]
+    fn it_encodes_point() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&PgPoint::new(1.5, -2.0), &mut buf);
+
+        assert_eq!(buf, POINT);
+    }
+
+    #[test]
+    fn it_decodes_point() {
+        let point: PgPoint = Decode::<Postgres>::decode(POINT).unwrap();
+
+        assert_eq!(point, PgPoint::new(1.5, -2.0));
+        assert!(<PgPoint as Decode<Postgres>>::decode(&POINT[..8]).is_err());
+    }
+}
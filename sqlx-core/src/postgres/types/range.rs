@@ -0,0 +1,203 @@
+use std::ops::{Bound, Range};
+
+use byteorder::{ByteOrder, NetworkEndian};
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::io::{Buf, BufMut};
+use crate::postgres::types::PgTypeMetadata;
+use crate::postgres::Postgres;
+use crate::types::HasSqlType;
+
+// https://github.com/postgres/postgres/blob/master/src/include/utils/rangetypes.h
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LB_INC: u8 = 0x02;
+const RANGE_UB_INC: u8 = 0x04;
+const RANGE_LB_INF: u8 = 0x08;
+const RANGE_UB_INF: u8 = 0x10;
+
+/// A Postgres range, such as `int4range` or `tstzrange`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PgRange<T> {
+    /// A range containing no values.
+    Empty,
+
+    /// A range between two bounds, either of which may be unbounded (infinite).
+    Range { start: Bound<T>, end: Bound<T> },
+}
+
+impl<T> From<Range<T>> for PgRange<T> {
+    fn from(range: Range<T>) -> Self {
+        PgRange::Range {
+            start: Bound::Included(range.start),
+            end: Bound::Excluded(range.end),
+        }
+    }
+}
+
+impl<T> From<(Bound<T>, Bound<T>)> for PgRange<T> {
+    fn from((start, end): (Bound<T>, Bound<T>)) -> Self {
+        PgRange::Range { start, end }
+    }
+}
+
+macro_rules! impl_range {
+    ($($(#[$meta:meta])? $ty:ty => ($oid:expr, $array_oid:expr)),* $(,)?) => {
+        $(
+            $(#[$meta])?
+            impl HasSqlType<PgRange<$ty>> for Postgres {
+                fn metadata() -> PgTypeMetadata {
+                    PgTypeMetadata::binary($oid, $array_oid)
+                }
+            }
+        )*
+    };
+}
+
+impl_range! {
+    i32 => (3904, 3905),
+    i64 => (3926, 3927),
+    #[cfg(feature = "chrono")]
+    chrono::NaiveDate => (3912, 3913),
+    #[cfg(feature = "chrono")]
+    chrono::NaiveDateTime => (3908, 3909),
+    #[cfg(feature = "chrono")]
+    chrono::DateTime<chrono::Utc> => (3910, 3911),
+}
+
+impl<T> Encode<Postgres> for PgRange<T>
+where
+    T: Encode<Postgres>,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let (start, end) = match self {
+            PgRange::Empty => {
+                buf.push(RANGE_EMPTY);
+                return;
+            }
+
+            PgRange::Range { start, end } => (start, end),
+        };
+
+        let mut flags = 0;
+
+        flags |= match start {
+            Bound::Included(_) => RANGE_LB_INC,
+            Bound::Excluded(_) => 0,
+            Bound::Unbounded => RANGE_LB_INF,
+        };
+
+        flags |= match end {
+            Bound::Included(_) => RANGE_UB_INC,
+            Bound::Excluded(_) => 0,
+            Bound::Unbounded => RANGE_UB_INF,
+        };
+
+        buf.push(flags);
+
+        for bound in &[start, end] {
+            if let Bound::Included(value) | Bound::Excluded(value) = bound {
+                let pos = buf.len();
+                buf.put_i32::<NetworkEndian>(0);
+
+                value.encode(buf);
+
+                let len = buf.len() - pos - 4;
+                NetworkEndian::write_i32(&mut buf[pos..], len as i32);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        match self {
+            PgRange::Empty => 1,
+
+            PgRange::Range { start, end } => {
+                let bound_size = |bound: &Bound<T>| match bound {
+                    Bound::Included(value) | Bound::Excluded(value) => 4 + value.size_hint(),
+                    Bound::Unbounded => 0,
+                };
+
+                1 + bound_size(start) + bound_size(end)
+            }
+        }
+    }
+}
+
+impl<T> Decode<Postgres> for PgRange<T>
+where
+    T: Decode<Postgres>,
+{
+    fn decode(mut buf: &[u8]) -> Result<Self, DecodeError> {
+        let flags = buf.get_u8()?;
+
+        if flags & RANGE_EMPTY != 0 {
+            return Ok(PgRange::Empty);
+        }
+
+        let mut decode_bound = |inclusive: u8, infinite: u8| -> Result<Bound<T>, DecodeError> {
+            if flags & infinite != 0 {
+                return Ok(Bound::Unbounded);
+            }
+
+            let len = buf.get_i32::<NetworkEndian>()?;
+            let value = T::decode(buf.get_bytes(len as usize)?)?;
+
+            Ok(if flags & inclusive != 0 {
+                Bound::Included(value)
+            } else {
+                Bound::Excluded(value)
+            })
+        };
+
+        let start = decode_bound(RANGE_LB_INC, RANGE_LB_INF)?;
+        let end = decode_bound(RANGE_UB_INC, RANGE_UB_INF)?;
+
+        Ok(PgRange::Range { start, end })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound;
+
+    use super::{Decode, Encode, PgRange, Postgres};
+
+    const INT4_RANGE: &[u8] = b"\x02\0\0\0\x04\0\0\0\x01\0\0\0\x04\0\0\0\x05";
+
+    #[test]
+    fn it_encodes_range() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&PgRange::from(1_i32..5), &mut buf);
+
+        assert_eq!(buf, INT4_RANGE);
+    }
+
+    #[test]
+    fn it_encodes_empty_and_unbounded_ranges() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&PgRange::<i32>::Empty, &mut buf);
+
+        assert_eq!(buf, b"\x01");
+
+        buf.clear();
+        Encode::<Postgres>::encode(
+            &PgRange::from((Bound::Excluded(1_i32), Bound::Unbounded)),
+            &mut buf,
+        );
+
+        assert_eq!(buf, b"\x10\0\0\0\x04\0\0\0\x01");
+    }
+
+    #[test]
+    fn it_decodes_range() {
+        let range: PgRange<i32> = Decode::<Postgres>::decode(INT4_RANGE).unwrap();
+        assert_eq!(range, PgRange::from(1..5));
+
+        let range: PgRange<i32> = Decode::<Postgres>::decode(b"\x01").unwrap();
+        assert_eq!(range, PgRange::Empty);
+
+        let range: PgRange<i32> = Decode::<Postgres>::decode(b"\x18").unwrap();
+        assert_eq!(range, PgRange::from((Bound::Unbounded, Bound::Unbounded)));
+    }
+}
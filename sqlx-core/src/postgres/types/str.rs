@@ -7,7 +7,10 @@ use std::str;
 
 impl HasSqlType<str> for Postgres {
     fn metadata() -> PgTypeMetadata {
-        PgTypeMetadata::binary(25, 1009)
+        // NAME (19), the type of identifiers in the system catalogs, XML (142) and CHAR(n)
+        // (BPCHAR, 1042) are transmitted as text; the padding of CHAR(n) is kept, see
+        // `PgBpChar` to trim it
+        PgTypeMetadata::binary_compatible(25, 1009, &[19, 142, 1042])
     }
 }
 
@@ -42,3 +45,39 @@ impl Decode<Postgres> for String {
         Ok(str::from_utf8(buf)?.to_owned())
     }
 }
+
+// string types with a different representation are encoded and decoded like `str`
+#[cfg(any(feature = "smol_str", feature = "compact_str"))]
+macro_rules! impl_str_like {
+    ($($ty:ty),*) => {
+        $(
+            impl HasSqlType<$ty> for Postgres {
+                fn metadata() -> PgTypeMetadata {
+                    <Postgres as HasSqlType<str>>::metadata()
+                }
+            }
+
+            impl Encode<Postgres> for $ty {
+                fn encode(&self, buf: &mut Vec<u8>) {
+                    <str as Encode<Postgres>>::encode(self.as_str(), buf)
+                }
+
+                fn size_hint(&self) -> usize {
+                    self.len()
+                }
+            }
+
+            impl Decode<Postgres> for $ty {
+                fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
+                    Ok(<$ty>::from(str::from_utf8(buf)?))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "smol_str")]
+impl_str_like!(smol_str::SmolStr);
+
+#[cfg(feature = "compact_str")]
+impl_str_like!(compact_str::CompactString);
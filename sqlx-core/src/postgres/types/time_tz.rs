@@ -0,0 +1,87 @@
+use std::mem;
+
+use byteorder::NetworkEndian;
+use chrono::{FixedOffset, NaiveTime};
+
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::io::Buf;
+use crate::postgres::types::PgTypeMetadata;
+use crate::postgres::Postgres;
+use crate::types::HasSqlType;
+
+/// A time of day with a time zone offset, i.e. a value of `TIME WITH TIME ZONE` (`timetz`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PgTimeTz {
+    pub time: NaiveTime,
+    pub offset: FixedOffset,
+}
+
+impl PgTimeTz {
+    pub fn new(time: NaiveTime, offset: FixedOffset) -> Self {
+        Self { time, offset }
+    }
+}
+
+impl HasSqlType<PgTimeTz> for Postgres {
+    fn metadata() -> PgTypeMetadata {
+        PgTypeMetadata::binary(1266, 1270)
+    }
+}
+
+// The time is sent as microseconds since midnight followed by the zone offset in seconds
+// *west* of UTC, so the sign of the offset is the inverse of `FixedOffset`'s.
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/date.c
+// `timetz_send` and `timetz_recv`
+impl Encode<Postgres> for PgTimeTz {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        Encode::<Postgres>::encode(&self.time, buf);
+        Encode::<Postgres>::encode(&-self.offset.local_minus_utc(), buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i64>() + mem::size_of::<i32>()
+    }
+}
+
+impl Decode<Postgres> for PgTimeTz {
+    fn decode(mut raw: &[u8]) -> Result<Self, DecodeError> {
+        let time = Decode::<Postgres>::decode(raw.get_bytes(8)?)?;
+        let zone = raw.get_i32::<NetworkEndian>()?;
+
+        let offset = FixedOffset::east_opt(-zone).ok_or_else(|| {
+            DecodeError::Message(Box::new(format!(
+                "Postgres time zone offset out of range: {}",
+                zone
+            )))
+        })?;
+
+        Ok(Self { time, offset })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decode, Encode, FixedOffset, NaiveTime, PgTimeTz, Postgres};
+
+    // 05:10:20+02
+    const TIME_TZ: &[u8] = b"\0\0\0\x04\x55\xd6\xa7\0\xff\xff\xe3\xe0";
+
+    #[test]
+    fn it_encodes_time_tz() {
+        let value = PgTimeTz::new(NaiveTime::from_hms(5, 10, 20), FixedOffset::east(7200));
+
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&value, &mut buf);
+
+        assert_eq!(buf, TIME_TZ);
+    }
+
+    #[test]
+    fn it_decodes_time_tz() {
+        let value: PgTimeTz = Decode::<Postgres>::decode(TIME_TZ).unwrap();
+
+        assert_eq!(value.time, NaiveTime::from_hms(5, 10, 20));
+        assert_eq!(value.offset, FixedOffset::east(7200));
+    }
+}
@@ -1,3 +1,5 @@
+use std::str;
+
 use crate::decode::{Decode, DecodeError};
 use crate::encode::Encode;
 use crate::postgres::types::PgTypeMetadata;
@@ -11,6 +13,9 @@ impl HasSqlType<Uuid> for Postgres {
     }
 }
 
+// Bind parameters are always sent in the binary format and the simple query protocol takes no
+// parameters, so a UUID is only ever encoded as its 16 bytes; to send its hyphenated text
+// instead, bind it as a `Text<Uuid>`.
 impl Encode<Postgres> for Uuid {
     fn encode(&self, buf: &mut Vec<u8>) {
         buf.extend_from_slice(self.as_bytes());
@@ -21,4 +26,41 @@ impl Decode<Postgres> for Uuid {
     fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
         Uuid::from_slice(buf).map_err(|err| DecodeError::Message(Box::new(err)))
     }
+
+    // the text format, e.g. `a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11`
+    fn decode_text(buf: &[u8]) -> Result<Self, DecodeError> {
+        Uuid::parse_str(str::from_utf8(buf)?).map_err(|err| DecodeError::Message(Box::new(err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decode, Encode, Postgres, Uuid};
+
+    const UUID: &[u8] = b"\xa0\xee\xbc\x99\x9c\x0b\x4e\xf8\xbb\x6d\x6b\xb9\xbd\x38\x0a\x11";
+
+    #[test]
+    fn it_encodes_uuid() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&Uuid::from_slice(UUID).unwrap(), &mut buf);
+
+        assert_eq!(buf, UUID);
+    }
+
+    #[test]
+    fn it_decodes_uuid() {
+        let decode = |buf| <Uuid as Decode<Postgres>>::decode(buf);
+        let decode_text = |buf| <Uuid as Decode<Postgres>>::decode_text(buf);
+
+        assert_eq!(decode(UUID).unwrap().as_bytes(), UUID);
+        assert!(decode(&UUID[..8]).is_err());
+
+        assert_eq!(
+            decode_text(b"a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11")
+                .unwrap()
+                .as_bytes(),
+            UUID
+        );
+        assert!(decode_text(b"a0eebc99").is_err());
+    }
 }
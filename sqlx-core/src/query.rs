@@ -1,11 +1,14 @@
 use crate::arguments::Arguments;
 use crate::arguments::IntoArguments;
 use crate::database::Database;
-use crate::encode::Encode;
-use crate::executor::Executor;
+use crate::decode::Decode;
+use crate::encode::{DynEncode, Encode};
+use crate::executor::{fetch_optional_row, Executor};
+use crate::query_as::QueryAs;
+use crate::row::{FromRow, Row, RowIndex};
 use crate::types::HasSqlType;
 use futures_core::stream::BoxStream;
-use futures_util::TryStreamExt;
+use futures_util::{StreamExt, TryStreamExt};
 use std::marker::PhantomData;
 
 /// Dynamic SQL query with bind parameters. Returned by [query].
@@ -18,6 +21,8 @@ where
 {
     query: &'q str,
     arguments: T,
+    fetch_size: Option<u32>,
+    persistent: bool,
     database: PhantomData<DB>,
 }
 
@@ -33,9 +38,33 @@ where
     where
         E: Executor<Database = DB>,
     {
-        executor
-            .execute(self.query, self.arguments.into_arguments())
-            .await
+        let args = self.arguments.into_arguments();
+
+        if self.persistent {
+            executor.execute(self.query, args).await
+        } else {
+            executor.execute_uncached(self.query, args).await
+        }
+    }
+
+    /// Fetch at most `fetch_size` rows from the database at a time when streaming the results.
+    ///
+    /// This bounds the memory used for very large result sets. Only supported by Postgres;
+    /// other databases always send the whole result set.
+    pub fn fetch_size(mut self, fetch_size: u32) -> Self {
+        self.fetch_size = Some(fetch_size);
+        self
+    }
+
+    /// Whether the prepared statement of the query is kept in the statement cache of the
+    /// connection (the default).
+    ///
+    /// Pass `false` for one-off statements such as DDL or dynamically generated SQL, so they
+    /// don't fill up the cache. Only supported by Postgres, where the query then uses the unnamed
+    /// statement; other databases always cache the statement.
+    pub fn persistent(mut self, persistent: bool) -> Self {
+        self.persistent = persistent;
+        self
     }
 
     /// Execute the query, returning the rows as a futures `Stream`.
@@ -46,7 +75,40 @@ where
         E: Executor<Database = DB>,
         'q: 'e,
     {
-        executor.fetch(self.query, self.arguments.into_arguments())
+        let args = self.arguments.into_arguments();
+
+        match self.fetch_size {
+            _ if !self.persistent => executor.fetch_uncached(self.query, args, self.fetch_size),
+            Some(fetch_size) => executor.fetch_paged(self.query, args, fetch_size),
+            None => executor.fetch(self.query, args),
+        }
+    }
+
+    /// Execute the query, converting every row into `T` with [FromRow] as it arrives.
+    ///
+    /// The rows are not buffered like with [fetch_all], so large result sets can be processed
+    /// with bounded memory (see also [fetch_size]). Errors, including columns that cannot be
+    /// decoded as the fields of `T`, are returned as items of the stream.
+    ///
+    /// ```ignore
+    /// let mut users = sqlx::query("SELECT id, name FROM users WHERE active = $1")
+    ///     .bind(true)
+    ///     .fetch_as::<User, _>(&mut conn);
+    ///
+    /// while let Some(user) = users.try_next().await? {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn fetch_as<'e, T, E>(self, executor: &'e mut E) -> BoxStream<'e, crate::Result<T>>
+    where
+        E: Executor<Database = DB>,
+        T: FromRow<DB::Row> + Send + 'e,
+        'q: 'e,
+    {
+        Box::pin(
+            self.fetch(executor)
+                .map(|row| row.and_then(T::try_from_row)),
+        )
     }
 
     /// Execute the query and get all rows from the result as a `Vec`.
@@ -54,10 +116,55 @@ where
     where
         E: Executor<Database = DB>,
     {
-        executor
-            .fetch(self.query, self.arguments.into_arguments())
-            .try_collect()
-            .await
+        self.fetch(executor).try_collect().await
+    }
+
+    /// Execute the query and get all rows from the result into `rows`, reusing its allocation.
+    ///
+    /// `rows` is cleared first. Returns the number of rows that were fetched.
+    pub async fn fetch_all_into<E>(
+        self,
+        executor: &mut E,
+        rows: &mut Vec<DB::Row>,
+    ) -> crate::Result<usize>
+    where
+        E: Executor<Database = DB>,
+    {
+        let mut s = self.fetch(executor);
+
+        rows.clear();
+
+        while let Some(row) = s.try_next().await? {
+            rows.push(row);
+        }
+
+        Ok(rows.len())
+    }
+
+    /// Execute the query and get all rows from the result as a `Vec`, up to `max_rows` rows.
+    ///
+    /// Returns [crate::Error::RowLimitExceeded] as soon as the query produces more rows than
+    /// that; the remaining rows are discarded before the connection is used again.
+    pub async fn fetch_all_limited<E>(
+        self,
+        executor: &mut E,
+        max_rows: usize,
+    ) -> crate::Result<Vec<DB::Row>>
+    where
+        E: Executor<Database = DB>,
+    {
+        let mut s = self.fetch(executor);
+        let mut rows = Vec::new();
+
+        while let Some(row) = s.try_next().await? {
+            if rows.len() == max_rows {
+                return Err(crate::Error::RowLimitExceeded(max_rows));
+            }
+
+            rows.push(row);
+        }
+
+        Ok(rows)
     }
 
     /// Execute a query which should return either 0 or 1 rows.
@@ -68,23 +175,102 @@ where
     where
         E: Executor<Database = DB>,
     {
+        if !self.persistent {
+            return fetch_optional_row(self.fetch(executor)).await;
+        }
+
         executor
             .fetch_optional(self.query, self.arguments.into_arguments())
             .await
     }
 
-    /// Execute a query which should return exactly 1 row.
+    /// Execute a query and return its first row.
     ///
-    /// * Returns [crate::Error::NotFound] if 0 rows are returned.
-    /// * Returns [crate::Error::FoundMoreThanOne] if more than one row is returned.
+    /// Returns [crate::Error::NotFound] if 0 rows are returned; any further rows are ignored.
+    /// Use [Query::fetch_exactly_one] to require that exactly 1 row is returned.
     pub async fn fetch_one<E>(self, executor: &mut E) -> crate::Result<DB::Row>
     where
         E: Executor<Database = DB>,
     {
+        if !self.persistent {
+            return self
+                .fetch(executor)
+                .try_next()
+                .await?
+                .ok_or(crate::Error::NotFound);
+        }
+
         executor
             .fetch_one(self.query, self.arguments.into_arguments())
             .await
     }
+
+    /// Execute a query and return the value of the first column of its first row.
+    ///
+    /// Returns [crate::Error::NotFound] if 0 rows are returned. This is the runtime counterpart
+    /// of `query_scalar!`:
+    ///
+    /// ```ignore
+    /// let count = sqlx::query("SELECT count(*) FROM users")
+    ///     .fetch_scalar::<i64, _>(&mut conn)
+    ///     .await?;
+    /// ```
+    pub async fn fetch_scalar<T, E>(self, executor: &mut E) -> crate::Result<T>
+    where
+        E: Executor<Database = DB>,
+        DB: HasSqlType<T>,
+        T: Decode<DB>,
+        usize: RowIndex<DB::Row>,
+    {
+        self.fetch_one(executor).await?.try_get(0_usize)
+    }
+
+    /// Execute a query which should return exactly 1 row.
+    ///
+    /// * Returns [crate::Error::NotFound] if 0 rows are returned.
+    /// * Returns [crate::Error::FoundMoreThanOne] if more than one row is returned.
+    pub async fn fetch_exactly_one<E>(self, executor: &mut E) -> crate::Result<DB::Row>
+    where
+        E: Executor<Database = DB>,
+    {
+        self.fetch_optional(executor)
+            .await?
+            .ok_or(crate::Error::NotFound)
+    }
+}
+
+impl<'q, DB, P> Query<'q, DB, P>
+where
+    DB: Database,
+{
+    /// Returns the SQL of this query.
+    pub fn sql(&self) -> &'q str {
+        self.query
+    }
+
+    /// Map each row of the result with `map_row`, for when implementing [crate::FromRow]
+    /// is not worth it.
+    ///
+    /// The closure may use [crate::Row::try_get] to extract the values of the row.
+    ///
+    /// ```ignore
+    /// let names: Vec<String> = sqlx::query("SELECT first_name, last_name FROM users")
+    ///     .map(|row| Ok(format!("{} {}", row.try_get::<String, _>(0)?, row.try_get::<String, _>(1)?)))
+    ///     .fetch_all(&mut conn)
+    ///     .await?;
+    /// ```
+    pub fn map<T, F>(self, map_row: F) -> QueryAs<'q, DB, T, P>
+    where
+        F: FnMut(DB::Row) -> crate::Result<T> + Send + 'q,
+    {
+        QueryAs {
+            query: self.query,
+            args: self.arguments,
+            fetch_size: self.fetch_size,
+            persistent: self.persistent,
+            map_row: Box::new(map_row),
+        }
+    }
 }
 
 impl<'q, DB> Query<'q, DB>
@@ -106,6 +292,23 @@ where
         self.arguments.add(value);
         self
     }
+
+    /// Bind a value whose type is only known at runtime, e.g. when binding a list of values
+    /// of different types to a query built dynamically.
+    ///
+    /// ```ignore
+    /// let params: Vec<Box<dyn DynEncode<Postgres>>> = vec![Box::new(1_i32), Box::new("name")];
+    ///
+    /// let mut query = sqlx::query("SELECT * FROM users WHERE id = $1 OR name = $2");
+    ///
+    /// for param in &params {
+    ///     query = query.bind_dyn(&**param);
+    /// }
+    /// ```
+    pub fn bind_dyn(mut self, value: &dyn DynEncode<DB>) -> Self {
+        self.arguments.add_dyn(value);
+        self
+    }
 }
 
 /// Construct a full SQL query that can be chained to bind parameters and executed.
@@ -120,12 +323,22 @@ where
 ///     .try_collect().await?; // -> Vec<String>
 /// ```
 pub fn query<DB>(sql: &str) -> Query<DB>
+where
+    DB: Database,
+{
+    query_with(sql, Default::default())
+}
+
+// Construct a query of SQL whose arguments are already bound
+pub(crate) fn query_with<DB>(sql: &str, arguments: DB::Arguments) -> Query<'_, DB>
 where
     DB: Database,
 {
     Query {
         database: PhantomData,
-        arguments: Default::default(),
+        arguments,
+        fetch_size: None,
+        persistent: true,
         query: sql,
     }
 }
@@ -3,7 +3,11 @@ use futures_util::{future, TryStreamExt};
 
 use crate::arguments::Arguments;
 use crate::{
-    arguments::IntoArguments, database::Database, encode::Encode, executor::Executor, row::FromRow,
+    arguments::IntoArguments,
+    database::Database,
+    encode::{DynEncode, Encode},
+    executor::{fetch_optional_row, Executor},
+    row::FromRow,
     types::HasSqlType,
 };
 
@@ -17,9 +21,27 @@ pub struct QueryAs<'q, DB, R, P = <DB as Database>::Arguments>
 where
     DB: Database,
 {
-    query: &'q str,
-    args: P,
-    map_row: fn(DB::Row) -> crate::Result<R>,
+    pub(crate) query: &'q str,
+    pub(crate) args: P,
+    pub(crate) fetch_size: Option<u32>,
+    pub(crate) persistent: bool,
+    pub(crate) map_row: MapRow<'q, DB, R>,
+}
+
+// Boxed so that the closures passed to `query_as_mapped` may capture their environment
+pub(crate) type MapRow<'q, DB, R> =
+    Box<dyn FnMut(<DB as Database>::Row) -> crate::Result<R> + Send + 'q>;
+
+impl<'q, DB, R, P> QueryAs<'q, DB, R, P>
+where
+    DB: Database,
+{
+    /// Returns the SQL of this query.
+    ///
+    /// For [query_file!] *et al* this is the contents of the file, embedded at compile time.
+    pub fn sql(&self) -> &'q str {
+        self.query
+    }
 }
 
 /// The result of [query!] for SQL queries that does not return output.
@@ -35,9 +57,13 @@ where
     where
         E: Executor<Database = DB>,
     {
-        executor
-            .execute(self.query, self.args.into_arguments())
-            .await
+        let args = self.args.into_arguments();
+
+        if self.persistent {
+            executor.execute(self.query, args).await
+        } else {
+            executor.execute_uncached(self.query, args).await
+        }
     }
 }
 
@@ -47,6 +73,22 @@ where
     P: IntoArguments<DB> + Send,
     R: Send + 'q,
 {
+    /// Fetch at most `fetch_size` rows from the database at a time when streaming the results.
+    ///
+    /// This bounds the memory used for very large result sets. Only supported by Postgres;
+    /// other databases always send the whole result set.
+    pub fn fetch_size(mut self, fetch_size: u32) -> Self {
+        self.fetch_size = Some(fetch_size);
+        self
+    }
+
+    /// Whether the prepared statement of the query is kept in the statement cache of the
+    /// connection. See [crate::Query::persistent].
+    pub fn persistent(mut self, persistent: bool) -> Self {
+        self.persistent = persistent;
+        self
+    }
+
     /// Execute the query, returning the rows as a futures `Stream`.
     ///
     /// Use [fetch_all] if you want a `Vec` instead.
@@ -58,12 +100,18 @@ where
         let Self {
             query,
             args,
-            map_row,
-            ..
+            fetch_size,
+            persistent,
+            mut map_row,
         } = self;
-        executor
-            .fetch(query, args.into_arguments())
-            .and_then(move |row| future::ready(map_row(row)))
+
+        let rows = match fetch_size {
+            _ if !persistent => executor.fetch_uncached(query, args.into_arguments(), fetch_size),
+            Some(fetch_size) => executor.fetch_paged(query, args.into_arguments(), fetch_size),
+            None => executor.fetch(query, args.into_arguments()),
+        };
+
+        rows.and_then(move |row| future::ready(map_row(row)))
     }
 
     /// Execute the query and get all rows from the result as a `Vec`.
@@ -74,6 +122,54 @@ where
         self.fetch(executor).try_collect().await
     }
 
+    /// Execute the query and get all rows from the result into `rows`, reusing its allocation.
+    ///
+    /// `rows` is cleared first. Returns the number of rows that were fetched.
+    pub async fn fetch_all_into<E>(
+        self,
+        executor: &mut E,
+        rows: &mut Vec<R>,
+    ) -> crate::Result<usize>
+    where
+        E: Executor<Database = DB>,
+    {
+        let mut s = Box::pin(self.fetch(executor));
+
+        rows.clear();
+
+        while let Some(row) = s.try_next().await? {
+            rows.push(row);
+        }
+
+        Ok(rows.len())
+    }
+
+    /// Execute the query and get all rows from the result as a `Vec`, up to `max_rows` rows.
+    ///
+    /// Returns [crate::Error::RowLimitExceeded] as soon as the query produces more rows than
+    /// that; the remaining rows are discarded before the connection is used again.
+    pub async fn fetch_all_limited<E>(
+        self,
+        executor: &mut E,
+        max_rows: usize,
+    ) -> crate::Result<Vec<R>>
+    where
+        E: Executor<Database = DB>,
+    {
+        let mut s = Box::pin(self.fetch(executor));
+        let mut rows = Vec::new();
+
+        while let Some(row) = s.try_next().await? {
+            if rows.len() == max_rows {
+                return Err(crate::Error::RowLimitExceeded(max_rows));
+            }
+
+            rows.push(row);
+        }
+
+        Ok(rows)
+    }
+
     /// Execute a query which should return either 0 or 1 rows.
     ///
     /// Returns [crate::Error::FoundMoreThanOne] if more than 1 row is returned.
@@ -82,6 +178,10 @@ where
     where
         E: Executor<Database = DB>,
     {
+        if !self.persistent {
+            return fetch_optional_row(Box::pin(self.fetch(executor))).await;
+        }
+
         executor
             .fetch_optional(self.query, self.args.into_arguments())
             .await?
@@ -89,20 +189,138 @@ where
             .transpose()
     }
 
-    /// Execute a query which should return exactly 1 row.
+    /// Execute a query and return its first row.
     ///
-    /// * Returns [crate::Error::NotFound] if 0 rows are returned.
-    /// * Returns [crate::Error::FoundMoreThanOne] if more than one row is returned.
+    /// Returns [crate::Error::NotFound] if 0 rows are returned; any further rows are ignored.
+    /// Use [QueryAs::fetch_exactly_one] to require that exactly 1 row is returned.
     pub async fn fetch_one<E>(self, executor: &mut E) -> crate::Result<R>
     where
         E: Executor<Database = DB>,
     {
-        (self.map_row)(
+        if !self.persistent {
+            return Box::pin(self.fetch(executor))
+                .try_next()
+                .await?
+                .ok_or(crate::Error::NotFound);
+        }
+
+        let mut map_row = self.map_row;
+
+        map_row(
             executor
                 .fetch_one(self.query, self.args.into_arguments())
                 .await?,
         )
     }
+
+    /// Execute a query which should return exactly 1 row.
+    ///
+    /// * Returns [crate::Error::NotFound] if 0 rows are returned.
+    /// * Returns [crate::Error::FoundMoreThanOne] if more than one row is returned.
+    pub async fn fetch_exactly_one<E>(self, executor: &mut E) -> crate::Result<R>
+    where
+        E: Executor<Database = DB>,
+    {
+        self.fetch_optional(executor)
+            .await?
+            .ok_or(crate::Error::NotFound)
+    }
+
+    /// Pass every record through `mapper`, e.g. to post-process the anonymous record of
+    /// [query!] into another type while keeping its fields checked at compile time.
+    ///
+    /// ```ignore
+    /// let user = sqlx::query!("SELECT id, roles FROM users WHERE id = $1", id)
+    ///     .try_map(|rec| {
+    ///         Ok(User {
+    ///             id: rec.id,
+    ///             roles: rec.roles.split(',').map(str::parse).collect::<Result<_, _>>()?,
+    ///         })
+    ///     })
+    ///     .fetch_one(&mut conn)
+    ///     .await?;
+    /// ```
+    pub fn try_map<T, F>(self, mapper: F) -> TryMap<'q, DB, R, F, P>
+    where
+        F: FnMut(R) -> crate::Result<T>,
+    {
+        TryMap {
+            inner: self,
+            mapper,
+        }
+    }
+}
+
+/// A [QueryAs] whose records are passed through a fallible function.
+///
+/// Returned by [QueryAs::try_map].
+pub struct TryMap<'q, DB, R, F, P = <DB as Database>::Arguments>
+where
+    DB: Database,
+{
+    inner: QueryAs<'q, DB, R, P>,
+    mapper: F,
+}
+
+impl<'q, DB, R, F, P, T> TryMap<'q, DB, R, F, P>
+where
+    DB: Database,
+    P: IntoArguments<DB> + Send,
+    R: Send + 'q,
+    F: FnMut(R) -> crate::Result<T>,
+{
+    /// Execute the query, returning the mapped records as a futures `Stream`.
+    pub fn fetch<'e, E>(self, executor: &'e mut E) -> impl Stream<Item = crate::Result<T>> + 'e
+    where
+        E: Executor<Database = DB>,
+        F: 'e,
+        T: 'e,
+        'q: 'e,
+    {
+        let mut mapper = self.mapper;
+
+        self.inner
+            .fetch(executor)
+            .and_then(move |record| future::ready(mapper(record)))
+    }
+
+    /// Execute the query and get all mapped records as a `Vec`.
+    pub async fn fetch_all<E>(self, executor: &mut E) -> crate::Result<Vec<T>>
+    where
+        E: Executor<Database = DB>,
+    {
+        let records = self.inner.fetch_all(executor).await?;
+
+        records.into_iter().map(self.mapper).collect()
+    }
+
+    /// Execute a query which should return either 0 or 1 rows. See [QueryAs::fetch_optional].
+    pub async fn fetch_optional<E>(self, executor: &mut E) -> crate::Result<Option<T>>
+    where
+        E: Executor<Database = DB>,
+    {
+        self.inner
+            .fetch_optional(executor)
+            .await?
+            .map(self.mapper)
+            .transpose()
+    }
+
+    /// Execute a query and return its first row. See [QueryAs::fetch_one].
+    pub async fn fetch_one<E>(mut self, executor: &mut E) -> crate::Result<T>
+    where
+        E: Executor<Database = DB>,
+    {
+        (self.mapper)(self.inner.fetch_one(executor).await?)
+    }
+
+    /// Execute a query which should return exactly 1 row. See [QueryAs::fetch_exactly_one].
+    pub async fn fetch_exactly_one<E>(mut self, executor: &mut E) -> crate::Result<T>
+    where
+        E: Executor<Database = DB>,
+    {
+        (self.mapper)(self.inner.fetch_exactly_one(executor).await?)
+    }
 }
 
 impl<'q, DB, R> QueryAs<'q, DB, R>
@@ -126,6 +344,12 @@ where
         self
     }
 
+    /// Bind a value whose type is only known at runtime. See [crate::Query::bind_dyn].
+    pub fn bind_dyn(mut self, value: &dyn DynEncode<DB>) -> Self {
+        self.args.add_dyn(value);
+        self
+    }
+
     // used by query!() and friends
     #[doc(hidden)]
     pub fn bind_all<I>(self, values: I) -> QueryAs<'q, DB, R, I>
@@ -135,6 +359,8 @@ where
         QueryAs {
             query: self.query,
             args: values,
+            fetch_size: self.fetch_size,
+            persistent: self.persistent,
             map_row: self.map_row,
         }
     }
@@ -150,21 +376,41 @@ where
     QueryAs {
         query,
         args: Default::default(),
-        map_row: |row| Ok(T::from_row(row)),
+        fetch_size: None,
+        persistent: true,
+        map_row: Box::new(|row| T::try_from_row(row)),
     }
 }
 
-#[doc(hidden)]
-pub fn query_as_mapped<DB, T>(
-    query: &str,
-    map_row: fn(DB::Row) -> crate::Result<T>,
-) -> QueryAs<DB, T>
+/// Construct a dynamic SQL query with an explicit output type produced by `map_row`.
+///
+/// The closure is called with every row of the result set and may use [crate::Row::try_get]
+/// to extract its values; this is useful for outputs that cannot implement [FromRow]. It may
+/// capture its environment, e.g. to look up values the rows refer to.
+///
+/// ```rust,ignore
+/// let users = sqlx::query_as_mapped::<Postgres, _>("SELECT id, name FROM users", |row| {
+///     Ok(User {
+///         id: row.try_get("id")?,
+///         name: row.try_get("name")?,
+///     })
+/// })
+/// .fetch_all(&mut conn)
+/// .await?;
+/// ```
+// `map_row` is an `impl Trait` so that `query_as_mapped::<DB, T>` can still name the output
+pub fn query_as_mapped<'q, DB, T>(
+    query: &'q str,
+    map_row: impl FnMut(DB::Row) -> crate::Result<T> + Send + 'q,
+) -> QueryAs<'q, DB, T>
 where
     DB: Database,
 {
     QueryAs {
         query,
         args: Default::default(),
-        map_row,
+        fetch_size: None,
+        persistent: true,
+        map_row: Box::new(map_row),
     }
 }
@@ -0,0 +1,226 @@
+use std::fmt::{Display, Write};
+
+use crate::arguments::Arguments;
+use crate::database::Database;
+use crate::encode::Encode;
+use crate::query::{query_with, Query};
+use crate::types::HasSqlType;
+
+/// Builds the SQL of a query at runtime, with its bind parameters, e.g. for a search with
+/// optional filters.
+///
+/// Values are never written into the SQL: [push_bind] adds the placeholder of the database
+/// (`$1`, `$2`, .. for Postgres, `?` for MySQL) and binds the value to it.
+///
+/// ```ignore
+/// let mut builder = QueryBuilder::<Postgres>::new("SELECT * FROM users WHERE active = ");
+/// builder.push_bind(true);
+///
+/// if let Some(name) = name {
+///     builder.push(" AND name = ").push_bind(name);
+/// }
+///
+/// let users = builder.build().fetch_all(&mut conn).await?;
+/// ```
+///
+/// The placeholders of [crate::any::Any] depend on the backend, so its builders are started with
+/// [AnyConnection::query_builder](crate::any::AnyConnection::query_builder); binding a value in
+/// one created with [QueryBuilder::new] panics.
+///
+/// [push_bind]: QueryBuilder::push_bind
+pub struct QueryBuilder<DB>
+where
+    DB: Database,
+{
+    query: String,
+
+    // taken by `build`
+    arguments: Option<DB::Arguments>,
+
+    // creates the arguments on `reset`
+    new_arguments: fn() -> DB::Arguments,
+}
+
+impl<DB> QueryBuilder<DB>
+where
+    DB: Database,
+{
+    /// Start the query with `init`.
+    pub fn new(init: impl Into<String>) -> Self {
+        Self::with_arguments(init, Default::default)
+    }
+
+    pub(crate) fn with_arguments(
+        init: impl Into<String>,
+        new_arguments: fn() -> DB::Arguments,
+    ) -> Self {
+        Self {
+            query: init.into(),
+            arguments: Some(new_arguments()),
+            new_arguments,
+        }
+    }
+
+    /// Append SQL to the query.
+    ///
+    /// The SQL is written as is; never push values that come from the user, bind them with
+    /// [QueryBuilder::push_bind] instead.
+    pub fn push(&mut self, sql: impl Display) -> &mut Self {
+        write!(self.query, "{}", sql).expect("writing to a String cannot fail");
+
+        self
+    }
+
+    /// Append a placeholder to the query and bind `value` to it.
+    ///
+    /// # Panics
+    ///
+    /// If the query was already built and not [reset](QueryBuilder::reset) since, or if this is a
+    /// builder for [crate::any::Any] that was not started by
+    /// [AnyConnection::query_builder](crate::any::AnyConnection::query_builder).
+    pub fn push_bind<T>(&mut self, value: T) -> &mut Self
+    where
+        DB: HasSqlType<T>,
+        T: Encode<DB>,
+    {
+        let arguments = self
+            .arguments
+            .as_mut()
+            .expect("QueryBuilder must be reset before it is reused");
+
+        // writing to a String cannot fail, so the placeholders of the database are not known
+        if arguments.format_placeholder(&mut self.query).is_err() {
+            panic!("QueryBuilder<Any> must be started with AnyConnection::query_builder");
+        }
+
+        arguments.add(value);
+
+        self
+    }
+
+    /// Returns the number of values bound so far.
+    pub fn len(&self) -> usize {
+        self.arguments.as_ref().map_or(0, Arguments::len)
+    }
+
+    /// Returns `true` if no value was bound so far.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the SQL built so far.
+    pub fn sql(&self) -> &str {
+        &self.query
+    }
+
+    /// Build the query with the values bound to it, to be executed.
+    ///
+    /// The values are moved into the query; call [reset](QueryBuilder::reset) to build
+    /// another query with this builder.
+    ///
+    /// # Panics
+    ///
+    /// If the query was already built and not reset since.
+    pub fn build(&mut self) -> Query<'_, DB> {
+        let arguments = self
+            .arguments
+            .take()
+            .expect("QueryBuilder must be reset before it is reused");
+
+        query_with(&self.query, arguments)
+    }
+
+    /// Clear the SQL and the values, keeping the allocation of the SQL.
+    pub fn reset(&mut self) -> &mut Self {
+        self.query.clear();
+        self.arguments = Some((self.new_arguments)());
+
+        self
+    }
+
+    /// Returns the SQL of the query, dropping any bound values.
+    pub fn into_sql(self) -> String {
+        self.query
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QueryBuilder;
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn it_numbers_postgres_placeholders() {
+        let mut builder = QueryBuilder::<crate::Postgres>::new("SELECT * FROM users WHERE id = ");
+
+        builder.push_bind(1_i32);
+        builder.push(" AND name = ").push_bind("name");
+
+        assert_eq!(
+            builder.sql(),
+            "SELECT * FROM users WHERE id = $1 AND name = $2"
+        );
+        assert_eq!(builder.len(), 2);
+
+        assert_eq!(
+            builder.build().sql(),
+            "SELECT * FROM users WHERE id = $1 AND name = $2"
+        );
+        assert!(builder.is_empty());
+
+        builder.reset().push("SELECT ").push_bind(1_i32);
+
+        assert_eq!(builder.sql(), "SELECT $1");
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn it_writes_mysql_placeholders() {
+        let mut builder = QueryBuilder::<crate::MySql>::new("SELECT * FROM users WHERE id = ");
+
+        builder.push_bind(1_i32);
+        builder.push(" AND name = ").push_bind("name");
+
+        assert_eq!(
+            builder.sql(),
+            "SELECT * FROM users WHERE id = ? AND name = ?"
+        );
+    }
+
+    #[cfg(feature = "any")]
+    #[test]
+    #[should_panic(expected = "AnyConnection::query_builder")]
+    fn it_refuses_any_placeholders_without_a_backend() {
+        let mut builder = QueryBuilder::<crate::any::Any>::new("SELECT ");
+
+        builder.push_bind(1_i32);
+    }
+
+    #[cfg(all(feature = "any", feature = "postgres"))]
+    #[test]
+    fn it_numbers_any_placeholders_for_postgres() {
+        let mut builder = QueryBuilder::<crate::any::Any>::with_arguments(
+            "SELECT ",
+            crate::any::AnyArguments::for_postgres,
+        );
+
+        builder.push_bind(1_i32).push(", ").push_bind("name");
+
+        assert_eq!(builder.sql(), "SELECT $1, $2");
+
+        let _ = builder.build();
+        builder.reset().push("SELECT ").push_bind(1_i32);
+
+        assert_eq!(builder.sql(), "SELECT $1");
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    #[should_panic(expected = "reset")]
+    fn it_panics_when_built_twice() {
+        let mut builder = QueryBuilder::<crate::Postgres>::new("SELECT 1");
+
+        let _ = builder.build();
+        let _ = builder.build();
+    }
+}
@@ -0,0 +1,30 @@
+use std::borrow::Cow;
+
+// The function set with `set_sql_rewriter` on a connection; it is applied to the SQL of the
+// queries run through `Executor` (and explicitly prepared statements and `COPY`) before they are
+// sent, but not to the queries the driver runs itself. It is cleared when a connection is
+// returned to a pool.
+#[derive(Default)]
+pub(crate) struct SqlRewriter(Option<Box<RewriteFn>>);
+
+type RewriteFn = dyn for<'a> Fn(&'a str) -> Cow<'a, str> + Send + Sync;
+
+impl SqlRewriter {
+    pub(crate) fn set<F>(&mut self, rewrite: F)
+    where
+        F: for<'a> Fn(&'a str) -> Cow<'a, str> + Send + Sync + 'static,
+    {
+        self.0 = Some(Box::new(rewrite));
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.0 = None;
+    }
+
+    pub(crate) fn rewrite<'q>(&self, sql: &'q str) -> Cow<'q, str> {
+        match &self.0 {
+            Some(rewrite) => rewrite(sql),
+            None => Cow::Borrowed(sql),
+        }
+    }
+}
@@ -18,28 +18,67 @@ where
 pub trait Row: Unpin + Send + 'static {
     type Database: Database + ?Sized;
 
-    /// Returns `true` if the row contains no values.
+    /// Returns `true` if the row contains no columns.
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
-    /// Returns the number of values in the row.
+    /// Returns the number of columns in the row.
     fn len(&self) -> usize;
 
     /// Returns the value at the `index`; can either be an integer ordinal or a column name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column does not exist or its value cannot be decoded as `T`; see
+    /// [Row::try_get] for a version that returns the error.
     fn get<T, I>(&self, index: I) -> T
     where
         Self::Database: HasSqlType<T>,
         I: RowIndex<Self>,
-        T: Decode<Self::Database>;
+        T: Decode<Self::Database>,
+    {
+        match index.try_get(self) {
+            Ok(value) => value,
+            Err(err) => panic!("could not get the value of a column: {}", err),
+        }
+    }
+
+    /// Returns the value at the `index`; can either be an integer ordinal or a column name.
+    ///
+    /// Unlike [Row::get], errors are returned instead of panicking if the column does not exist
+    /// or its value cannot be decoded as `T`.
+    fn try_get<T, I>(&self, index: I) -> crate::Result<T>
+    where
+        Self::Database: HasSqlType<T>,
+        I: RowIndex<Self>,
+        T: Decode<Self::Database>,
+    {
+        index.try_get(self)
+    }
 }
 
 /// A **record** that can be built from a row returned from by the database.
+///
+/// Implemented for tuples of up to 8 elements, which are decoded from the leading columns of
+/// the row in order; like [Row::get], [FromRow::from_row] panics if a column cannot be decoded
+/// while [FromRow::try_from_row] returns the error.
 pub trait FromRow<R>
 where
     R: Row,
 {
     fn from_row(row: R) -> Self;
+
+    /// Builds the record from the row, returning an error instead of panicking if a column
+    /// cannot be decoded.
+    ///
+    /// Defaults to [FromRow::from_row], which is assumed to not fail.
+    fn try_from_row(row: R) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self::from_row(row))
+    }
 }
 
 #[allow(unused_macros)]
@@ -53,3 +92,88 @@ macro_rules! impl_from_row_for_row {
         }
     };
 }
+
+// tuples are built from the leading columns of a row, in order
+macro_rules! impl_from_row_for_tuple {
+    ($( ($idx:tt) -> $T:ident );+;) => {
+        impl<R, $($T,)+> FromRow<R> for ($($T,)+)
+        where
+            R: Row,
+            usize: RowIndex<R>,
+            $(
+                R::Database: HasSqlType<$T>,
+                $T: Decode<R::Database>,
+            )+
+        {
+            #[inline]
+            fn from_row(row: R) -> Self {
+                ($(row.get::<$T, usize>($idx),)+)
+            }
+
+            #[inline]
+            fn try_from_row(row: R) -> crate::Result<Self> {
+                Ok(($(row.try_get::<$T, usize>($idx)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_row_for_tuple!(
+    (0) -> T1;
+);
+
+impl_from_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+);
+
+impl_from_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+);
+
+impl_from_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+);
+
+impl_from_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+);
+
+impl_from_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+);
+
+impl_from_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+);
+
+impl_from_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+);
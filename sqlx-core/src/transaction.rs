@@ -0,0 +1,213 @@
+use std::fmt::{self, Display};
+use std::ops::{Deref, DerefMut};
+
+use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+
+use crate::connection::Connection;
+use crate::describe::Describe;
+use crate::executor::{CommandTag, Executor};
+use crate::Database;
+
+/// The isolation level of a transaction started with [Connection::begin_with].
+///
+/// Postgres runs `READ UNCOMMITTED` transactions as `READ COMMITTED`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl Display for IsolationLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+        })
+    }
+}
+
+// `START TRANSACTION` with the characteristics of the transaction, in standard SQL
+pub(crate) fn start_sql(isolation: IsolationLevel, read_only: bool) -> String {
+    let access = if read_only { "READ ONLY" } else { "READ WRITE" };
+
+    format!(
+        "START TRANSACTION ISOLATION LEVEL {}, {}",
+        isolation, access
+    )
+}
+
+/// A transaction on a connection, started with [Connection::begin] or
+/// [Connection::begin_with].
+///
+/// Queries are run inside the transaction by passing it as their executor, e.g.
+/// `.fetch_one(&mut tx)`, or on the connection it dereferences to.
+///
+/// A transaction that is dropped before it is committed or rolled back is rolled back before
+/// the next query is run on the connection.
+///
+/// ```ignore
+/// let mut tx = conn.begin_with(IsolationLevel::Serializable, false).await?;
+///
+/// sqlx::query("UPDATE accounts SET balance = balance - $1 WHERE id = $2")
+///     .bind(amount)
+///     .bind(id)
+///     .execute(&mut tx)
+///     .await?;
+///
+/// tx.commit().await?;
+/// ```
+#[must_use = "a transaction is rolled back if it is dropped without being committed"]
+pub struct Transaction<'c, C>
+where
+    C: Connection,
+{
+    conn: &'c mut C,
+
+    // Has the transaction not been committed or rolled back yet
+    open: bool,
+}
+
+impl<'c, C> Transaction<'c, C>
+where
+    C: Connection,
+{
+    // Start the transaction by running each statement in order
+    pub(crate) async fn begin(conn: &'c mut C, statements: &[&str]) -> crate::Result<Self> {
+        for statement in statements {
+            conn.send(statement).await?;
+        }
+
+        Ok(Self { conn, open: true })
+    }
+
+    /// Commit the transaction.
+    pub async fn commit(mut self) -> crate::Result<()> {
+        self.conn.send("COMMIT").await?;
+        self.open = false;
+
+        Ok(())
+    }
+
+    /// Roll the transaction back.
+    pub async fn rollback(mut self) -> crate::Result<()> {
+        self.conn.send("ROLLBACK").await?;
+        self.open = false;
+
+        Ok(())
+    }
+}
+
+impl<C> Deref for Transaction<'_, C>
+where
+    C: Connection,
+{
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        self.conn
+    }
+}
+
+impl<C> DerefMut for Transaction<'_, C>
+where
+    C: Connection,
+{
+    fn deref_mut(&mut self) -> &mut C {
+        self.conn
+    }
+}
+
+impl<C> Executor for Transaction<'_, C>
+where
+    C: Connection,
+{
+    type Database = C::Database;
+
+    fn send<'e, 'q: 'e>(
+        &'e mut self,
+        command: &'q str,
+    ) -> BoxFuture<'e, crate::Result<Vec<CommandTag>>> {
+        self.conn.send(command)
+    }
+
+    fn execute<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: <Self::Database as Database>::Arguments,
+    ) -> BoxFuture<'e, crate::Result<u64>> {
+        self.conn.execute(query, args)
+    }
+
+    fn fetch<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: <Self::Database as Database>::Arguments,
+    ) -> BoxStream<'e, crate::Result<<Self::Database as Database>::Row>> {
+        self.conn.fetch(query, args)
+    }
+
+    fn fetch_paged<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: <Self::Database as Database>::Arguments,
+        fetch_size: u32,
+    ) -> BoxStream<'e, crate::Result<<Self::Database as Database>::Row>> {
+        self.conn.fetch_paged(query, args, fetch_size)
+    }
+
+    fn execute_uncached<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: <Self::Database as Database>::Arguments,
+    ) -> BoxFuture<'e, crate::Result<u64>> {
+        self.conn.execute_uncached(query, args)
+    }
+
+    fn fetch_uncached<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: <Self::Database as Database>::Arguments,
+        fetch_size: Option<u32>,
+    ) -> BoxStream<'e, crate::Result<<Self::Database as Database>::Row>> {
+        self.conn.fetch_uncached(query, args, fetch_size)
+    }
+
+    fn fetch_optional<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: <Self::Database as Database>::Arguments,
+    ) -> BoxFuture<'e, crate::Result<Option<<Self::Database as Database>::Row>>> {
+        self.conn.fetch_optional(query, args)
+    }
+
+    fn fetch_one<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+        args: <Self::Database as Database>::Arguments,
+    ) -> BoxFuture<'e, crate::Result<<Self::Database as Database>::Row>> {
+        self.conn.fetch_one(query, args)
+    }
+
+    fn describe<'e, 'q: 'e>(
+        &'e mut self,
+        query: &'q str,
+    ) -> BoxFuture<'e, crate::Result<Describe<Self::Database>>> {
+        self.conn.describe(query)
+    }
+}
+
+impl<C> Drop for Transaction<'_, C>
+where
+    C: Connection,
+{
+    fn drop(&mut self) {
+        if self.open {
+            self.conn.start_rollback();
+        }
+    }
+}
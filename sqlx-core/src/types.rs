@@ -1,13 +1,45 @@
 //! Traits linking Rust types to SQL types.
 
+use std::borrow::Cow;
 use std::fmt::Display;
 
+pub use self::text::Text;
+
+#[cfg(feature = "json")]
+pub use self::json::Json;
+
+mod non_zero;
+mod text;
+
+#[cfg(feature = "json")]
+mod json;
+
 #[cfg(feature = "uuid")]
 pub use uuid::Uuid;
 
+#[cfg(feature = "ipnetwork")]
+pub use ipnetwork::IpNetwork;
+
+#[cfg(feature = "json")]
+pub use serde_json::Value as JsonValue;
+
+#[cfg(feature = "decimal")]
+pub use rust_decimal::Decimal;
+
+#[cfg(feature = "smol_str")]
+pub use smol_str::SmolStr;
+
+#[cfg(feature = "compact_str")]
+pub use compact_str::CompactString;
+
+#[cfg(feature = "geo")]
+pub mod geo {
+    pub use geo_types::Point;
+}
+
 #[cfg(feature = "chrono")]
 pub mod chrono {
-    pub use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+    pub use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 }
 
 /// Information about how a database stores metadata about given SQL types.
@@ -37,6 +69,16 @@ where
     }
 }
 
+impl<T: ?Sized, DB> HasSqlType<Cow<'_, T>> for DB
+where
+    DB: HasSqlType<T>,
+    T: ToOwned,
+{
+    fn metadata() -> Self::TypeMetadata {
+        <DB as HasSqlType<T>>::metadata()
+    }
+}
+
 impl<T, DB> HasSqlType<Option<T>> for DB
 where
     DB: HasSqlType<T>,
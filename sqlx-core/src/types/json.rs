@@ -0,0 +1,90 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::database::Database;
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::types::HasSqlType;
+
+/// A value stored as a JSON document, e.g. in a `JSONB` column for Postgres or a `JSON` column
+/// for MySQL.
+///
+/// The value is sent with its [Serialize] implementation and received with its
+/// [DeserializeOwned] implementation, going through a [serde_json::Value].
+///
+/// ```ignore
+/// let tags: Json<Vec<String>> = sqlx::query("SELECT tags FROM posts")
+///     .fetch_one(&mut conn)
+///     .await?
+///     .get(0);
+/// ```
+///
+/// # Panics
+///
+/// Encoding panics if the value cannot be serialized as JSON, e.g. a map whose keys are not
+/// strings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Json<T>(pub T);
+
+impl<T> Json<T> {
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Json<T> {
+    fn from(value: T) -> Self {
+        Json(value)
+    }
+}
+
+impl<T> Deref for Json<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Json<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T, DB> HasSqlType<Json<T>> for DB
+where
+    DB: HasSqlType<Value>,
+{
+    fn metadata() -> Self::TypeMetadata {
+        <DB as HasSqlType<Value>>::metadata()
+    }
+}
+
+impl<T, DB> Encode<DB> for Json<T>
+where
+    DB: Database,
+    T: Serialize,
+    Value: Encode<DB>,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        serde_json::to_value(&self.0)
+            .expect("the value of a Json<T> could not be serialized")
+            .encode(buf)
+    }
+}
+
+impl<T, DB> Decode<DB> for Json<T>
+where
+    DB: Database,
+    T: DeserializeOwned,
+    Value: Decode<DB>,
+{
+    fn decode(raw: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Json(serde_json::from_value(Value::decode(raw)?)?))
+    }
+}
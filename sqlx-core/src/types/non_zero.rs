@@ -0,0 +1,64 @@
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8,
+};
+
+use crate::database::Database;
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::types::HasSqlType;
+
+// non-zero integers are sent and received as their integer type; a zero is a decoding error
+macro_rules! impl_non_zero {
+    ($($ty:ident: $int:ty),*) => {
+        $(
+            impl<DB> HasSqlType<$ty> for DB
+            where
+                DB: HasSqlType<$int>,
+            {
+                fn metadata() -> Self::TypeMetadata {
+                    <DB as HasSqlType<$int>>::metadata()
+                }
+            }
+
+            impl<DB> Encode<DB> for $ty
+            where
+                DB: Database,
+                $int: Encode<DB>,
+            {
+                fn encode(&self, buf: &mut Vec<u8>) {
+                    self.get().encode(buf)
+                }
+
+                fn size_hint(&self) -> usize {
+                    self.get().size_hint()
+                }
+            }
+
+            impl<DB> Decode<DB> for $ty
+            where
+                DB: Database,
+                $int: Decode<DB>,
+            {
+                fn decode(raw: &[u8]) -> Result<Self, DecodeError> {
+                    $ty::new(<$int>::decode(raw)?).ok_or_else(|| {
+                        DecodeError::Message(Box::new(concat!(
+                            "a zero cannot be decoded as a ",
+                            stringify!($ty)
+                        )))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero!(
+    NonZeroI8: i8,
+    NonZeroI16: i16,
+    NonZeroI32: i32,
+    NonZeroI64: i64,
+    NonZeroU8: u8,
+    NonZeroU16: u16,
+    NonZeroU32: u32,
+    NonZeroU64: u64
+);
@@ -0,0 +1,82 @@
+use std::error::Error as StdError;
+use std::fmt::Display;
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+
+use crate::database::Database;
+use crate::decode::{Decode, DecodeError};
+use crate::encode::Encode;
+use crate::types::HasSqlType;
+
+/// A value stored in a text column, e.g. a comma separated list or serialized JSON.
+///
+/// The value is sent with its [Display] implementation and received with its [FromStr]
+/// implementation, so any such type can be mapped to a text column.
+///
+/// ```ignore
+/// let ip: Text<IpAddr> = sqlx::query("SELECT '127.0.0.1'")
+///     .fetch_one(&mut conn)
+///     .await?
+///     .get(0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Text<T>(pub T);
+
+impl<T> Text<T> {
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Text<T> {
+    fn from(value: T) -> Self {
+        Text(value)
+    }
+}
+
+impl<T> Deref for Text<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Text<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T, DB> HasSqlType<Text<T>> for DB
+where
+    DB: HasSqlType<String>,
+{
+    fn metadata() -> Self::TypeMetadata {
+        <DB as HasSqlType<String>>::metadata()
+    }
+}
+
+impl<T, DB> Encode<DB> for Text<T>
+where
+    DB: Database,
+    T: Display,
+    String: Encode<DB>,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        self.0.to_string().encode(buf)
+    }
+}
+
+impl<T, DB> Decode<DB> for Text<T>
+where
+    DB: Database,
+    T: FromStr,
+    T::Err: StdError + Send + Sync + 'static,
+    String: Decode<DB>,
+{
+    fn decode(raw: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Text(String::decode(raw)?.parse()?))
+    }
+}
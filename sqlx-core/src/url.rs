@@ -1,5 +1,7 @@
+use std::borrow::Cow;
 use std::convert::{TryFrom, TryInto};
 
+/// A parsed database URL, as accepted by `Connection::open`.
 pub struct Url(url::Url);
 
 impl TryFrom<String> for Url {
@@ -27,6 +29,10 @@ impl<'s> TryFrom<&'s String> for Url {
 }
 
 impl Url {
+    pub fn scheme(&self) -> &str {
+        self.0.scheme()
+    }
+
     pub fn host(&self) -> &str {
         let host = self.0.host_str();
 
@@ -55,6 +61,13 @@ impl Url {
         self.0.password()
     }
 
+    /// Returns the (percent-decoded) value of the query parameter `key`, if present.
+    pub fn param(&self, key: &str) -> Option<Cow<'_, str>> {
+        self.0
+            .query_pairs()
+            .find_map(|(k, v)| if k == key { Some(v) } else { None })
+    }
+
     pub fn database(&self) -> Option<&str> {
         let database = self.0.path().trim_start_matches('/');
 
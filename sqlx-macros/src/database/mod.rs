@@ -1,3 +1,4 @@
+use tokio_sqlx::describe::{Column, Describe};
 use tokio_sqlx::Database;
 
 #[derive(PartialEq, Eq)]
@@ -19,10 +20,26 @@ pub trait DatabaseExt: Database {
     fn param_type_for_id(id: &Self::TypeId) -> Option<&'static str>;
 
     fn return_type_for_id(id: &Self::TypeId) -> Option<&'static str>;
+
+    /// Returns the Rust type for a result column.
+    ///
+    /// Override when the type ID alone is not enough to pick the type.
+    fn return_type_for_column(column: &Column<Self>) -> Option<&'static str> {
+        Self::return_type_for_id(&column.type_id)
+    }
+
+    /// Explains why a result column of a type without a Rust type can never be decoded, e.g.
+    /// because it is a pseudo-type, so that a more helpful error can be shown.
+    fn unsupported_column_reason(_column: &Column<Self>) -> Option<&'static str> {
+        None
+    }
+
+    /// Converts the result of describing a query to JSON for `SQLX_PREPARE`.
+    fn describe_to_json(describe: &Describe<Self>) -> serde_json::Value;
 }
 
 macro_rules! impl_database_ext {
-    ($database:path { $($(#[$meta:meta])? $ty:ty $(| $input:ty)?),*$(,)? }, ParamChecking::$param_checking:ident) => {
+    ($database:path { $($(#[$meta:meta])? $ty:ty $(| $input:ty)?),*$(,)? }, ParamChecking::$param_checking:ident $(, $extra:item)*$(,)?) => {
         impl $crate::database::DatabaseExt for $database {
             const DATABASE_PATH: &'static str = stringify!($database);
             const PARAM_CHECKING: $crate::database::ParamChecking = $crate::database::ParamChecking::$param_checking;
@@ -47,6 +64,12 @@ macro_rules! impl_database_ext {
                     _ => None
                 }
             }
+
+            fn describe_to_json(describe: &tokio_sqlx::describe::Describe<Self>) -> serde_json::Value {
+                $crate::query_macros::describe_to_json(describe)
+            }
+
+            $($extra)*
         }
     }
 }
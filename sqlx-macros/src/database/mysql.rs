@@ -1,12 +1,31 @@
 impl_database_ext! {
     tokio_sqlx::MySql {
+        // listed before `bool` so a plain `TINYINT` decodes as `i8`
+        i8,
         bool,
         String,
         i16,
         i32,
         i64,
         f32,
-        f64
+        f64,
+
+        // after `String` so only `JSON` columns are read as JSON
+        #[cfg(feature = "json")]
+        tokio_sqlx::types::JsonValue
     },
-    ParamChecking::Weak
+    ParamChecking::Weak,
+    fn return_type_for_column(column: &tokio_sqlx::describe::Column<Self>) -> Option<&'static str> {
+        // `BOOLEAN` is an alias for `TINYINT(1)` (type ID 1 with a display length of 1)
+        if column.type_id == 1 && column.display_size == Some(1) {
+            return Some("bool");
+        }
+
+        // `YEAR` (type ID 13) is decoded as a 2-byte integer
+        if column.type_id == 13 {
+            return Some("u16");
+        }
+
+        Self::return_type_for_id(&column.type_id)
+    }
 }
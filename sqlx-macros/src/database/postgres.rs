@@ -8,9 +8,59 @@ impl_database_ext! {
         f32,
         f64,
 
+        // oid
+        u32,
+
+        // bytea
+        Vec<u8>,
+
+        // macaddr, macaddr8
+        [u8; 6],
+        [u8; 8],
+
+        // arrays
+        Vec<bool>,
+        Vec<String>,
+        Vec<i16>,
+        Vec<i32>,
+        Vec<i64>,
+        Vec<u32>,
+        Vec<f32>,
+        Vec<f64>,
+
+        tokio_sqlx::postgres::types::PgRange<i32>,
+        tokio_sqlx::postgres::types::PgRange<i64>,
+
+        // `point` is received as a `geo_types::Point` when the `geo` feature is enabled
+        #[cfg(feature = "geo")]
+        tokio_sqlx::types::geo::Point<f64>,
+
+        tokio_sqlx::postgres::types::PgPoint,
+
+        tokio_sqlx::postgres::types::PgJsonPath,
+
         #[cfg(feature = "uuid")]
         tokio_sqlx::types::Uuid,
 
+        #[cfg(feature = "uuid")]
+        Vec<tokio_sqlx::types::Uuid>,
+
+        // `inet` and `cidr`; an `inet` may hold a network so it is never received as an `IpAddr`
+        #[cfg(feature = "ipnetwork")]
+        tokio_sqlx::types::IpNetwork,
+
+        #[cfg(feature = "ipnetwork")]
+        Vec<tokio_sqlx::types::IpNetwork>,
+
+        #[cfg(feature = "json")]
+        tokio_sqlx::types::JsonValue,
+
+        #[cfg(feature = "decimal")]
+        tokio_sqlx::types::Decimal,
+
+        #[cfg(feature = "decimal")]
+        Vec<tokio_sqlx::types::Decimal>,
+
         #[cfg(feature = "chrono")]
         tokio_sqlx::types::chrono::NaiveTime,
 
@@ -22,6 +72,61 @@ impl_database_ext! {
 
         #[cfg(feature = "chrono")]
         tokio_sqlx::types::chrono::DateTime<tokio_sqlx::types::chrono::Utc> | tokio_sqlx::types::chrono::DateTime<_>,
+
+        #[cfg(feature = "chrono")]
+        tokio_sqlx::types::chrono::Duration,
+
+        #[cfg(feature = "chrono")]
+        tokio_sqlx::postgres::types::PgTimeTz,
+
+        #[cfg(feature = "chrono")]
+        tokio_sqlx::postgres::types::PgRange<tokio_sqlx::types::chrono::NaiveDate>,
+
+        #[cfg(feature = "chrono")]
+        tokio_sqlx::postgres::types::PgRange<tokio_sqlx::types::chrono::NaiveDateTime>,
+
+        #[cfg(feature = "chrono")]
+        tokio_sqlx::postgres::types::PgRange<tokio_sqlx::types::chrono::DateTime<tokio_sqlx::types::chrono::Utc>>,
+
+        // after `chrono::Duration` which is preferred if enabled
+        tokio_sqlx::postgres::types::PgInterval,
+    },
+    ParamChecking::Strong,
+    fn return_type_for_column(column: &tokio_sqlx::describe::Column<Self>) -> Option<&'static str> {
+        // the types of extensions have no fixed OID so they are matched by name
+        match column.type_name.as_deref() {
+            Some("ltree") => Some("tokio_sqlx::postgres::types::PgLTree"),
+
+            // case-insensitive text; transmitted as plain text
+            Some("citext") => Some("String"),
+
+            // enums are transmitted as the text of their label
+            Some(_) if column.type_is_enum == Some(true) => Some("String"),
+
+            _ => match column.type_id {
+                // `int2vector` and `oidvector` of the system catalogs, e.g. `pg_index.indkey`,
+                // are transmitted as arrays
+                22 => Some("Vec<i16>"),
+                30 => Some("Vec<u32>"),
+
+                _ => Self::return_type_for_id(&column.type_id),
+            },
+        }
     },
-    ParamChecking::Strong
+    fn unsupported_column_reason(column: &tokio_sqlx::describe::Column<Self>) -> Option<&'static str> {
+        match column.type_id {
+            // `record`, `record[]`
+            2249 | 2287 => Some(
+                "is an anonymous record (e.g. from `ROW(..)` or a table used as a column) \
+                 which cannot be decoded; select its fields as separate columns instead",
+            ),
+
+            // `anyarray`, `anyelement`, `anynonarray`, `anyenum`
+            2277 | 2283 | 2776 | 3500 => Some(
+                "has a polymorphic pseudo-type which cannot be decoded; cast it to a concrete type",
+            ),
+
+            _ => None,
+        }
+    }
 }
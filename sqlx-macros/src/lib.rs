@@ -24,17 +24,40 @@ mod query_macros;
 
 use query_macros::*;
 
+// connects to the database of `url`, or through the Unix-domain socket at `DATABASE_SOCKET`
+// with the credentials and database of `url`, e.g. of a server that was started for the build
+#[cfg(feature = "postgres")]
+async fn connect_postgres(url: &Url) -> tokio_sqlx::Result<tokio_sqlx::postgres::PgConnection> {
+    use tokio_sqlx::Connection;
+
+    match dotenv::var("DATABASE_SOCKET") {
+        #[cfg(unix)]
+        Ok(path) => {
+            let stream = tokio::net::UnixStream::connect(path).await?;
+
+            tokio_sqlx::postgres::PgConnection::connect_stream(stream, url.as_str()).await
+        }
+
+        #[cfg(not(unix))]
+        Ok(_) => Err(tokio_sqlx::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "DATABASE_SOCKET is only supported on Unix",
+        ))),
+
+        Err(_) => tokio_sqlx::postgres::PgConnection::open(url.as_str()).await,
+    }
+}
+
 macro_rules! async_macro (
-    ($db:ident => $expr:expr) => {{
+    ($db:ident, $url_var:expr => $expr:expr) => {{
         let res: Result<proc_macro2::TokenStream> = tokio::runtime::Runtime::new().unwrap().block_on(async {
-            use tokio_sqlx::Connection;
-
-            let db_url = Url::parse(&dotenv::var("DATABASE_URL").map_err(|_| "DATABASE_URL not set")?)?;
+            let url_var = $url_var.to_owned();
+            let db_url = Url::parse(&dotenv::var(&url_var).map_err(|_| format!("{} not set", url_var))?)?;
 
             match db_url.scheme() {
                 #[cfg(feature = "postgres")]
                 "postgresql" | "postgres" => {
-                    let $db = tokio_sqlx::postgres::PgConnection::open(db_url.as_str())
+                    let $db = connect_postgres(&db_url)
                         .await
                         .map_err(|e| format!("failed to connect to database: {}", e))?;
 
@@ -42,12 +65,18 @@ macro_rules! async_macro (
                 }
                 #[cfg(not(feature = "postgres"))]
                 "postgresql" | "postgres" => Err(format!(
-                    "DATABASE_URL {} has the scheme of a Postgres database but the `postgres` \
+                    "{} {} has the scheme of a Postgres database but the `postgres` \
                      feature of sqlx was not enabled",
-                     db_url
+                     url_var, db_url
                 ).into()),
                 #[cfg(feature = "mysql")]
+                "mysql" | "mariadb" if dotenv::var("DATABASE_SOCKET").is_ok() => Err(
+                    "DATABASE_SOCKET is only supported for Postgres databases".into()
+                ),
+                #[cfg(feature = "mysql")]
                 "mysql" | "mariadb" => {
+                    use tokio_sqlx::Connection;
+
                     let $db = tokio_sqlx::mysql::MySqlConnection::open(db_url.as_str())
                             .await
                             .map_err(|e| format!("failed to connect to database: {}", e))?;
@@ -56,11 +85,11 @@ macro_rules! async_macro (
                 }
                 #[cfg(not(feature = "mysql"))]
                 "mysql" | "mariadb" => Err(format!(
-                    "DATABASE_URL {} has the scheme of a MySQL/MariaDB database but the `mysql` \
+                    "{} {} has the scheme of a MySQL/MariaDB database but the `mysql` \
                      feature of sqlx was not enabled",
-                     db_url
+                     url_var, db_url
                 ).into()),
-                scheme => Err(format!("unexpected scheme {:?} in DATABASE_URL {}", scheme, db_url).into()),
+                scheme => Err(format!("unexpected scheme {:?} in {} {}", scheme, url_var, db_url).into()),
             }
         });
 
@@ -82,26 +111,33 @@ macro_rules! async_macro (
 pub fn query(input: TokenStream) -> TokenStream {
     #[allow(unused_variables)]
     let input = parse_macro_input!(input as QueryMacroInput);
-    async_macro!(db => expand_query(input, db))
+    async_macro!(db, input.database_url_var() => expand_query(input, db))
 }
 
 #[proc_macro_hack]
 pub fn query_file(input: TokenStream) -> TokenStream {
     #[allow(unused_variables)]
     let input = parse_macro_input!(input as QueryMacroInput);
-    async_macro!(db => expand_query_file(input, db))
+    async_macro!(db, input.database_url_var() => expand_query_file(input, db))
 }
 
 #[proc_macro_hack]
 pub fn query_as(input: TokenStream) -> TokenStream {
     #[allow(unused_variables)]
     let input = parse_macro_input!(input as QueryAsMacroInput);
-    async_macro!(db => expand_query_as(input, db))
+    async_macro!(db, input.database_url_var() => expand_query_as(input, db))
 }
 
 #[proc_macro_hack]
 pub fn query_file_as(input: TokenStream) -> TokenStream {
     #[allow(unused_variables)]
     let input = parse_macro_input!(input as QueryAsMacroInput);
-    async_macro!(db => expand_query_file_as(input, db))
+    async_macro!(db, input.database_url_var() => expand_query_file_as(input, db))
+}
+
+#[proc_macro_hack]
+pub fn query_scalar(input: TokenStream) -> TokenStream {
+    #[allow(unused_variables)]
+    let input = parse_macro_input!(input as QueryAsMacroInput);
+    async_macro!(db, input.database_url_var() => expand_query_scalar(input, db))
 }
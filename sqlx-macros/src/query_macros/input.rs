@@ -1,25 +1,36 @@
 use std::env;
+use std::fmt::Display;
 
-use proc_macro2::Span;
+use proc_macro2::{Literal, Span};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::Token;
-use syn::{Expr, ExprLit, ExprPath, Lit};
+use syn::{Expr, ExprLit, Ident, Lit, LitStr, Path, PathArguments, Type, TypePath};
 use tokio::fs;
 
 use tokio_sqlx::describe::Describe;
 use tokio_sqlx::Connection;
 
+use super::prepare;
+use crate::database::DatabaseExt;
+
 /// Macro input shared by `query!()` and `query_file!()`
 pub struct QueryMacroInput {
     pub(super) source: String,
     pub(super) source_span: Span,
+    // the SQL string literal if the query was given inline, used to point errors into the query
+    source_literal: Option<Literal>,
+    // the path of the file the query was read from, used to point errors into the file
+    source_file: Option<String>,
     pub(super) args: Vec<Expr>,
+    // the environment variable holding the URL of the database to check the query against,
+    // if given with `env = "..."` before the query
+    database_url_var: Option<String>,
 }
 
 impl QueryMacroInput {
     fn from_exprs(input: ParseStream, mut args: impl Iterator<Item = Expr>) -> syn::Result<Self> {
-        let sql = match args.next() {
+        let sql = match args.next().map(ungroup) {
             Some(Expr::Lit(ExprLit {
                 lit: Lit::Str(sql), ..
             })) => sql,
@@ -35,14 +46,27 @@ impl QueryMacroInput {
         Ok(Self {
             source: sql.value(),
             source_span: sql.span(),
+            source_literal: Some(sql.token()),
+            source_file: None,
             args: args.collect(),
+            database_url_var: None,
         })
     }
 
+    /// The environment variable holding the URL of the database to check the query against.
+    pub fn database_url_var(&self) -> &str {
+        self.database_url_var.as_deref().unwrap_or("DATABASE_URL")
+    }
+
     pub async fn expand_file_src(self) -> syn::Result<Self> {
         let source = read_file_src(&self.source, self.source_span).await?;
 
-        Ok(Self { source, ..self })
+        Ok(Self {
+            source_file: Some(self.source.clone()),
+            source,
+            source_literal: None,
+            ..self
+        })
     }
 
     /// Run a parse/describe on the query described by this input and validate that it matches the
@@ -50,11 +74,26 @@ impl QueryMacroInput {
     pub async fn describe_validate<C: Connection>(
         &self,
         conn: &mut C,
-    ) -> crate::Result<Describe<C::Database>> {
+    ) -> crate::Result<Describe<C::Database>>
+    where
+        C::Database: DatabaseExt + Sized,
+    {
+        if cfg!(feature = "deny-select-star") {
+            if let Some(offset) = find_wildcard(&self.source) {
+                return Err(self
+                    .error_at(
+                        offset,
+                        "wildcards are not allowed with the `deny-select-star` feature; \
+                         list the columns of the query explicitly",
+                    )
+                    .into());
+            }
+        }
+
         let describe = conn
             .describe(&self.source)
             .await
-            .map_err(|e| syn::Error::new(self.source_span, e))?;
+            .map_err(|e| self.describe_error(e))?;
 
         if self.args.len() != describe.param_types.len() {
             return Err(syn::Error::new(
@@ -68,21 +107,102 @@ impl QueryMacroInput {
             .into());
         }
 
+        if prepare::enabled() {
+            prepare::write_query_data(&self.source, &describe).await?;
+        }
+
         Ok(describe)
     }
 }
 
+impl QueryMacroInput {
+    // Build an error that points at the position in the query reported by the database, if any
+    fn describe_error(&self, err: tokio_sqlx::Error) -> syn::Error {
+        let position = match &err {
+            tokio_sqlx::Error::Database(err) => err.position(),
+            _ => None,
+        };
+
+        // the reported position is a 1-based character index
+        let offset = position.and_then(|pos| self.source.char_indices().nth(pos.checked_sub(1)?));
+
+        match offset {
+            Some((offset, _)) => self.error_at(offset, err),
+            None => self.error(err),
+        }
+    }
+
+    // Build an error about the query, naming the file of the query if it was read from one
+    pub(super) fn error(&self, err: impl Display) -> syn::Error {
+        match &self.source_file {
+            Some(file) => syn::Error::new(self.source_span, format!("{}: {}", file, err)),
+            None => syn::Error::new(self.source_span, err),
+        }
+    }
+
+    // Build an error about the output column `name` that shows where it is named in the query
+    pub(super) fn column_error(&self, name: &str, err: impl Display) -> syn::Error {
+        match find_name(&self.source, name) {
+            Some(offset) => self.error_at(offset, err),
+            None => self.error(err),
+        }
+    }
+
+    // Build an error that shows the line of the query containing the byte `offset`
+    fn error_at(&self, offset: usize, err: impl Display) -> syn::Error {
+        let line_start = self.source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.source[offset..]
+            .find('\n')
+            .map_or(self.source.len(), |i| offset + i);
+
+        let line = &self.source[line_start..line_end];
+        let column = self.source[line_start..offset].chars().count();
+
+        let message = match &self.source_file {
+            // the span of the macro cannot point into the file so name the position in it instead
+            Some(file) => format!(
+                "{}:{}:{}: {}\n\n{}\n{}^",
+                file,
+                self.source[..offset].matches('\n').count() + 1,
+                column + 1,
+                err,
+                line,
+                " ".repeat(column)
+            ),
+
+            None => format!("{}\n\n{}\n{}^", err, line, " ".repeat(column)),
+        };
+
+        // this is only approximate as escape sequences in the literal will offset the position;
+        // `subspan()` also always returns `None` on stable
+        let span = self
+            .source_literal
+            .as_ref()
+            .and_then(|lit| {
+                let start = lit.to_string().find('"')? + 1 + offset;
+                lit.subspan(start..start + 1)
+            })
+            .unwrap_or(self.source_span);
+
+        syn::Error::new(span, message)
+    }
+}
+
 impl Parse for QueryMacroInput {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let database_url_var = parse_database_url_var(input)?;
         let args = Punctuated::<Expr, Token![,]>::parse_terminated(input)?.into_iter();
 
-        Self::from_exprs(input, args)
+        Ok(Self {
+            database_url_var,
+            ..Self::from_exprs(input, args)?
+        })
     }
 }
 
 /// Macro input shared by `query_as!()` and `query_file_as!()`
 pub struct QueryAsMacroInput {
-    pub(super) as_ty: ExprPath,
+    pub(super) as_ty: Path,
     pub(super) query_input: QueryMacroInput,
 }
 
@@ -93,30 +213,96 @@ impl QueryAsMacroInput {
             ..self
         })
     }
+
+    /// The environment variable holding the URL of the database to check the query against.
+    pub fn database_url_var(&self) -> &str {
+        self.query_input.database_url_var()
+    }
 }
 
 impl Parse for QueryAsMacroInput {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let mut args = Punctuated::<Expr, Token![,]>::parse_terminated(input)?.into_iter();
+        let database_url_var = parse_database_url_var(input)?;
 
-        let as_ty = match args.next() {
-            Some(Expr::Path(path)) => path,
-            Some(other_expr) => {
-                return Err(syn::Error::new_spanned(
-                    other_expr,
-                    "expected path to a type",
-                ));
-            }
-            None => return Err(input.error("expected path to SQL file")),
-        };
+        if input.is_empty() {
+            return Err(input.error("expected path to a type"));
+        }
+
+        let as_ty = type_path(input.parse()?)?;
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        let args = Punctuated::<Expr, Token![,]>::parse_terminated(input)?.into_iter();
 
         Ok(QueryAsMacroInput {
             as_ty,
-            query_input: QueryMacroInput::from_exprs(input, args)?,
+            query_input: QueryMacroInput {
+                database_url_var,
+                ..QueryMacroInput::from_exprs(input, args)?
+            },
         })
     }
 }
 
+// The database to check a query against can be chosen with a leading `env = "VAR",`, naming the
+// environment variable holding its URL, e.g. in a workspace with both Postgres and MySQL databases
+fn parse_database_url_var(input: ParseStream) -> syn::Result<Option<String>> {
+    if !(input.peek(Ident) && input.peek2(Token![=])) {
+        return Ok(None);
+    }
+
+    let key: Ident = input.parse()?;
+
+    if key != "env" {
+        return Err(syn::Error::new(key.span(), "expected `env`"));
+    }
+
+    input.parse::<Token![=]>()?;
+    let var: LitStr = input.parse()?;
+
+    if input.is_empty() {
+        return Err(input.error("expected the query after the environment variable"));
+    }
+
+    input.parse::<Token![,]>()?;
+
+    Ok(Some(var.value()))
+}
+
+// the output type may have generic arguments, e.g. `Wrapper<i32>`, which have to be written with
+// a turbofish (`Wrapper::<i32>`) to be used in a struct expression
+fn type_path(ty: Type) -> syn::Result<Path> {
+    match ty {
+        Type::Group(group) => type_path(*group.elem),
+
+        Type::Path(TypePath {
+            qself: None,
+            mut path,
+        }) => {
+            for segment in &mut path.segments {
+                if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                    args.colon2_token.get_or_insert_with(Default::default);
+                }
+            }
+
+            Ok(path)
+        }
+
+        other => Err(syn::Error::new_spanned(other, "expected path to a type")),
+    }
+}
+
+// `macro_rules!` wrappers forward `$query:literal` and `$out_struct:path` to us wrapped in
+// invisible groups; `Type::Group` is unwrapped the same way in `type_path`
+fn ungroup(expr: Expr) -> Expr {
+    match expr {
+        Expr::Group(group) => ungroup(*group.expr),
+        expr => expr,
+    }
+}
+
 async fn read_file_src(source: &str, source_span: Span) -> syn::Result<String> {
     use std::path::Path;
 
@@ -164,3 +350,217 @@ async fn read_file_src(source: &str, source_span: Span) -> syn::Result<String> {
         )
     })
 }
+
+// Returns the byte offset of the first `*` in the query that selects all columns, e.g. in
+// `SELECT *`, `SELECT t.*` or `RETURNING *`; `count(*)` and multiplication are not matched
+fn find_wildcard(sql: &str) -> Option<usize> {
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            // skip over strings and quoted identifiers
+            '\'' | '"' => {
+                for (_, next) in chars.by_ref() {
+                    if next == c {
+                        break;
+                    }
+                }
+            }
+
+            '-' if chars.peek().map(|&(_, c)| c) == Some('-') => {
+                for (_, next) in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+
+            '/' if chars.peek().map(|&(_, c)| c) == Some('*') => {
+                chars.next();
+
+                let mut prev = ' ';
+                for (_, next) in chars.by_ref() {
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+
+                    prev = next;
+                }
+            }
+
+            '*' => {
+                let before = sql[..i].trim_end();
+
+                if before.ends_with(',') || before.ends_with('.') {
+                    return Some(i);
+                }
+
+                let word = before
+                    .rsplit(|c: char| !c.is_alphanumeric() && c != '_')
+                    .next()
+                    .unwrap_or_default();
+
+                if ["select", "distinct", "all", "returning"]
+                    .iter()
+                    .any(|kw| word.eq_ignore_ascii_case(kw))
+                {
+                    return Some(i);
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    None
+}
+
+// Returns the byte offset of the first occurrence of `name` as a whole word in the query,
+// ignoring ASCII case as unquoted identifiers are folded by the database
+fn find_name(sql: &str, name: &str) -> Option<usize> {
+    if name.is_empty() {
+        return None;
+    }
+
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    (0..sql.len())
+        .filter(|&i| sql.is_char_boundary(i) && sql.is_char_boundary(i + name.len()))
+        .filter(|&i| i + name.len() <= sql.len())
+        .find(|&i| {
+            sql[i..i + name.len()].eq_ignore_ascii_case(name)
+                && !sql[..i].ends_with(is_word)
+                && !sql[i + name.len()..].starts_with(is_word)
+        })
+}
+
+#[test]
+fn test_find_name() {
+    assert_eq!(find_name("SELECT id, name FROM accounts", "name"), Some(11));
+    assert_eq!(
+        find_name("SELECT username, Name FROM accounts", "name"),
+        Some(17)
+    );
+    assert_eq!(find_name("SELECT username FROM accounts", "name"), None);
+}
+
+#[test]
+fn test_error_in_file() {
+    let input = QueryMacroInput {
+        source: "SELECT id,\n       name\nFROM accounts".into(),
+        source_span: Span::call_site(),
+        source_literal: None,
+        source_file: Some("queries/accounts.sql".into()),
+        args: Vec::new(),
+        database_url_var: None,
+    };
+
+    assert_eq!(
+        input.column_error("name", "unknown type").to_string(),
+        "queries/accounts.sql:2:8: unknown type\n\n       name\n       ^"
+    );
+    assert_eq!(
+        input.column_error("?column?", "unknown type").to_string(),
+        "queries/accounts.sql: unknown type"
+    );
+}
+
+#[test]
+fn test_describe_error_position() {
+    use std::error::Error as StdError;
+    use std::fmt::{self, Formatter};
+
+    #[derive(Debug)]
+    struct PositionError(Option<usize>);
+
+    impl Display for PositionError {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("column \"nmae\" does not exist")
+        }
+    }
+
+    impl StdError for PositionError {}
+
+    impl tokio_sqlx::error::DatabaseError for PositionError {
+        fn message(&self) -> &str {
+            "column \"nmae\" does not exist"
+        }
+
+        fn as_std_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
+            self
+        }
+
+        fn position(&self) -> Option<usize> {
+            self.0
+        }
+    }
+
+    let input = QueryMacroInput {
+        source: "SELECT id,\n       nmae\nFROM accounts".into(),
+        source_span: Span::call_site(),
+        source_literal: None,
+        source_file: None,
+        args: Vec::new(),
+        database_url_var: None,
+    };
+
+    assert_eq!(
+        input
+            .describe_error(PositionError(Some(19)).into())
+            .to_string(),
+        "column \"nmae\" does not exist\n\n       nmae\n       ^"
+    );
+
+    // without a position, or with one past the end of the query, the whole query is pointed at
+    for &position in &[None, Some(100)] {
+        assert_eq!(
+            input
+                .describe_error(PositionError(position).into())
+                .to_string(),
+            "column \"nmae\" does not exist"
+        );
+    }
+}
+
+#[test]
+fn test_parse_database_url_var() {
+    let input: QueryMacroInput =
+        syn::parse_str(r#"env = "DATABASE_URL_MYSQL", "SELECT ?", id"#).unwrap();
+
+    assert_eq!(input.database_url_var(), "DATABASE_URL_MYSQL");
+    assert_eq!(input.source, "SELECT ?");
+    assert_eq!(input.args.len(), 1);
+
+    let input: QueryAsMacroInput =
+        syn::parse_str(r#"env = "DATABASE_URL_MYSQL", Account, "SELECT id""#).unwrap();
+
+    assert_eq!(input.database_url_var(), "DATABASE_URL_MYSQL");
+
+    let input: QueryMacroInput = syn::parse_str(r#""SELECT 1""#).unwrap();
+
+    assert_eq!(input.database_url_var(), "DATABASE_URL");
+
+    assert!(syn::parse_str::<QueryMacroInput>(r#"url = "DATABASE_URL", "SELECT 1""#).is_err());
+}
+
+#[test]
+fn test_find_wildcard() {
+    assert_eq!(find_wildcard("SELECT * FROM accounts"), Some(7));
+    assert_eq!(
+        find_wildcard("select distinct a.* from accounts a"),
+        Some(18)
+    );
+    assert_eq!(find_wildcard("SELECT id, * FROM accounts"), Some(11));
+
+    assert_eq!(
+        find_wildcard("INSERT INTO accounts (name) VALUES ('*') RETURNING *"),
+        Some(51)
+    );
+    assert_eq!(
+        find_wildcard("DELETE FROM accounts a RETURNING a.*"),
+        Some(35)
+    );
+    assert_eq!(find_wildcard("SELECT count(*) FROM accounts"), None);
+    assert_eq!(find_wildcard("SELECT 2 * price FROM accounts"), None);
+    assert_eq!(find_wildcard("SELECT '*' /* * */ -- SELECT *"), None);
+}
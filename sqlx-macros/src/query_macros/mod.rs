@@ -4,6 +4,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 pub use input::{QueryAsMacroInput, QueryMacroInput};
+pub use prepare::describe_to_json;
 pub use query::expand_query;
 
 use crate::database::DatabaseExt;
@@ -14,6 +15,7 @@ use tokio_sqlx::Connection;
 mod args;
 mod input;
 mod output;
+mod prepare;
 mod query;
 
 pub async fn expand_query_file<C: Connection>(
@@ -38,21 +40,17 @@ where
     let describe = input.query_input.describe_validate(&mut conn).await?;
 
     if describe.result_columns.is_empty() {
-        return Err(syn::Error::new(
-            input.query_input.source_span,
-            "query must output at least one column",
-        )
-        .into());
+        return Err(input
+            .query_input
+            .error("query must output at least one column")
+            .into());
     }
 
     let args_tokens = args::quote_args(&input.query_input, &describe)?;
 
-    let columns = output::columns_to_rust(&describe)?;
-    let output = output::quote_query_as::<C::Database>(
-        &input.query_input.source,
-        &input.as_ty.path,
-        &columns,
-    );
+    let columns = output::columns_to_rust(&input.query_input, &describe)?;
+    let output =
+        output::quote_query_as::<C::Database>(&input.query_input.source, &input.as_ty, &columns);
 
     Ok(quote! {{
         #args_tokens
@@ -70,3 +68,33 @@ where
 {
     expand_query_as(input.expand_file_src().await?, conn).await
 }
+
+pub async fn expand_query_scalar<C: Connection>(
+    input: QueryAsMacroInput,
+    mut conn: C,
+) -> crate::Result<TokenStream>
+where
+    C::Database: DatabaseExt + Sized,
+    <C::Database as HasTypeMetadata>::TypeId: Display,
+{
+    let describe = input.query_input.describe_validate(&mut conn).await?;
+
+    if describe.result_columns.len() != 1 {
+        return Err(input
+            .query_input
+            .error(format_args!(
+                "query must output exactly one column, found {}",
+                describe.result_columns.len()
+            ))
+            .into());
+    }
+
+    let args_tokens = args::quote_args(&input.query_input, &describe)?;
+
+    let output = output::quote_query_scalar::<C::Database>(&input.query_input.source, &input.as_ty);
+
+    Ok(quote! {{
+        #args_tokens
+        #output.bind_all(args)
+    }})
+}
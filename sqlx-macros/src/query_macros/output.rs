@@ -1,34 +1,79 @@
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
-use syn::Path;
+use quote::{quote, ToTokens};
+use syn::{Path, Type};
 
 use tokio_sqlx::describe::Describe;
 
 use crate::database::DatabaseExt;
 
+use super::QueryMacroInput;
+
 pub struct RustColumn {
     pub(super) ident: Ident,
     pub(super) type_: TokenStream,
 }
 
-pub fn columns_to_rust<DB: DatabaseExt>(describe: &Describe<DB>) -> crate::Result<Vec<RustColumn>> {
+// errors name the position of the offending column in the query, or in the file it was read from
+pub fn columns_to_rust<DB: DatabaseExt>(
+    input: &QueryMacroInput,
+    describe: &Describe<DB>,
+) -> crate::Result<Vec<RustColumn>> {
     describe
         .result_columns
         .iter()
         .enumerate()
         .map(|(i, column)| -> crate::Result<_> {
-            let name = column
-                .name
-                .as_deref()
-                .ok_or_else(|| format!("column at position {} must have a name", i))?;
+            let name = column.name.as_deref().ok_or_else(|| {
+                input.error(format_args!("column at position {} must have a name", i))
+            })?;
+
+            // the type of a column can be overridden in its name, e.g. `status AS "status: Status"`
+            let (name, override_type) = match name.find(':') {
+                Some(pos) => (name[..pos].trim(), Some(name[pos + 1..].trim())),
+                None => (name, None),
+            };
+
+            let ident = syn::parse_str::<Ident>(name).map_err(|_| {
+                input.column_error(
+                    name,
+                    format_args!("{:?} is not a valid Rust identifier", name),
+                )
+            })?;
+
+            let type_ = match override_type {
+                Some(ty) => syn::parse_str::<Type>(ty)
+                    .map_err(|_| {
+                        input.column_error(name, format_args!("{:?} is not a valid Rust type", ty))
+                    })?
+                    .into_token_stream(),
 
-            let ident = syn::parse_str::<Ident>(name)
-                .map_err(|_| format!("{:?} is not a valid Rust identifier", name))?;
+                None => <DB as DatabaseExt>::return_type_for_column(column)
+                    // e.g. the columns of the right table of a `LEFT JOIN`
+                    .map(|type_| match column.nullable {
+                        Some(true) => format!("Option<{}>", type_),
+                        _ => type_.to_owned(),
+                    })
+                    .ok_or_else(|| {
+                        input.column_error(
+                            name,
+                            match DB::unsupported_column_reason(column) {
+                                Some(reason) => format!(
+                            "column {:?} {}; override the type of the column with an alias \
+                             like `AS \"{}: T\"` if `T` can decode it",
+                            name, reason, name
+                        ),
 
-            let type_ = <DB as DatabaseExt>::return_type_for_id(&column.type_id)
-                .ok_or_else(|| format!("unknown field type ID: {}", &column.type_id))?
-                .parse::<TokenStream>()
-                .unwrap();
+                                None => format!(
+                                    "unknown field type ID: {}; override the type of the column \
+                             with an alias like `AS \"{}: T\"`",
+                                    &column.type_id, name
+                                ),
+                            },
+                        )
+                    })?
+                    .parse::<TokenStream>()
+                    .unwrap(),
+            };
 
             Ok(RustColumn { ident, type_ })
         })
@@ -61,3 +106,15 @@ pub fn quote_query_as<DB: DatabaseExt>(
         })
     }
 }
+
+pub fn quote_query_scalar<DB: DatabaseExt>(sql: &str, out_ty: &Path) -> TokenStream {
+    let db_path = DB::quotable_path();
+
+    // the column type is not checked; the `Decode` impl of `out_ty` is trusted instead
+    quote! {
+        tokio_sqlx::query_as_mapped::<#db_path, _>(#sql, |row| {
+            use tokio_sqlx::row::RowIndex as _;
+            0usize.try_get::<#out_ty>(&row)
+        })
+    }
+}
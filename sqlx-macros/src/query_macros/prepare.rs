@@ -0,0 +1,168 @@
+//! Support for writing the data of every query to disk, so it can be assembled into a
+//! `sqlx-data.json` describing the queries of a crate without a database.
+//!
+//! When `SQLX_PREPARE` is set during a build, every query macro writes the result of describing
+//! its query to `sqlx/query-<hash>.json` in the target directory of cargo (`CARGO_TARGET_DIR` or
+//! `target` in the root of the workspace), where `<hash>` is the SHA-256 of the query. As macro
+//! expansions are cached, touch or clean the crate beforehand so every query is expanded again:
+//!
+//! ```text
+//! cargo clean -p my-crate && SQLX_PREPARE=1 cargo check
+//! jq -s 'map({ (.hash): . }) | add' target/sqlx/query-*.json > sqlx-data.json
+//! ```
+//!
+//! Nothing in this crate reads `sqlx-data.json` back; the macros always describe their queries
+//! against `DATABASE_URL`.
+
+use std::env;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use tokio::fs;
+
+use tokio_sqlx::describe::Describe;
+use tokio_sqlx::types::HasTypeMetadata;
+
+use crate::database::DatabaseExt;
+
+/// Returns `true` if the data of every query should be written to disk.
+pub fn enabled() -> bool {
+    matches!(env::var_os("SQLX_PREPARE"), Some(value) if value != "0")
+}
+
+/// Writes the data of a query and the result of describing it to its own file.
+pub async fn write_query_data<DB>(sql: &str, describe: &Describe<DB>) -> crate::Result<()>
+where
+    DB: DatabaseExt,
+{
+    let hash = format!("{:x}", Sha256::digest(sql.as_bytes()));
+
+    let data = json!({
+        "query": sql,
+        "hash": hash,
+        "database": DB::DATABASE_PATH,
+        "describe": DB::describe_to_json(describe),
+    });
+
+    write_data(&target_dir()?.join("sqlx"), &hash, &data).await
+}
+
+// writes the data of a query to `query-<hash>.json` in `dir`
+async fn write_data(dir: &Path, hash: &str, data: &Value) -> crate::Result<()> {
+    fs::create_dir_all(dir)
+        .await
+        .map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
+
+    let path = dir.join(format!("query-{}.json", hash));
+
+    fs::write(&path, serde_json::to_vec_pretty(data)?)
+        .await
+        .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+
+    Ok(())
+}
+
+/// Converts the result of describing a query to JSON, with type and table IDs as strings.
+pub fn describe_to_json<DB>(describe: &Describe<DB>) -> Value
+where
+    DB: DatabaseExt,
+    <DB as HasTypeMetadata>::TypeId: Display,
+    <DB as HasTypeMetadata>::TableId: Display,
+{
+    let param_types = describe
+        .param_types
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>();
+
+    let result_columns = describe
+        .result_columns
+        .iter()
+        .map(|column| {
+            json!({
+                "name": column.name.as_deref(),
+                "table_id": column.table_id.as_ref().map(|id| id.to_string()),
+                "type_id": column.type_id.to_string(),
+                "type_name": column.type_name.as_deref(),
+                "type_is_enum": column.type_is_enum,
+                "format": column.format,
+                "type_size": column.type_size,
+                "type_modifier": column.type_modifier,
+                "display_size": column.display_size,
+                "nullable": column.nullable,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "param_types": param_types,
+        "result_columns": result_columns,
+    })
+}
+
+// resolves the target directory like cargo does, from `CARGO_TARGET_DIR`, the configuration of
+// cargo or the root of the workspace
+fn target_dir() -> crate::Result<PathBuf> {
+    if let Some(dir) = env::var_os("CARGO_TARGET_DIR") {
+        return Ok(dir.into());
+    }
+
+    let manifest_dir =
+        env::var_os("CARGO_MANIFEST_DIR").ok_or("`CARGO_MANIFEST_DIR` must be set")?;
+
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+
+    let output = Command::new(cargo)
+        .args([
+            "metadata",
+            "--format-version",
+            "1",
+            "--no-deps",
+            "--manifest-path",
+        ])
+        .arg(PathBuf::from(manifest_dir).join("Cargo.toml"))
+        .output()
+        .map_err(|e| format!("failed to run `cargo metadata`: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "`cargo metadata` failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+
+    let metadata: Value = serde_json::from_slice(&output.stdout)?;
+
+    let dir = metadata["target_directory"]
+        .as_str()
+        .ok_or("`cargo metadata` did not return the target directory")?;
+
+    Ok(dir.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_writes_query_data() {
+        let dir = env::temp_dir().join(format!("sqlx-prepare-{}", std::process::id()));
+
+        let data = json!({ "query": "SELECT 1", "hash": "abc" });
+
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(write_data(&dir, "abc", &data))
+            .unwrap();
+
+        let written = std::fs::read(dir.join("query-abc.json")).unwrap();
+
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(serde_json::from_slice::<Value>(&written).unwrap(), data);
+    }
+}
@@ -32,7 +32,7 @@ where
         }});
     }
 
-    let columns = output::columns_to_rust(&describe)?;
+    let columns = output::columns_to_rust(&input, &describe)?;
 
     // record_type will be wrapped in parens which the compiler ignores without a trailing comma
     // e.g. (Foo) == Foo but (Foo,) = one-element tuple
@@ -49,14 +49,32 @@ where
         )
         .collect::<TokenStream>();
 
+    // implemented by hand so a column type that doesn't implement `Debug` doesn't break the macro
+    let record_debug_fields = columns
+        .iter()
+        .map(|output::RustColumn { ident, .. }| {
+            let name = ident.to_string();
+            quote!((&tokio_sqlx::record_debug::DebugField(&self.#ident)).debug_field(#name, &mut builder);)
+        })
+        .collect::<TokenStream>();
+
     let output = output::quote_query_as::<C::Database>(sql, &record_type, &columns);
 
     Ok(quote! {{
-        #[derive(Debug)]
         struct #record_type {
             #record_fields
         }
 
+        impl std::fmt::Debug for #record_type {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                use tokio_sqlx::record_debug::{DebugFieldExt as _, DebugFieldFallbackExt as _};
+
+                let mut builder = f.debug_struct(stringify!(#record_type));
+                #record_debug_fields
+                builder.finish()
+            }
+        }
+
         #args
 
         #output.bind_all(args)
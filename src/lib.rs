@@ -5,14 +5,12 @@ pub use sqlx_core::{arguments, decode, describe, encode, error, pool, row, types
 
 // Types
 pub use sqlx_core::{
-    Connection, Database, Error, Executor, FromRow, Pool, Query, QueryAs, Result, Row,
+    Acquire, CommandTag, Connection, Database, Error, Executor, FromRow, IsolationLevel, Pool,
+    Query, QueryAs, QueryBuilder, Result, Row, Transaction, TryMap, Url,
 };
 
 // Functions
-pub use sqlx_core::{query, query_as};
-
-#[doc(hidden)]
-pub use sqlx_core::query_as_mapped;
+pub use sqlx_core::{query, query_as, query_as_mapped};
 
 #[cfg(feature = "mysql")]
 pub use sqlx_core::mysql::{self, MySql, MySqlConnection, MySqlPool};
@@ -20,6 +18,9 @@ pub use sqlx_core::mysql::{self, MySql, MySqlConnection, MySqlPool};
 #[cfg(feature = "postgres")]
 pub use sqlx_core::postgres::{self, PgConnection, PgPool, Postgres};
 
+#[cfg(all(feature = "any", any(feature = "postgres", feature = "mysql")))]
+pub use sqlx_core::any::{self, Any, AnyConnection, AnyPool};
+
 #[macro_export]
 mod macros;
 
@@ -47,6 +48,12 @@ pub use sqlx_macros::query_file as query_file_;
 #[allow(dead_code)]
 pub use sqlx_macros::query_file_as as query_file_as_;
 
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[proc_macro_hack::proc_macro_hack(fake_call_site)]
+#[allow(dead_code)]
+pub use sqlx_macros::query_scalar as query_scalar_;
+
 // macro support
 #[cfg(feature = "macros")]
 #[doc(hidden)]
@@ -55,3 +62,7 @@ pub mod ty_cons;
 #[cfg(feature = "macros")]
 #[doc(hidden)]
 pub mod result_ext;
+
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod record_debug;
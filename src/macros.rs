@@ -16,7 +16,8 @@
 ///     .fetch_one(&mut conn)
 ///     .await?;
 ///
-/// // anonymous struct has `#[derive(Debug)]` for convenience
+/// // anonymous struct implements `Debug` for convenience; columns of types that do not
+/// // implement `Debug` themselves are printed as their type name
 /// println!("{:?}", account);
 /// println!("{}: {}", account.id, account.name);
 ///
@@ -62,11 +63,23 @@
 /// * Postgres: `$N` where `N` is the 1-based positional argument index
 /// * MySQL: `?` which matches arguments in order that it appears in the query
 ///
+/// If the database cannot infer the type of a parameter from its usage, an explicit cast in the
+/// SQL (e.g. `$1::bigint` in Postgres) determines the Rust type the argument is checked against.
+///
 /// ## Requirements
 /// * The `DATABASE_URL` environment variable must be set at build-time to point to a database
 /// server with the schema that the query string will be checked against. (All variants of
 /// `query!()` use [dotenv] so this can be in a `.env` file instead.)
 ///
+///     * To check against a database that is spun up for the build, a build script of the
+///       crate can point the macros at it with `cargo:rustc-env=DATABASE_URL=...`. A Postgres
+///       server can also be reached through the directory of its Unix-domain socket, e.g.
+///       `postgres://localhost/app?host=/tmp/build-db`.
+///
+///     * If `DATABASE_SOCKET` is also set (Postgres on Unix only), the macros connect to the
+///       Unix-domain socket at that path instead, such as the socket of a proxy or of a server
+///       started for the build, and use `DATABASE_URL` only for the credentials and database.
+///
 /// * The query must be a string literal or else it cannot be introspected (and thus cannot
 /// be dynamic or the result of another macro).
 ///
@@ -78,6 +91,63 @@
 ///       determine the database type.
 ///
 /// [dotenv]: https://crates.io/crates/dotenv
+///
+/// ## Choosing the Database
+/// In a crate with queries for more than one database, e.g. both a Postgres and a MySQL
+/// database, a query can be checked against another database by naming the environment
+/// variable holding its URL before the query. This works with all variants of `query!()`:
+///
+/// ```rust,ignore
+/// let account = sqlx::query!(env = "DATABASE_URL_MYSQL", "SELECT id, name FROM accounts WHERE id = ?", id)
+///     .fetch_one(&mut mysql_conn)
+///     .await?;
+/// ```
+///
+/// ## Column Types
+/// The Rust type of every output column is inferred from the type of the column in the database.
+/// Postgres enums are read as the `String` of their label. Columns of views and materialized
+/// views have the types of the expressions they select, and columns of a domain type have the
+/// type of the domain's base type.
+///
+/// `CHAR(n)` columns are read as a `String` padded with spaces to `n` characters, as sent by
+/// Postgres; override the type with `PgBpChar` to trim the padding, which SQL ignores in
+/// comparisons.
+///
+/// The nullability of a column is mostly not inferred: every column is read as its non-optional
+/// type, which fails with `DecodeError::UnexpectedNull` if the column is `NULL`, unless the field
+/// of the struct passed to [query_as!] is an `Option`. In Postgres, the columns of the nullable
+/// side of an outer join (e.g. the right table of a `LEFT JOIN`) are read as an `Option`.
+///
+/// The type of a column can be overridden with a column alias of the form `"name: Type"`, e.g.
+/// for a type implementing `HasSqlType` and `Decode`:
+///
+/// ```rust,ignore
+/// sqlx::query!(r#"SELECT status as "status: Status" FROM orders"#)
+/// ```
+///
+/// The column is then decoded with the `Decode` impl of the given type instead of being checked
+/// against the type of the column, so that e.g. a JSON column can be read as a domain type.
+///
+/// ## Wildcards
+/// The output of a query using `SELECT *` (or `RETURNING *`) changes with the schema of the table, which may
+/// silently change the fields of the generated struct after a migration. Enabling the
+/// `deny-select-star` feature makes the query macros reject queries selecting all columns
+/// with a wildcard so that the columns must be listed explicitly.
+///
+/// ## Preparing Query Data
+/// If the `SQLX_PREPARE` environment variable is set at build-time, every query macro also writes
+/// the query and the result of describing it to `sqlx/query-<hash>.json` in the target directory
+/// of cargo (`CARGO_TARGET_DIR` or `target` in the root of the workspace). These files can be
+/// assembled into a `sqlx-data.json`:
+///
+/// ```text
+/// cargo clean -p my-crate && SQLX_PREPARE=1 cargo check
+/// jq -s 'map({ (.hash): . }) | add' target/sqlx/query-*.json > sqlx-data.json
+/// ```
+///
+/// The query macros themselves do not read `sqlx-data.json` and always need `DATABASE_URL`;
+/// the file records the queries of a crate, e.g. to check in CI that they did not change.
+///
 /// ## See Also
 /// * [query_as!] if you want to use a struct you can name,
 /// * [query_file!] if you want to define the SQL query out-of-line,
@@ -94,6 +164,14 @@ macro_rules! query (
     ($query:literal, $($args:tt)*) => (#[allow(dead_code)]{
         #![allow(dead_code)]
         $crate::query_!($query, $($args)*)
+    });
+    // `env = "VAR"`; the key is forwarded as written (and checked by the proc macro) as the
+    // first token of the input determines the call site of the expansion
+    ($key:ident = $env:literal, $query:literal) => (#[allow(dead_code)] {
+        $crate::query_!($key = $env, $query)
+    });
+    ($key:ident = $env:literal, $query:literal, $($args:tt)*) => (#[allow(dead_code)] {
+        $crate::query_!($key = $env, $query, $($args)*)
     })
 );
 
@@ -108,6 +186,9 @@ macro_rules! query (
 /// unlike `include_str!()` which uses compiler internals to get the path of the file where it
 /// was invoked.
 ///
+/// The contents of the file are embedded at compile time and can be retrieved with `.sql()`
+/// on the returned query, e.g. for logging.
+///
 /// -----
 ///
 /// `examples/queries/account-by-id.sql`:
@@ -147,6 +228,12 @@ macro_rules! query_file (
     });
     ($query:literal, $($args:tt)*) => (#[allow(dead_code)]{
         $crate::query_file_!($query, $($args)*)
+    });
+    ($key:ident = $env:literal, $query:literal) => (#[allow(dead_code)]{
+        $crate::query_file_!($key = $env, $query)
+    });
+    ($key:ident = $env:literal, $query:literal, $($args:tt)*) => (#[allow(dead_code)]{
+        $crate::query_file_!($key = $env, $query, $($args)*)
     })
 );
 
@@ -205,6 +292,12 @@ macro_rules! query_as (
     });
     ($out_struct:path, $query:literal, $($args:tt)*) => (#[allow(dead_code)] {
         $crate::query_as_!($out_struct, $query, $($args)*)
+    });
+    ($key:ident = $env:literal, $out_struct:path, $query:literal) => (#[allow(dead_code)] {
+        $crate::query_as_!($key = $env, $out_struct, $query)
+    });
+    ($key:ident = $env:literal, $out_struct:path, $query:literal, $($args:tt)*) => (#[allow(dead_code)] {
+        $crate::query_as_!($key = $env, $out_struct, $query, $($args)*)
     })
 );
 
@@ -248,5 +341,63 @@ macro_rules! query_file_as (
     });
     ($out_struct:path, $query:literal, $($args:tt)*) => (#[allow(dead_code)] {
         $crate::query_file_as_!($out_struct, $query, $($args)*)
+    });
+    ($key:ident = $env:literal, $out_struct:path, $query:literal) => (#[allow(dead_code)] {
+        $crate::query_file_as_!($key = $env, $out_struct, $query)
+    });
+    ($key:ident = $env:literal, $out_struct:path, $query:literal, $($args:tt)*) => (#[allow(dead_code)] {
+        $crate::query_file_as_!($key = $env, $out_struct, $query, $($args)*)
+    })
+);
+
+/// A variant of [query!] which decodes the single column of the query directly into the
+/// given type.
+///
+/// Unlike [query_as!], the type of the column is not checked against the output type;
+/// its [Decode](crate::decode::Decode) implementation is trusted instead. This allows
+/// mapping a column to your own types, such as an enum:
+///
+/// ```rust,ignore
+/// enum Status {
+///     Active,
+///     Banned,
+/// }
+///
+/// impl HasSqlType<Status> for Postgres {
+///     fn metadata() -> PgTypeMetadata {
+///         <Postgres as HasSqlType<String>>::metadata()
+///     }
+/// }
+///
+/// impl Decode<Postgres> for Status {
+///     fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
+///         match <String as Decode<Postgres>>::decode(buf)?.as_str() {
+///             "active" => Ok(Status::Active),
+///             "banned" => Ok(Status::Banned),
+///             other => Err(DecodeError::Message(Box::new(format!("unknown status: {}", other)))),
+///         }
+///     }
+/// }
+///
+/// let status: Status = tokio_sqlx::query_scalar!(Status, "SELECT status FROM accounts WHERE id = $1", 1i32)
+///     .fetch_one(&mut conn)
+///     .await?;
+/// ```
+///
+/// The query must output exactly one column; this is checked at compile time.
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! query_scalar (
+    ($out_ty:path, $query:literal) => (#[allow(dead_code)] {
+        $crate::query_scalar_!($out_ty, $query)
+    });
+    ($out_ty:path, $query:literal, $($args:tt)*) => (#[allow(dead_code)] {
+        $crate::query_scalar_!($out_ty, $query, $($args)*)
+    });
+    ($key:ident = $env:literal, $out_ty:path, $query:literal) => (#[allow(dead_code)] {
+        $crate::query_scalar_!($key = $env, $out_ty, $query)
+    });
+    ($key:ident = $env:literal, $out_ty:path, $query:literal, $($args:tt)*) => (#[allow(dead_code)] {
+        $crate::query_scalar_!($key = $env, $out_ty, $query, $($args)*)
     })
 );
@@ -0,0 +1,61 @@
+use std::any;
+use std::fmt::{self, Debug};
+
+// These types allow the `sqlx_macros::query!()` macro to implement `Debug` for its anonymous
+// record even if some of the column types do not implement `Debug`; those columns are printed
+// as their type name instead
+
+#[doc(hidden)]
+pub struct DebugField<'a, T>(pub &'a T);
+
+#[doc(hidden)]
+pub trait DebugFieldExt {
+    fn debug_field(&self, name: &str, builder: &mut fmt::DebugStruct<'_, '_>);
+}
+
+// picked over the `&'_ DebugField<T>` impl by method resolution when `T: Debug`
+impl<T: Debug> DebugFieldExt for DebugField<'_, T> {
+    fn debug_field(&self, name: &str, builder: &mut fmt::DebugStruct<'_, '_>) {
+        builder.field(name, self.0);
+    }
+}
+
+#[doc(hidden)]
+pub trait DebugFieldFallbackExt {
+    fn debug_field(&self, name: &str, builder: &mut fmt::DebugStruct<'_, '_>);
+}
+
+impl<T> DebugFieldFallbackExt for &'_ DebugField<'_, T> {
+    fn debug_field(&self, name: &str, builder: &mut fmt::DebugStruct<'_, '_>) {
+        builder.field(name, &format_args!("<{}>", any::type_name::<T>()));
+    }
+}
+
+#[test]
+fn test_debug_field() {
+    struct NotDebug;
+
+    struct Record {
+        id: i32,
+        custom: NotDebug,
+    }
+
+    impl Debug for Record {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let mut builder = f.debug_struct("Record");
+            (&DebugField(&self.id)).debug_field("id", &mut builder);
+            (&DebugField(&self.custom)).debug_field("custom", &mut builder);
+            builder.finish()
+        }
+    }
+
+    let record = Record {
+        id: 1,
+        custom: NotDebug,
+    };
+
+    assert_eq!(
+        format!("{:?}", record),
+        "Record { id: 1, custom: <tokio_sqlx::record_debug::test_debug_field::NotDebug> }"
+    );
+}
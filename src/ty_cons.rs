@@ -27,6 +27,83 @@ impl<T> TyCons<Option<&'_ T>> {
     }
 }
 
+// optional values are checked against the type of the value, e.g. `Option<Vec<i32>>` against
+// `Vec<i32>` for a nullable array
+impl<T> TyCons<&'_ Option<T>> {
+    pub fn ty_cons(self) -> T {
+        panic!("should not be run, only for type resolution")
+    }
+}
+
+// values stored in text columns are checked against the text parameter type
+impl<T> TyCons<&'_ crate::types::Text<T>> {
+    pub fn ty_cons(self) -> String {
+        panic!("should not be run, only for type resolution")
+    }
+}
+
+// JSON documents are checked against the JSON parameter type
+#[cfg(feature = "json")]
+impl<T> TyCons<&'_ crate::types::Json<T>> {
+    pub fn ty_cons(self) -> crate::types::JsonValue {
+        panic!("should not be run, only for type resolution")
+    }
+}
+
+// host addresses are checked against the network parameter type of `inet`
+#[cfg(feature = "ipnetwork")]
+impl TyCons<&'_ std::net::IpAddr> {
+    pub fn ty_cons(self) -> crate::types::IpNetwork {
+        panic!("should not be run, only for type resolution")
+    }
+}
+
+// small-string types are checked against the text parameter type
+#[cfg(feature = "smol_str")]
+impl TyCons<&'_ crate::types::SmolStr> {
+    pub fn ty_cons(self) -> String {
+        panic!("should not be run, only for type resolution")
+    }
+}
+
+#[cfg(feature = "compact_str")]
+impl TyCons<&'_ crate::types::CompactString> {
+    pub fn ty_cons(self) -> String {
+        panic!("should not be run, only for type resolution")
+    }
+}
+
+// non-zero integers are checked against their integer type
+macro_rules! impl_ty_cons_non_zero {
+    ($($ty:ident: $int:ty),*) => {
+        $(
+            impl TyCons<&'_ std::num::$ty> {
+                pub fn ty_cons(self) -> $int {
+                    panic!("should not be run, only for type resolution")
+                }
+            }
+        )*
+    };
+}
+
+impl_ty_cons_non_zero!(
+    NonZeroI8: i8,
+    NonZeroI16: i16,
+    NonZeroI32: i32,
+    NonZeroI64: i64,
+    NonZeroU8: u8,
+    NonZeroU16: u16,
+    NonZeroU32: u32,
+    NonZeroU64: u64
+);
+
+// borrowed or owned values are checked against the owned type, e.g. `Cow<str>` against `String`
+impl<T: ?Sized + ToOwned> TyCons<&'_ std::borrow::Cow<'_, T>> {
+    pub fn ty_cons(self) -> T::Owned {
+        panic!("should not be run, only for type resolution")
+    }
+}
+
 // no overlap with the following impls because of the `: Sized` bound
 impl<T: Sized> TyConsExt for TyCons<&'_ T> {
     type Cons = T;
@@ -54,6 +131,10 @@ fn test_tycons_ext() {
         let _: u64 = TyCons::new(&Some(5u64)).ty_cons();
         let _: u64 = TyCons::new(&Some(&5u64)).ty_cons();
         let _: u64 = TyCons::new(&&5u64).ty_cons();
+        let _: Vec<u64> = TyCons::new(&&Some(vec![5u64])).ty_cons();
         let _: u64 = TyCons::new(&5u64).ty_cons();
+        let _: String = TyCons::new(&&crate::types::Text(5u64)).ty_cons();
+        let _: String = TyCons::new(&&std::borrow::Cow::Borrowed("5")).ty_cons();
+        let _: i32 = TyCons::new(&&std::num::NonZeroI32::new(5).unwrap()).ty_cons();
     }
 }
@@ -5,7 +5,18 @@ set -e
 cargo test -p tokio-sqlx-core --all-features
 
 # Postgres
-env DATABASE_URL="postgres://" cargo test -p tokio-sqlx --no-default-features --features 'postgres macros uuid chrono'
+env DATABASE_URL="postgres://" DATABASE_URL_ALT="postgres://" cargo test -p tokio-sqlx --no-default-features --features 'postgres macros uuid chrono'
+
+# Unix-domain sockets (requires the socket directory of the server)
+env DATABASE_URL="postgres://" cargo test -p tokio-sqlx --no-default-features --features 'postgres' --test postgres it_connects_over_unix_socket -- --ignored
+
+# JSON (on its own, as serde_json makes integer comparisons with decoded values ambiguous)
+env DATABASE_URL="postgres://" cargo test -p tokio-sqlx --no-default-features --features 'postgres macros json' --test postgres-types-json
+
+# Any (on its own, as a second database leaves the database of `sqlx::query` to be inferred)
+env DATABASE_URL="postgres://" cargo test -p tokio-sqlx --no-default-features --features 'postgres any' --test any
 
 # MySQL (requires sqlx database)
 env DATABASE_URL="mysql:///sqlx" cargo test -p tokio-sqlx --no-default-features --features 'mysql chrono'
+env DATABASE_URL="mysql:///sqlx" cargo test -p tokio-sqlx --no-default-features --features 'mysql json' --test mysql-types-json
+env DATABASE_URL="mysql:///sqlx" cargo test -p tokio-sqlx --no-default-features --features 'mysql any' --test any
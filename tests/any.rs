@@ -0,0 +1,111 @@
+extern crate tokio_sqlx as sqlx;
+
+use sqlx::{any::AnyConnection, Connection as _, Row as _};
+
+#[tokio::test]
+async fn it_connects() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT 'Hello'").fetch_one(&mut conn).await?;
+
+    assert_eq!(row.get::<String, _>(0), "Hello");
+
+    conn.close().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_binds_and_decodes_values() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let sql = if is_postgres() {
+        "SELECT $1::INT4, $2::TEXT, $3::BYTEA, $4::TEXT"
+    } else {
+        "SELECT CAST(? AS SIGNED INTEGER), ?, ?, ?"
+    };
+
+    let row = sqlx::query(sql)
+        .bind(5_i32)
+        .bind("Hello")
+        .bind(vec![1_u8, 2, 3])
+        .bind(None::<String>)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<String, _>(1), "Hello");
+    assert_eq!(row.get::<Vec<u8>, _>(2), [1, 2, 3]);
+    assert_eq!(row.get::<Option<String>, _>(3), None);
+
+    if is_postgres() {
+        assert_eq!(row.get::<i32, _>(0), 5);
+    } else {
+        assert_eq!(row.get::<i64, _>(0), 5);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_returns_decode_errors() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT 'Hello' AS greeting")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(matches!(
+        row.try_get::<i32, _>(0),
+        Err(sqlx::Error::Decode(_))
+    ));
+
+    assert!(matches!(
+        row.try_get::<String, _>("farewell"),
+        Err(sqlx::Error::ColumnNotFound(_))
+    ));
+
+    assert_eq!(row.try_get::<String, _>("greeting")?, "Hello");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_builds_queries_with_the_placeholders_of_the_backend() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let mut builder = conn.query_builder("SELECT ");
+    builder
+        .push_bind("Hello")
+        .push(", ")
+        .push_bind(vec![1_u8, 2, 3]);
+
+    if is_postgres() {
+        assert_eq!(builder.sql(), "SELECT $1, $2");
+    } else {
+        assert_eq!(builder.sql(), "SELECT ?, ?");
+    }
+
+    let row = builder.build().fetch_one(&mut conn).await?;
+
+    assert_eq!(row.get::<String, _>(0), "Hello");
+    assert_eq!(row.get::<Vec<u8>, _>(1), [1, 2, 3]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_rejects_unsupported_schemes() -> anyhow::Result<()> {
+    let res = AnyConnection::open("sqlite://test.db").await;
+
+    assert!(matches!(res, Err(sqlx::Error::UnsupportedScheme(_))));
+
+    Ok(())
+}
+
+fn is_postgres() -> bool {
+    dotenv::var("DATABASE_URL").map_or(false, |url| url.starts_with("postgres"))
+}
+
+async fn connect() -> anyhow::Result<AnyConnection> {
+    Ok(AnyConnection::open(dotenv::var("DATABASE_URL")?).await?)
+}
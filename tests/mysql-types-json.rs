@@ -0,0 +1,63 @@
+extern crate tokio_sqlx as sqlx;
+
+use sqlx::types::{Json, JsonValue};
+use sqlx::{mysql::MySqlConnection, Connection, Executor, Row};
+
+async fn connect() -> anyhow::Result<MySqlConnection> {
+    Ok(MySqlConnection::open(dotenv::var("DATABASE_URL")?).await?)
+}
+
+#[tokio::test]
+async fn mysql_json() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let value = serde_json::json!({ "a": [1, null], "b": "🦀" });
+
+    let row =
+        sqlx::query(r#"SELECT JSON_EXTRACT(?, '$.a[0]') = 1, CAST(? AS JSON), CAST(? AS JSON)"#)
+            .bind(&value)
+            .bind(&value)
+            .bind(Json(vec![1, 2]))
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert!(row.get::<bool, _>(0));
+    assert_eq!(row.get::<JsonValue, _>(1), value);
+    assert_eq!(row.get::<Json<Vec<i32>>, _>(2), Json(vec![1, 2]));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mysql_json_column() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE json_values (id INT NOT NULL, value JSON NOT NULL)")
+        .await?;
+
+    sqlx::query("INSERT INTO json_values (id, value) VALUES (1, ?), (2, ?)")
+        .bind(Json(vec![1, 2]))
+        .bind(serde_json::json!({ "name": "Herp" }))
+        .execute(&mut conn)
+        .await?;
+
+    // a column of JSON is received as the JSON type, with its text length-encoded
+    let row = sqlx::query("SELECT id, value FROM json_values WHERE id = 1")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<i32, _>("id"), 1);
+    assert_eq!(row.get::<Json<Vec<i32>>, _>("value"), Json(vec![1, 2]));
+
+    let row = sqlx::query("SELECT value, id FROM json_values WHERE id = 2")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(
+        row.get::<JsonValue, _>("value"),
+        serde_json::json!({ "name": "Herp" })
+    );
+    assert_eq!(row.get::<i32, _>("id"), 2);
+
+    Ok(())
+}
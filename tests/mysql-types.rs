@@ -45,7 +45,38 @@ test!(mysql_long: i32: "2141512" == 2141512_i32);
 test!(mysql_longlong_unsigned: u64: "2141512" == 2141512_u64);
 test!(mysql_longlong: i64: "2141512" == 2141512_i64);
 
+test!(mysql_non_zero_long: std::num::NonZeroI32: "-2141512" == std::num::NonZeroI32::new(-2141512).unwrap());
+test!(mysql_non_zero_longlong_unsigned: std::num::NonZeroU64: "2141512" == std::num::NonZeroU64::new(2141512).unwrap());
+
 test!(mysql_string: String: "'helloworld'" == "helloworld");
+test!(mysql_string_emoji: String: "'🦀 crab'" == "🦀 crab");
+
+#[cfg(feature = "smol_str")]
+test!(mysql_smol_str: sqlx::types::SmolStr: "'helloworld'" == sqlx::types::SmolStr::new("helloworld"));
+
+#[cfg(feature = "compact_str")]
+test!(mysql_compact_str: sqlx::types::CompactString: "'helloworld'" == sqlx::types::CompactString::new("helloworld"));
+
+#[tokio::test]
+async fn mysql_string_charset() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let value = "🦀 crab";
+
+    // the server must read the 4-byte emoji as a single `utf8mb4` character
+    let row = sqlx::query("SELECT CHAR_LENGTH(?), OCTET_LENGTH(?), ?")
+        .bind(value)
+        .bind(value)
+        .bind(value)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<i64, _>(0), 6);
+    assert_eq!(row.get::<i64, _>(1), 9);
+    assert_eq!(row.get::<String, _>(2), value);
+
+    Ok(())
+}
 
 #[tokio::test]
 async fn mysql_bytes() -> anyhow::Result<()> {
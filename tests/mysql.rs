@@ -1,7 +1,7 @@
 extern crate tokio_sqlx as sqlx;
 
 use futures::TryStreamExt;
-use sqlx::{mysql::MySqlConnection, Connection as _, Executor as _, Row as _};
+use sqlx::{mysql::MySqlConnection, Connection as _, Executor as _, IsolationLevel, Row as _};
 
 #[tokio::test]
 async fn it_connects() -> anyhow::Result<()> {
@@ -50,6 +50,90 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)
     Ok(())
 }
 
+#[tokio::test]
+async fn it_sends_commands() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE sent (id INTEGER)")
+        .await?;
+
+    let tags = conn
+        .send("INSERT INTO sent (id) VALUES (1), (2), (3)")
+        .await?;
+
+    assert_eq!(tags.len(), 1);
+    assert_eq!(tags[0].command(), "INSERT");
+    assert_eq!(tags[0].rows_affected(), 3);
+
+    let tags = conn.send("select id FROM sent").await?;
+
+    assert_eq!(tags.len(), 1);
+    assert_eq!(tags[0].command(), "SELECT");
+    assert_eq!(tags[0].rows_affected(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_rejects_postgres_placeholders() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let res = sqlx::query("SELECT $1 + 1")
+        .bind(1_i32)
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::PlaceholderStyleMismatch(_))));
+
+    let row = sqlx::query("SELECT ? + 1")
+        .bind(1_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<i64, _>(0), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_decodes_year() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE events (year YEAR)")
+        .await?;
+
+    sqlx::query("INSERT INTO events (year) VALUES (?), (0)")
+        .bind(2019_u16)
+        .execute(&mut conn)
+        .await?;
+
+    let years: Vec<u16> = sqlx::query("SELECT year FROM events ORDER BY year DESC")
+        .fetch(&mut conn)
+        .map_ok(|row| row.get::<u16, _>("year"))
+        .try_collect()
+        .await?;
+
+    assert_eq!(years, [2019, 0]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_describes_display_size() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE flags (active BOOLEAN, level TINYINT)")
+        .await?;
+
+    let describe = conn.describe("SELECT active, level FROM flags").await?;
+
+    // `BOOLEAN` is an alias for `TINYINT(1)`
+    assert_eq!(describe.result_columns[0].display_size, Some(1));
+    assert_eq!(describe.result_columns[1].display_size, Some(4));
+
+    Ok(())
+}
+
 #[cfg(feature = "macros")]
 #[tokio::test]
 async fn macro_select_from_cte() -> anyhow::Result<()> {
@@ -67,6 +151,47 @@ async fn macro_select_from_cte() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn it_runs_transactions() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE transacted (id INTEGER NOT NULL) ENGINE = InnoDB")
+        .await?;
+
+    for (id, commit) in &[(1_i32, true), (2, false)] {
+        let mut tx = conn.begin_with(IsolationLevel::Serializable, false).await?;
+
+        sqlx::query("INSERT INTO transacted (id) VALUES (?)")
+            .bind(*id)
+            .execute(&mut tx)
+            .await?;
+
+        if *commit {
+            tx.commit().await?;
+        } else {
+            tx.rollback().await?;
+        }
+    }
+
+    // a transaction that is dropped is rolled back
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("INSERT INTO transacted (id) VALUES (3)")
+        .execute(&mut tx)
+        .await?;
+
+    drop(tx);
+
+    let rows = sqlx::query("SELECT id FROM transacted")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<i32, _>(0), 1);
+
+    Ok(())
+}
+
 async fn connect() -> anyhow::Result<MySqlConnection> {
     Ok(MySqlConnection::open(dotenv::var("DATABASE_URL")?).await?)
 }
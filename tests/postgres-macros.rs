@@ -26,6 +26,11 @@ async fn test_query_file() -> sqlx::Result<()> {
 
     println!("{:?}", account);
 
+    // the contents of the file are embedded
+    let sql = sqlx::query_file!("tests/test-query.sql").sql();
+
+    assert_eq!(sql, include_str!("test-query.sql"));
+
     Ok(())
 }
 
@@ -53,6 +58,29 @@ async fn test_query_as() -> sqlx::Result<()> {
     Ok(())
 }
 
+#[derive(Debug)]
+struct Named<T> {
+    id: i32,
+    name: T,
+}
+
+#[tokio::test]
+async fn test_query_as_generic() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let named = sqlx::query_as!(
+        Named<String>,
+        "SELECT * from (VALUES (1, 'Herp Derpinson')) accounts(id, name)"
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(named.id, 1);
+    assert_eq!(named.name, "Herp Derpinson");
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_query_file_as() -> sqlx::Result<()> {
     let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
@@ -110,3 +138,595 @@ async fn test_nullable_err() -> sqlx::Result<()> {
         panic!("expected `UnexpectedNull`, got {}", err)
     }
 }
+
+#[tokio::test]
+async fn test_query_view() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    // `information_schema.columns` is a view whose columns have domain types
+    let column = sqlx::query!(
+        "SELECT table_name, ordinal_position FROM information_schema.columns \
+         WHERE table_schema = 'pg_catalog' AND table_name = 'pg_type' AND column_name = 'oid'"
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(column.table_name, "pg_type");
+    assert_eq!(column.ordinal_position, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_bpchar() -> sqlx::Result<()> {
+    use sqlx::postgres::types::PgBpChar;
+
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let code = "ab".to_owned();
+
+    let result = sqlx::query!(
+        r#"SELECT $1::char(4) as code, $1::char(4) as "trimmed: PgBpChar""#,
+        code
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(result.code, "ab  ");
+    assert_eq!(result.trimmed, PgBpChar::from("ab"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_non_zero() -> sqlx::Result<()> {
+    use std::num::NonZeroI64;
+
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let id = NonZeroI64::new(5).unwrap();
+
+    let result = sqlx::query!(r#"SELECT $1::bigint as "id: NonZeroI64""#, id)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(result.id, id);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_explicit_param_cast() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let result = sqlx::query!("SELECT $1::bigint as id", 5i64)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(result.id, 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_xml() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let result = sqlx::query!("SELECT '<greeting>Hello</greeting>'::xml as doc")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(result.doc, "<greeting>Hello</greeting>");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_jsonpath() -> sqlx::Result<()> {
+    use sqlx::postgres::types::PgJsonPath;
+
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let filter = PgJsonPath::from("$.a[*] ? (@ > 1)");
+    let predicate = PgJsonPath::from("$.a[0] == 1");
+
+    let result = sqlx::query!(
+        r#"SELECT '{"a": [1, 2]}'::jsonb @? $1 as exists, '{"a": [1, 2]}'::jsonb @@ $2 as matches, $1 as path"#,
+        filter,
+        predicate
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(result.exists);
+    assert!(result.matches);
+    assert_eq!(result.path.to_string(), "$.\"a\"[*]?(@ > 1)");
+
+    Ok(())
+}
+
+#[cfg(feature = "decimal")]
+#[tokio::test]
+async fn test_query_decimal() -> sqlx::Result<()> {
+    use sqlx::types::Decimal;
+
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let price = Decimal::new(1999, 2);
+
+    let result = sqlx::query!(
+        "SELECT $1::numeric * 3 as total, ARRAY[$1::numeric] as prices",
+        price
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(result.total, Decimal::new(5997, 2));
+    assert_eq!(result.prices, [price]);
+
+    Ok(())
+}
+
+#[cfg(feature = "ipnetwork")]
+#[tokio::test]
+async fn test_query_ipnetwork() -> sqlx::Result<()> {
+    use sqlx::types::IpNetwork;
+    use std::net::IpAddr;
+
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let host: IpAddr = "192.168.0.1".parse().unwrap();
+
+    let result = sqlx::query!(
+        "SELECT $1::inet as host, set_masklen($1::inet, 24) as network, '10.0.0.0/8'::cidr as cidr",
+        host
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(result.host, IpNetwork::from(host));
+    assert_eq!(
+        result.network,
+        "192.168.0.1/24".parse::<IpNetwork>().unwrap()
+    );
+    assert_eq!(result.cidr, "10.0.0.0/8".parse::<IpNetwork>().unwrap());
+
+    Ok(())
+}
+
+#[cfg(all(feature = "smol_str", feature = "compact_str"))]
+#[tokio::test]
+async fn test_query_small_strings() -> sqlx::Result<()> {
+    use sqlx::types::{CompactString, SmolStr};
+
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let id = SmolStr::new("a1b2");
+    let name = CompactString::new("Herp Derpinson");
+
+    let result = sqlx::query!(
+        r#"SELECT $1::text as "id: SmolStr", $2::text as "name: CompactString""#,
+        id,
+        name
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(result.id, id);
+    assert_eq!(result.name, name);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_array() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let ids = vec![1, 2, 3];
+
+    let result = sqlx::query!("SELECT $1::int4[] as ids", ids[..])
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(result.ids, ids);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_option_array() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    for ids in vec![None, Some(vec![]), Some(vec![1, 2, 3])] {
+        let result = sqlx::query!(
+            "SELECT $1::int4[] IS NULL as is_null, coalesce($1, '{}') as ids",
+            ids
+        )
+        .fetch_one(&mut conn)
+        .await?;
+
+        assert_eq!(result.is_null, ids.is_none());
+        assert_eq!(result.ids, ids.unwrap_or_default());
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+enum Mood {
+    Happy,
+    Sad,
+}
+
+impl sqlx::types::HasSqlType<Mood> for sqlx::Postgres {
+    fn metadata() -> Self::TypeMetadata {
+        <Self as sqlx::types::HasSqlType<String>>::metadata()
+    }
+}
+
+impl sqlx::decode::Decode<sqlx::Postgres> for Mood {
+    fn decode(buf: &[u8]) -> Result<Self, sqlx::decode::DecodeError> {
+        match <String as sqlx::decode::Decode<sqlx::Postgres>>::decode(buf)?.as_str() {
+            "happy" => Ok(Mood::Happy),
+            "sad" => Ok(Mood::Sad),
+            other => Err(sqlx::decode::DecodeError::Message(Box::new(format!(
+                "unknown mood: {}",
+                other
+            )))),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_query_scalar() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let text = "sad".to_owned();
+
+    let mood = sqlx::query_scalar!(Mood, "SELECT $1::text as mood", text)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(mood, Mood::Sad);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_range() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let result = sqlx::query!("SELECT int4range(1, 10) as range")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(result.range, sqlx::postgres::types::PgRange::from(1..10));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_without_args() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    // no placeholders and one output column
+    let result = sqlx::query!("SELECT 1::int4 as id")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(result.id, 1);
+
+    // no placeholders and no output columns
+    sqlx::query!("SET application_name = 'test_query_without_args'")
+        .execute(&mut conn)
+        .await?;
+
+    let result = sqlx::query!("SELECT current_setting('application_name') as name")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(result.name, "test_query_without_args");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_text_adapter() -> sqlx::Result<()> {
+    use std::net::IpAddr;
+
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let ip = sqlx::types::Text("127.0.0.1".parse::<IpAddr>().unwrap());
+
+    let result = sqlx::query!("SELECT $1::text as ip", ip)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(result.ip, "127.0.0.1");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_override_column_type() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let result = sqlx::query!(r#"SELECT '42'::text as "answer: sqlx::types::Text<i32>""#)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(*result.answer, 42);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_cow_str_param() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let name = std::borrow::Cow::Borrowed("Herp Derpinson");
+
+    let result = sqlx::query!("SELECT upper($1) as name", name)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(result.name, "HERP DERPINSON");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_limit_offset() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    // Postgres infers the parameters of `LIMIT` and `OFFSET` as `int8`
+    let (limit, offset) = (2_i64, 1_i64);
+
+    let rows = sqlx::query!(
+        "SELECT id FROM generate_series(1, 10) id ORDER BY id LIMIT $1 OFFSET $2",
+        limit,
+        offset
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    let ids: Vec<i32> = rows.into_iter().map(|row| row.id).collect();
+
+    assert_eq!(ids, [2, 3]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_reused_placeholder() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    // `$1` appears twice but is a single parameter of a single type
+    let id = 3_i32;
+
+    let rows = sqlx::query!(
+        "SELECT id FROM generate_series(1, 10) id WHERE id = $1 OR id = $1 * 2 ORDER BY id",
+        id
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    let ids: Vec<i32> = rows.into_iter().map(|row| row.id).collect();
+
+    assert_eq!(ids, [3, 6]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_name() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let table = "pg_class".to_owned();
+
+    // `relname` is of type `name`
+    let result = sqlx::query!(
+        "SELECT relname FROM pg_catalog.pg_class WHERE relname = $1",
+        table
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(result.relname, "pg_class");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_try_map() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let ids = "1,2,3".to_owned();
+
+    let ids = sqlx::query!("SELECT $1::text as ids", ids)
+        .try_map(|rec| {
+            rec.ids
+                .split(',')
+                .map(|id| id.parse::<i32>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| sqlx::Error::Decode(e.into()))
+        })
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(ids, [1, 2, 3]);
+
+    let ids = "1,two".to_owned();
+
+    let res = sqlx::query!("SELECT $1::text as ids", ids)
+        .try_map(|rec| {
+            rec.ids
+                .split(',')
+                .map(|id| id.parse::<i32>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| sqlx::Error::Decode(e.into()))
+        })
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_nullable_bytes() -> sqlx::Result<()> {
+    #[derive(Debug)]
+    struct Blobs {
+        empty: Option<Vec<u8>>,
+        missing: Option<Vec<u8>>,
+        data: Vec<u8>,
+    }
+
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let data = vec![1_u8, 2, 3];
+
+    let blobs = sqlx::query_as!(
+        Blobs,
+        "SELECT ''::bytea as empty, NULL::bytea as missing, $1::bytea as data",
+        data
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(blobs.empty, Some(vec![]));
+    assert_eq!(blobs.missing, None);
+    assert_eq!(blobs.data, [1, 2, 3]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_conditional_params() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let name: Option<String> = None;
+
+    // the parameter types are deduced from the other arguments of the expressions
+    let result = sqlx::query!(
+        "SELECT COALESCE($1, name) as name, CASE WHEN $2 THEN $3 ELSE 0 END as n FROM (SELECT 'x'::text AS name) t",
+        name,
+        true,
+        3_i32
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(result.name, "x");
+    assert_eq!(result.n, 3);
+
+    // even when the parameter is first used where its type cannot be deduced
+    let result = sqlx::query!(
+        "SELECT CASE WHEN $1 IS NULL THEN 'none' ELSE $1 END as name, id FROM (VALUES (1), (2)) t (id) WHERE $2 IS NULL OR id = $2",
+        name,
+        Some(2_i32)
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].name, "none");
+    assert_eq!(result[0].id, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_left_join() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    // the columns of the right side of the join are optional
+    let result = sqlx::query!(
+        "SELECT a.id, b.name, b.id as b_id FROM (VALUES (1), (2)) a (id) \
+         LEFT JOIN (VALUES (1, 'one'), (3, 'three')) b (id, name) ON b.id = a.id \
+         WHERE $1 IS NULL OR a.id <= $1 ORDER BY a.id",
+        Some(2_i32)
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].id, 1);
+    assert_eq!(result[0].name.as_deref(), Some("one"));
+    assert_eq!(result[0].b_id, Some(1));
+    assert_eq!(result[1].id, 2);
+    assert_eq!(result[1].name, None);
+    assert_eq!(result[1].b_id, None);
+
+    Ok(())
+}
+
+// checked against the database in `DATABASE_URL_ALT`, which has to be set along with
+// `DATABASE_URL` to build these tests
+#[tokio::test]
+async fn test_query_with_database_url_var() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL_ALT").unwrap()).await?;
+
+    let result = sqlx::query!(env = "DATABASE_URL_ALT", "SELECT $1::int4 as id", 1_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(result.id, 1);
+
+    let account = sqlx::query_as!(
+        env = "DATABASE_URL_ALT",
+        Account,
+        "SELECT * from (VALUES (1, 'Herp Derpinson')) accounts(id, name)"
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(account.id, 1);
+
+    let id = sqlx::query_scalar!(env = "DATABASE_URL_ALT", i32, "SELECT 2")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(id, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_in_transaction() -> sqlx::Result<()> {
+    use sqlx::Connection as _;
+
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+    let mut tx = conn.begin().await?;
+
+    for id in 1..=2_i32 {
+        let result = sqlx::query!("SELECT $1::int as id", id)
+            .fetch_one(&mut tx)
+            .await?;
+
+        assert_eq!(result.id, id);
+    }
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_catalog_vectors() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let result = sqlx::query!(
+        "SELECT indrelid as table_oid, indkey as columns, indclass as classes FROM pg_index WHERE indrelid = $1 LIMIT 1",
+        1259_u32
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(result.table_oid, 1259);
+    assert!(!result.columns.is_empty());
+    assert_eq!(result.columns.len(), result.classes.len());
+
+    Ok(())
+}
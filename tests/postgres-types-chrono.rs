@@ -1,7 +1,7 @@
 extern crate tokio_sqlx as sqlx;
 
-use sqlx::types::chrono::{DateTime, NaiveDate, NaiveTime, Utc};
-use sqlx::{Connection, PgConnection, Row};
+use sqlx::types::chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use sqlx::{Connection, Executor as _, PgConnection, Row};
 
 async fn connect() -> anyhow::Result<PgConnection> {
     Ok(PgConnection::open(dotenv::var("DATABASE_URL")?).await?)
@@ -81,3 +81,204 @@ async fn postgres_chrono_timestamp_tz() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn postgres_chrono_timestamp_tz_ignores_session_time_zone() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("SET TIME ZONE 'America/New_York'").await?;
+
+    assert_eq!(conn.parameter_statuses()["TimeZone"], "America/New_York");
+
+    let row = sqlx::query("SELECT TIMESTAMPTZ '2019-01-02 05:10:20-05'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let value: DateTime<Utc> = row.get(0);
+
+    assert_eq!(value.to_rfc3339(), "2019-01-02T10:10:20+00:00");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_chrono_interval() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let value = Duration::hours(30) + Duration::microseconds(115100);
+
+    let row = sqlx::query("SELECT INTERVAL '30 hours 0.1151 seconds' = $1, $1")
+        .bind(value)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.get::<bool, _>(0));
+    assert_eq!(value, row.get(1));
+
+    // days are combined with the microseconds
+    let row = sqlx::query("SELECT INTERVAL '1 day 2 hours'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(Duration::hours(26), row.get(0));
+
+    // too many microseconds for an interval
+    let res = sqlx::query("SELECT $1::interval")
+        .bind(Duration::max_value())
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(
+        matches!(res, Err(sqlx::Error::Encode(_))),
+        "{:?}",
+        res.err()
+    );
+
+    // the query was not sent
+    let row = sqlx::query("SELECT 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(row.get::<i32, _>(0), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_chrono_timestamp_tz_range() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgRange;
+    use std::ops::Bound;
+
+    let mut conn = connect().await?;
+
+    let start = DateTime::<Utc>::from_utc(NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20), Utc);
+    let value = PgRange::from((Bound::Included(start), Bound::Unbounded));
+
+    let row = sqlx::query("SELECT tstzrange('2019-01-02 05:10:20+00', NULL, '[)') = $1, $1")
+        .bind(value.clone())
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.get::<bool, _>(0));
+    assert_eq!(value, row.get::<PgRange<DateTime<Utc>>, _>(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_chrono_time_tz() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgTimeTz;
+    use sqlx::types::chrono::FixedOffset;
+
+    let mut conn = connect().await?;
+
+    let value = PgTimeTz::new(
+        NaiveTime::from_hms_micro(5, 10, 20, 115100),
+        FixedOffset::east(2 * 3600),
+    );
+
+    let row = sqlx::query("SELECT TIMETZ '05:10:20.115100+02' = $1, $1")
+        .bind(value)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.get::<bool, _>(0));
+    assert_eq!(value, row.get(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_chrono_round_trips() -> anyhow::Result<()> {
+    use sqlx::types::chrono::NaiveDateTime;
+
+    let mut conn = connect().await?;
+
+    // dates before the Postgres epoch of 2000-01-01 are negative offsets
+    for (text, value) in &[
+        ("2000-01-01", NaiveDate::from_ymd(2000, 1, 1)),
+        ("1999-12-31", NaiveDate::from_ymd(1999, 12, 31)),
+        ("1970-01-01", NaiveDate::from_ymd(1970, 1, 1)),
+        ("1900-02-28", NaiveDate::from_ymd(1900, 2, 28)),
+        ("0001-01-01", NaiveDate::from_ymd(1, 1, 1)),
+        ("2400-02-29", NaiveDate::from_ymd(2400, 2, 29)),
+    ] {
+        let row = sqlx::query("SELECT $1::date = $2, $2, $1::date")
+            .bind(*text)
+            .bind(value)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(row.get::<bool, _>(0), "{}", text);
+        assert_eq!(*value, row.get::<NaiveDate, _>(1));
+        assert_eq!(*value, row.get::<NaiveDate, _>(2));
+    }
+
+    for (text, value) in &[
+        ("00:00:00", NaiveTime::from_hms(0, 0, 0)),
+        ("00:00:00.000001", NaiveTime::from_hms_micro(0, 0, 0, 1)),
+        (
+            "23:59:59.999999",
+            NaiveTime::from_hms_micro(23, 59, 59, 999_999),
+        ),
+        ("12:34:56.5", NaiveTime::from_hms_milli(12, 34, 56, 500)),
+    ] {
+        let row = sqlx::query("SELECT $1::time = $2, $2, $1::time")
+            .bind(*text)
+            .bind(value)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(row.get::<bool, _>(0), "{}", text);
+        assert_eq!(*value, row.get::<NaiveTime, _>(1));
+        assert_eq!(*value, row.get::<NaiveTime, _>(2));
+    }
+
+    let date_time = |y, m, d, micros| {
+        NaiveDate::from_ymd(y, m, d).and_hms(0, 0, 0) + Duration::microseconds(micros)
+    };
+
+    for (text, value) in &[
+        ("2000-01-01 00:00:00", date_time(2000, 1, 1, 0)),
+        (
+            "1999-12-31 23:59:59.999999",
+            date_time(1999, 12, 31, 86_399_999_999),
+        ),
+        (
+            "1969-07-20 20:17:40.123456",
+            date_time(1969, 7, 20, 73_060_123_456),
+        ),
+        ("1900-01-01 00:00:00.000001", date_time(1900, 1, 1, 1)),
+        ("0001-01-01 12:00:00", date_time(1, 1, 1, 43_200_000_000)),
+        (
+            "2262-04-11 23:47:16.854775",
+            date_time(2262, 4, 11, 85_636_854_775),
+        ),
+    ] {
+        let row = sqlx::query("SELECT $1::timestamp = $2, $2, $1::timestamp")
+            .bind(*text)
+            .bind(value)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(row.get::<bool, _>(0), "{}", text);
+        assert_eq!(*value, row.get::<NaiveDateTime, _>(1));
+        assert_eq!(*value, row.get::<NaiveDateTime, _>(2));
+    }
+
+    // truncated to the microsecond before it, not after it
+    let row = sqlx::query("SELECT $1::text")
+        .bind(date_time(1999, 12, 31, 86_399_999_999) + Duration::nanoseconds(500))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!("1999-12-31 23:59:59.999999", row.get::<String, _>(0));
+
+    // out of the range of chrono
+    let row = sqlx::query("SELECT 'infinity'::date, '24:00:00'::time")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.try_get::<NaiveDate, _>(0).is_err());
+    assert!(row.try_get::<NaiveTime, _>(1).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+extern crate tokio_sqlx as sqlx;
+
+use sqlx::types::Decimal;
+use sqlx::{postgres::PgConnection, Connection as _, Row};
+use std::str::FromStr;
+
+async fn connect() -> anyhow::Result<PgConnection> {
+    Ok(PgConnection::open(dotenv::var("DATABASE_URL")?).await?)
+}
+
+macro_rules! test {
+    ($name:ident: $ty:ty: $($text:literal == $value:expr),+) => {
+        #[tokio::test]
+        async fn $name () -> anyhow::Result<()> {
+            let mut conn = connect().await?;
+
+            $(
+                let row = sqlx::query(&format!("SELECT {} = $1, $1 as _1", $text))
+                    .bind($value)
+                    .fetch_one(&mut conn)
+                    .await?;
+
+                assert!(row.get::<bool, _>(0));
+                assert!($value == row.get::<$ty, _>("_1"));
+            )+
+
+            Ok(())
+        }
+    }
+}
+
+fn decimal(s: &str) -> Decimal {
+    Decimal::from_str(s).unwrap()
+}
+
+test!(postgres_decimal: Decimal:
+    "0::numeric" == decimal("0"),
+    "1::numeric" == decimal("1"),
+    "-12345.678::numeric" == decimal("-12345.678"),
+    "0.00001::numeric" == decimal("0.00001"),
+    "100000.00::numeric" == decimal("100000.00"),
+    "79228162514264337593543950335::numeric" == Decimal::MAX,
+    "0.0000000000000000000000000001::numeric" == decimal("0.0000000000000000000000000001"));
+
+#[tokio::test]
+async fn postgres_decimal_scale() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    // the display scale of the value is kept
+    let row = sqlx::query("SELECT 1.50::numeric(10, 3)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<Decimal, _>(0).to_string(), "1.500");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_decimal_overflow() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let row = sqlx::query(
+        "SELECT 79228162514264337593543950336::numeric, 1e-29::numeric, 'NaN'::numeric",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(row.try_get::<Decimal, _>(0).is_err());
+    assert!(row.try_get::<Decimal, _>(1).is_err());
+    assert!(row.try_get::<Decimal, _>(2).is_err());
+
+    Ok(())
+}
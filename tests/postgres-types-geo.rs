@@ -0,0 +1,26 @@
+extern crate tokio_sqlx as sqlx;
+
+use sqlx::types::geo::Point;
+use sqlx::{postgres::PgConnection, Connection as _, Row};
+
+async fn connect() -> anyhow::Result<PgConnection> {
+    Ok(PgConnection::open(dotenv::var("DATABASE_URL")?).await?)
+}
+
+#[tokio::test]
+async fn postgres_geo_point() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let point = Point::new(1.5, -2.0);
+
+    let row = sqlx::query("SELECT $1 ~= point(1.5, -2), $1 AS _1, point(3, 4)")
+        .bind(point)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.get::<bool, _>(0));
+    assert_eq!(row.get::<Point<f64>, _>("_1"), point);
+    assert_eq!(row.get::<Point<f64>, _>(2), Point::new(3.0, 4.0));
+
+    Ok(())
+}
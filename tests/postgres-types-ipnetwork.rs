@@ -0,0 +1,87 @@
+extern crate tokio_sqlx as sqlx;
+
+use std::net::IpAddr;
+
+use sqlx::types::IpNetwork;
+use sqlx::{postgres::PgConnection, Connection as _, Row};
+
+async fn connect() -> anyhow::Result<PgConnection> {
+    Ok(PgConnection::open(dotenv::var("DATABASE_URL")?).await?)
+}
+
+macro_rules! test {
+    ($name:ident: $ty:ty: $($text:literal == $value:expr),+) => {
+        #[tokio::test]
+        async fn $name () -> anyhow::Result<()> {
+            let mut conn = connect().await?;
+
+            $(
+                let row = sqlx::query(&format!("SELECT {} = $1, $1 as _1", $text))
+                    .bind($value)
+                    .fetch_one(&mut conn)
+                    .await?;
+
+                assert!(row.get::<bool, _>(0));
+                assert!($value == row.get::<$ty, _>("_1"));
+            )+
+
+            Ok(())
+        }
+    }
+}
+
+fn net(s: &str) -> IpNetwork {
+    s.parse().unwrap()
+}
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+test!(postgres_inet_network: IpNetwork:
+    "'192.168.0.1'::inet" == net("192.168.0.1/32"),
+    "'192.168.0.1/24'::inet" == net("192.168.0.1/24"),
+    "'2001:db8::1/64'::inet" == net("2001:db8::1/64"));
+
+test!(postgres_inet_host: IpAddr:
+    "'192.168.0.1'::inet" == ip("192.168.0.1"),
+    "'::1'::inet" == ip("::1"));
+
+#[tokio::test]
+async fn postgres_cidr() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT '10.0.0.0/8'::cidr, '2001:db8::/32'::cidr")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<IpNetwork, _>(0), net("10.0.0.0/8"));
+    assert_eq!(row.get::<IpNetwork, _>(1), net("2001:db8::/32"));
+
+    // the netmask of a network is never dropped
+    assert!(row.try_get::<IpAddr, _>(0).is_err());
+
+    let row = sqlx::query("SELECT '192.168.0.1/24'::inet")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.try_get::<IpAddr, _>(0).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_inet_array() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let nets = vec![net("192.168.0.0/24"), net("::1/128")];
+
+    let row = sqlx::query("SELECT $1::inet[]")
+        .bind(&nets)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<Vec<IpNetwork>, _>(0), nets);
+
+    Ok(())
+}
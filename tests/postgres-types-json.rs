@@ -0,0 +1,154 @@
+extern crate tokio_sqlx as sqlx;
+
+use sqlx::{postgres::PgConnection, Connection as _, Row};
+
+async fn connect() -> anyhow::Result<PgConnection> {
+    Ok(PgConnection::open(dotenv::var("DATABASE_URL")?).await?)
+}
+
+#[tokio::test]
+async fn postgres_json() -> anyhow::Result<()> {
+    use sqlx::types::JsonValue;
+
+    let mut conn = connect().await?;
+
+    let value = serde_json::json!({ "name": "Herp", "tags": ["a", "b"] });
+
+    let row = sqlx::query(
+        r#"SELECT '{"tags": ["a", "b"], "name": "Herp"}'::jsonb = $1, $1, '{"a": 1}'::json"#,
+    )
+    .bind(&value)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(row.get::<bool, _>(0));
+    assert_eq!(value, row.get::<JsonValue, _>(1));
+    assert_eq!(serde_json::json!({ "a": 1 }), row.get::<JsonValue, _>(2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_json_adapter() -> anyhow::Result<()> {
+    use sqlx::types::Json;
+    use std::collections::BTreeMap;
+
+    let mut conn = connect().await?;
+
+    let tags: BTreeMap<String, Vec<i32>> = vec![("a".to_owned(), vec![1, 2])].into_iter().collect();
+
+    let row = sqlx::query(r#"SELECT '{"a": [1, 2]}'::jsonb = $1, $1, '{"a": [1, 2]}'::json"#)
+        .bind(Json(&tags))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.get::<bool, _>(0));
+    assert_eq!(row.get::<Json<BTreeMap<String, Vec<i32>>>, _>(1).0, tags);
+    assert_eq!(row.get::<Json<BTreeMap<String, Vec<i32>>>, _>(2).0, tags);
+    assert!(row.try_get::<Json<Vec<i32>>, _>(2).is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "macros")]
+#[tokio::test]
+async fn test_query_override_column_type_json() -> sqlx::Result<()> {
+    use sqlx::decode::{Decode, DecodeError};
+    use sqlx::types::{HasSqlType, JsonValue};
+    use sqlx::Postgres;
+
+    #[derive(Debug, PartialEq)]
+    struct Account {
+        name: String,
+    }
+
+    impl HasSqlType<Account> for Postgres {
+        fn metadata() -> Self::TypeMetadata {
+            <Postgres as HasSqlType<JsonValue>>::metadata()
+        }
+    }
+
+    impl Decode<Postgres> for Account {
+        fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
+            let value = <JsonValue as Decode<Postgres>>::decode(buf)?;
+
+            match value["name"].as_str() {
+                Some(name) => Ok(Account { name: name.into() }),
+                None => Err(DecodeError::Message(Box::new("account without a name"))),
+            }
+        }
+    }
+
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let result =
+        sqlx::query!(r#"SELECT '{"name": "Herp Derpinson"}'::jsonb as "account: Account""#)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(
+        result.account,
+        Account {
+            name: "Herp Derpinson".into()
+        }
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "macros")]
+#[tokio::test]
+async fn test_query_aggregates() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let result = sqlx::query!(
+        "SELECT array_agg(x) as ids, json_agg(x) as json FROM generate_series(1, 3) x"
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(result.ids, [1, 2, 3]);
+    assert_eq!(result.json, serde_json::json!([1, 2, 3]));
+
+    Ok(())
+}
+
+#[cfg(feature = "macros")]
+#[tokio::test]
+async fn test_query_jsonb_operators() -> sqlx::Result<()> {
+    let mut conn = sqlx::postgres::connect(&dotenv::var("DATABASE_URL").unwrap()).await?;
+
+    let data = serde_json::json!({ "name": "Herp", "tags": ["a", "b"], "owner": { "id": 1 } });
+
+    // the fields of a document may be missing, so they are all optional
+    let result = sqlx::query!(
+        r#"SELECT data->>'name' as name, data->'tags' as tags, data#>>'{owner,id}' as owner,
+                  data->>'missing' as missing
+           FROM (SELECT $1::jsonb) t(data)"#,
+        data
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(result.name, Some("Herp".to_owned()));
+    assert_eq!(result.tags, Some(serde_json::json!(["a", "b"])));
+    assert_eq!(result.owner, Some("1".to_owned()));
+    assert_eq!(result.missing, None);
+
+    // a missing key is NULL
+    #[derive(Debug)]
+    struct Named {
+        name: Option<String>,
+    }
+
+    let named = sqlx::query_as!(
+        Named,
+        r#"SELECT data->>'name' as name FROM (VALUES ('{}'::jsonb)) t(data)"#
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(named.name, None);
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+extern crate tokio_sqlx as sqlx;
+
+use sqlx::types::Uuid;
+use sqlx::{postgres::PgConnection, Connection as _, Executor as _, Row};
+
+async fn connect() -> anyhow::Result<PgConnection> {
+    Ok(PgConnection::open(dotenv::var("DATABASE_URL")?).await?)
+}
+
+macro_rules! test {
+    ($name:ident: $ty:ty: $($text:literal == $value:expr),+) => {
+        #[tokio::test]
+        async fn $name () -> anyhow::Result<()> {
+            let mut conn = connect().await?;
+
+            $(
+                let row = sqlx::query(&format!("SELECT {} = $1, $1 as _1", $text))
+                    .bind($value)
+                    .fetch_one(&mut conn)
+                    .await?;
+
+                assert!(row.get::<bool, _>(0));
+                assert!($value == row.get::<$ty, _>("_1"));
+            )+
+
+            Ok(())
+        }
+    }
+}
+
+fn uuid(s: &str) -> Uuid {
+    Uuid::parse_str(s).unwrap()
+}
+
+test!(postgres_uuid: Uuid:
+    "'a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11'::uuid" == uuid("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11"),
+    "'00000000-0000-0000-0000-000000000000'::uuid" == Uuid::nil());
+
+#[tokio::test]
+async fn postgres_uuid_simple_protocol() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+    use sqlx::types::Text;
+
+    let mut conn = connect().await?;
+
+    let id = uuid("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11");
+
+    // a value sent in a simple query is written as its hyphenated text form
+    conn.send(&format!(
+        "CREATE TEMPORARY TABLE uuids (id UUID NOT NULL); INSERT INTO uuids (id) VALUES ('{}')",
+        id.to_hyphenated()
+    ))
+    .await?;
+
+    // bound in the binary format, or as text
+    sqlx::query("INSERT INTO uuids (id) VALUES ($1), ($2::uuid)")
+        .bind(id)
+        .bind(Text(id))
+        .execute(&mut conn)
+        .await?;
+
+    // the rows of a simple query are received in the text format
+    let rows: Vec<_> = conn
+        .fetch_simple("SELECT id FROM uuids")
+        .try_collect()
+        .await?;
+
+    assert_eq!(rows.len(), 3);
+
+    for row in rows {
+        assert_eq!(row.get::<Uuid, _>(0), id);
+    }
+
+    let rows = sqlx::query("SELECT id FROM uuids")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(rows.len(), 3);
+
+    for row in rows {
+        assert_eq!(row.get::<Uuid, _>(0), id);
+    }
+
+    Ok(())
+}
@@ -28,16 +28,168 @@ macro_rules! test {
 }
 
 test!(postgres_bool: bool: "false::boolean" == false, "true::boolean" == true);
+test!(postgres_option_bool: Option<bool>: "false::boolean" == Some(false), "true::boolean" == Some(true));
 
 test!(postgres_smallint: i16: "821::smallint" == 821_i16);
 test!(postgres_int: i32: "94101::int" == 94101_i32);
 test!(postgres_bigint: i64: "9358295312::bigint" == 9358295312_i64);
+test!(postgres_oid: u32: "4294967295::oid" == u32::MAX, "1259::oid" == 1259_u32);
+
+test!(postgres_non_zero_smallint: std::num::NonZeroI16: "-821::smallint" == std::num::NonZeroI16::new(-821).unwrap());
+test!(postgres_non_zero_int: std::num::NonZeroI32: "94101::int" == std::num::NonZeroI32::new(94101).unwrap());
+test!(postgres_non_zero_bigint: std::num::NonZeroI64: "9358295312::bigint" == std::num::NonZeroI64::new(9358295312).unwrap());
+
+test!(postgres_macaddr: [u8; 6]: "'08:00:2b:01:02:03'::macaddr" == [0x08_u8, 0x00, 0x2b, 0x01, 0x02, 0x03]);
+test!(postgres_macaddr8: [u8; 8]: "'08:00:2b:01:02:03:04:05'::macaddr8" == [0x08_u8, 0x00, 0x2b, 0x01, 0x02, 0x03, 0x04, 0x05]);
 
 test!(postgres_real: f32: "9419.122::real" == 9419.122_f32);
 test!(postgres_double: f64: "939399419.1225182::double precision" == 939399419.1225182_f64);
 
+test!(postgres_real_infinity: f32: "'Infinity'::real" == f32::INFINITY, "'-Infinity'::real" == f32::NEG_INFINITY);
+test!(postgres_double_infinity: f64: "'Infinity'::double precision" == f64::INFINITY, "'-Infinity'::double precision" == f64::NEG_INFINITY);
+
+test!(postgres_interval: sqlx::postgres::types::PgInterval:
+    "'-1 mon 5 days -3 hours'::interval" == sqlx::postgres::types::PgInterval::new(-1, 5, -3 * 3_600_000_000),
+    "'1 year 2 mons -3 days 0.5 seconds'::interval" == sqlx::postgres::types::PgInterval::new(14, -3, 500_000));
+
+#[tokio::test]
+async fn postgres_catalog_vectors() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT '1 -2 3'::int2vector, '23 25'::oidvector, ''::int2vector")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<Vec<i16>, _>(0), [1, -2, 3]);
+    assert_eq!(row.get::<Vec<u32>, _>(1), [23, 25]);
+    assert!(row.get::<Vec<i16>, _>(2).is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_non_zero_zero() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT 0::int").fetch_one(&mut conn).await?;
+
+    assert!(row.try_get::<std::num::NonZeroI32, _>(0).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_double_nan() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT 'NaN'::double precision = $1, $1")
+        .bind(f64::NAN)
+        .fetch_one(&mut conn)
+        .await?;
+
+    // NaN is equal to itself in Postgres
+    assert!(row.get::<bool, _>(0));
+    assert!(row.get::<f64, _>(1).is_nan());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_float_text() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+
+    let mut conn = connect().await?;
+
+    // the simple query protocol sends every value as text
+    let rows: Vec<_> = conn
+        .fetch_simple(
+            "SELECT 1.5::real, 0.1::double precision; \
+             SELECT 'Infinity'::real, '-Infinity'::double precision, 'NaN'::double precision",
+        )
+        .try_collect()
+        .await?;
+
+    assert_eq!(rows.len(), 2);
+
+    assert_eq!(rows[0].get::<f32, _>(0), 1.5);
+    assert_eq!(rows[0].get::<f64, _>(1), 0.1);
+
+    assert_eq!(rows[1].get::<f32, _>(0), f32::INFINITY);
+    assert_eq!(rows[1].get::<f64, _>(1), f64::NEG_INFINITY);
+    assert!(rows[1].get::<f64, _>(2).is_nan());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_bool_text() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+
+    let mut conn = connect().await?;
+
+    // the simple query protocol sends booleans as `t` or `f`
+    let rows: Vec<_> = conn
+        .fetch_simple("SELECT true, false, NULL::boolean")
+        .try_collect()
+        .await?;
+
+    assert_eq!(rows.len(), 1);
+
+    assert!(rows[0].get::<bool, _>(0));
+    assert!(!rows[0].get::<bool, _>(1));
+    assert_eq!(rows[0].get::<Option<bool>, _>(2), None);
+    assert_eq!(rows[0].get::<Option<bool>, _>(1), Some(false));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_cow_str() -> anyhow::Result<()> {
+    use std::borrow::Cow;
+
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT $1 || $2")
+        .bind(Cow::Borrowed("Herp "))
+        .bind(Cow::<str>::Owned("Derpinson".to_owned()))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<String, _>(0), "Herp Derpinson");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_point() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgPoint;
+
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT point(1.5, -2) ~= $1, $1")
+        .bind(PgPoint::new(1.5, -2.0))
+        .fetch_one(&mut conn)
+        .await?;
+
+    // points have no `=` operator, `~=` is "same as"
+    assert!(row.get::<bool, _>(0));
+    assert_eq!(row.get::<PgPoint, _>(1), PgPoint::new(1.5, -2.0));
+
+    Ok(())
+}
+
 test!(postgres_text: String: "'this is foo'" == "this is foo", "''" == "");
 
+#[cfg(feature = "smol_str")]
+test!(postgres_smol_str: sqlx::types::SmolStr:
+    "'this is foo'" == sqlx::types::SmolStr::new("this is foo"),
+    "'a string longer than twenty-three bytes'" == sqlx::types::SmolStr::new("a string longer than twenty-three bytes"));
+
+#[cfg(feature = "compact_str")]
+test!(postgres_compact_str: sqlx::types::CompactString:
+    "'this is foo'" == sqlx::types::CompactString::new("this is foo"),
+    "'a string longer than twenty-four bytes'" == sqlx::types::CompactString::new("a string longer than twenty-four bytes"));
+
 #[tokio::test]
 async fn postgres_bytes() -> anyhow::Result<()> {
     let mut conn = connect().await?;
@@ -57,3 +209,282 @@ async fn postgres_bytes() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn postgres_nullable_bytes() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT NULL::bytea, ''::bytea, $1")
+        .bind(Some(vec![1_u8, 2]))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<Option<Vec<u8>>, _>(0), None);
+
+    // an empty value is not NULL
+    assert_eq!(row.get::<Option<Vec<u8>>, _>(1), Some(vec![]));
+    assert_eq!(row.get::<Option<Vec<u8>>, _>(2), Some(vec![1, 2]));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_xml() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let value = "<greeting>Hello, World</greeting>";
+
+    let row = sqlx::query("SELECT $1::xml::text = $1, $1::xml")
+        .bind(value)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.get::<bool, _>(0));
+
+    let output: String = row.get(1);
+
+    assert_eq!(value, output);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_int_array() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let value: &[i32] = &[1, 2, 3];
+
+    let row = sqlx::query("SELECT '{1,2,3}'::int4[] = $1, $1")
+        .bind(value)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.get::<bool, _>(0));
+
+    let output: Vec<i32> = row.get(1);
+
+    assert_eq!(value, &*output);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_text_array() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let value = vec![Some("foo".to_owned()), None, Some(String::new())];
+
+    let row = sqlx::query("SELECT ARRAY['foo', NULL, '']::text[] IS NOT DISTINCT FROM $1, $1")
+        .bind(value.clone())
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.get::<bool, _>(0));
+
+    let output: Vec<Option<String>> = row.get(1);
+
+    assert_eq!(value, output);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_int_array_2d() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT '{{1,2,3},{4,NULL,6}}'::int4[][], '{1,2}'::int4[]")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let output: Vec<Vec<Option<i32>>> = row.get(0);
+
+    assert_eq!(
+        output,
+        [[Some(1), Some(2), Some(3)], [Some(4), None, Some(6)]]
+    );
+
+    assert!(row.try_get::<Vec<Vec<i32>>, _>(1).is_err());
+    assert!(row.try_get::<Vec<i32>, _>(0).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_empty_array() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT cardinality($1::int8[]), $1")
+        .bind(&[0_i64; 0][..])
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<i32, _>(0), 0);
+    assert!(row.get::<Vec<i64>, _>(1).is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_option_int_array() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    for value in &[None, Some(vec![]), Some(vec![1_i32, 2])] {
+        let row = sqlx::query("SELECT $1::int4[] IS NULL, cardinality($1::int4[]), $1")
+            .bind(value.clone())
+            .fetch_one(&mut conn)
+            .await?;
+
+        // a NULL array is not the same as an empty array
+        assert_eq!(row.get::<bool, _>(0), value.is_none());
+        assert_eq!(
+            row.get::<Option<i32>, _>(1),
+            value.as_ref().map(|value| value.len() as i32)
+        );
+        assert_eq!(row.get::<Option<Vec<i32>>, _>(2), *value);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_jsonpath() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgJsonPath;
+
+    let mut conn = connect().await?;
+
+    // `jsonpath` has no equality operator; the server returns the path in a normalized form
+    let row = sqlx::query(r#"SELECT $1::text = '$."a"[*]?(@ > 1)', $1, '{"a": [1, 2]}' @? $1"#)
+        .bind(PgJsonPath::from("$.a[*] ? (@ > 1)"))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.get::<bool, _>(0));
+    assert_eq!(
+        row.get::<PgJsonPath, _>(1),
+        PgJsonPath::from(r#"$."a"[*]?(@ > 1)"#)
+    );
+    assert!(row.get::<bool, _>(2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_int_range() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgRange;
+    use std::ops::Bound;
+
+    let mut conn = connect().await?;
+
+    // Postgres normalizes discrete ranges to `[start, end)`
+    for (text, value) in vec![
+        ("'[1,5)'::int4range", PgRange::from(1..5)),
+        (
+            "'(,5]'::int4range",
+            PgRange::from((Bound::Unbounded, Bound::Excluded(6))),
+        ),
+        (
+            "'[1,)'::int4range",
+            PgRange::from((Bound::Included(1), Bound::Unbounded)),
+        ),
+        ("'empty'::int4range", PgRange::Empty),
+    ] {
+        let row = sqlx::query(&format!("SELECT {} = $1, $1", text))
+            .bind(value.clone())
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(row.get::<bool, _>(0), "{}", text);
+        assert_eq!(value, row.get::<PgRange<i32>, _>(1));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_ltree() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgLTree;
+    use sqlx::Executor as _;
+
+    let mut conn = connect().await?;
+
+    conn.send("CREATE EXTENSION IF NOT EXISTS ltree").await?;
+
+    let row = sqlx::query("SELECT 'Top.Science.Astronomy'::ltree = $1::ltree, $1::ltree")
+        .bind(PgLTree::from("Top.Science.Astronomy"))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.get::<bool, _>(0));
+
+    let path: PgLTree = row.get(1);
+
+    assert_eq!(
+        path.labels().collect::<Vec<_>>(),
+        ["Top", "Science", "Astronomy"]
+    );
+
+    // `ltree` has no fixed OID; it is reported by name instead
+    let describe = conn.describe("SELECT 'Top'::ltree, 1").await?;
+
+    assert_eq!(
+        describe.result_columns[0].type_name.as_deref(),
+        Some("ltree")
+    );
+    assert_eq!(describe.result_columns[1].type_name, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_citext() -> anyhow::Result<()> {
+    use sqlx::Executor as _;
+
+    let mut conn = connect().await?;
+
+    conn.send("CREATE EXTENSION IF NOT EXISTS citext").await?;
+
+    let row =
+        sqlx::query("SELECT 'Foo@Example.com'::citext = $1::citext, 'Foo@Example.com'::citext")
+            .bind("foo@example.com")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert!(row.get::<bool, _>(0));
+    assert_eq!(row.get::<String, _>(1), "Foo@Example.com");
+
+    let describe = conn.describe("SELECT 'Foo'::citext").await?;
+
+    assert_eq!(
+        describe.result_columns[0].type_name.as_deref(),
+        Some("citext")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_text_adapter() -> anyhow::Result<()> {
+    use sqlx::types::Text;
+    use std::net::IpAddr;
+
+    let mut conn = connect().await?;
+
+    let value = Text("127.0.0.1".parse::<IpAddr>()?);
+
+    let row = sqlx::query("SELECT '127.0.0.1' = $1, $1")
+        .bind(value)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.get::<bool, _>(0));
+    assert_eq!(value, row.get(1));
+
+    // a value that fails to parse is a decode error
+    let row = sqlx::query("SELECT 'localhost'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.try_get::<Text<IpAddr>, _>(0).is_err());
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
 extern crate tokio_sqlx as sqlx;
 
 use futures::TryStreamExt;
-use sqlx::{postgres::PgConnection, Connection as _, Executor as _, Row as _};
+use sqlx::{
+    postgres::{PgConnection, PgNotification, PgPool, PgRow},
+    Connection as _, Executor as _, IsolationLevel, Postgres, QueryBuilder, Row as _,
+};
+use std::time::{Duration, Instant};
 
 #[tokio::test]
 async fn it_connects() -> anyhow::Result<()> {
@@ -91,6 +95,1913 @@ async fn it_remains_stable_issue_30() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn it_reads_large_rows_with_read_buffer_max() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.set_read_buffer_max(16 * 1024);
+
+    for _ in 0..2 {
+        let row = sqlx::query("SELECT repeat('x', 100000), 1 + 1")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(row.get::<String, _>(0).len(), 100000);
+        assert_eq!(row.get::<i32, _>(1), 2);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_spawns_with_owned_pool_connection() -> anyhow::Result<()> {
+    let pool = PgPool::new(&dotenv::var("DATABASE_URL")?).await?;
+
+    let mut conn = pool.acquire_owned().await?;
+
+    let value: i32 = tokio::spawn(async move {
+        let row = sqlx::query("SELECT 1 + 1").fetch_one(&mut *conn).await?;
+
+        Ok::<i32, sqlx::Error>(row.get(0))
+    })
+    .await??;
+
+    assert_eq!(value, 2);
+    assert_eq!(pool.size(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_reuses_pool_connections_by_policy() -> anyhow::Result<()> {
+    use sqlx::pool::ReusePolicy;
+
+    async fn backend_pid(conn: &mut PgConnection) -> anyhow::Result<i32> {
+        let row = sqlx::query("SELECT pg_backend_pid()")
+            .fetch_one(conn)
+            .await?;
+
+        Ok(row.get(0))
+    }
+
+    for &(policy, reused) in &[(ReusePolicy::Lifo, 1), (ReusePolicy::Fifo, 0)] {
+        let pool = PgPool::builder()
+            .max_size(2)
+            .reuse_policy(policy)
+            .build(&dotenv::var("DATABASE_URL")?)
+            .await?;
+
+        assert_eq!(pool.reuse_policy(), policy);
+
+        let mut first = pool.acquire_owned().await?;
+        let mut second = pool.acquire_owned().await?;
+
+        let pids = [
+            backend_pid(&mut first).await?,
+            backend_pid(&mut second).await?,
+        ];
+
+        // returned in this order
+        drop(first);
+        drop(second);
+
+        let mut conn = pool.acquire().await?;
+
+        assert_eq!(backend_pid(&mut conn).await?, pids[reused]);
+        assert_eq!(pool.size(), 2);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_hands_released_pool_connections_to_waiting_tasks() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire_owned().await?;
+    let row = sqlx::query("SELECT pg_backend_pid()")
+        .fetch_one(&mut *conn)
+        .await?;
+    let pid: i32 = row.get(0);
+
+    let waiting = tokio::spawn({
+        let pool = pool.clone();
+
+        async move {
+            let mut conn = pool.acquire().await?;
+            let row = sqlx::query("SELECT pg_backend_pid()")
+                .fetch_one(&mut *conn)
+                .await?;
+
+            Ok::<_, sqlx::Error>(row.get::<i32, _>(0))
+        }
+    });
+
+    tokio::time::delay_for(std::time::Duration::from_millis(100)).await;
+
+    // a connection that is neither too old nor idle for too long is not replaced
+    drop(conn);
+
+    assert_eq!(waiting.await??, pid);
+    assert_eq!(pool.size(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_reaps_idle_pool_connections() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(2)
+        .idle_timeout(Duration::from_millis(100))
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    assert_eq!(pool.idle_timeout(), Some(Duration::from_millis(100)));
+
+    let first = pool.acquire_owned().await?;
+    let second = pool.acquire_owned().await?;
+
+    drop(first);
+    drop(second);
+
+    assert_eq!(pool.size(), 2);
+
+    tokio::time::delay_for(Duration::from_millis(500)).await;
+
+    assert_eq!(pool.size(), 0);
+
+    // a new connection is opened on demand
+    let mut conn = pool.acquire().await?;
+    let row = sqlx::query("SELECT 1 + 1").fetch_one(&mut *conn).await?;
+
+    assert_eq!(row.get::<i32, _>(0), 2);
+    assert_eq!(pool.size(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_runs_after_connect_on_pool_connections() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(2)
+        .after_connect(|conn| {
+            Box::pin(async move {
+                conn.send("SET application_name = 'after_connect'").await?;
+
+                Ok(())
+            })
+        })
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    // both connections are open at the same time
+    let conns = vec![pool.acquire_owned().await?, pool.acquire_owned().await?];
+
+    for mut conn in conns {
+        let row = sqlx::query("SELECT current_setting('application_name')")
+            .fetch_one(&mut *conn)
+            .await?;
+
+        assert_eq!(row.get::<String, _>(0), "after_connect");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_times_out_a_slow_after_connect() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .connect_timeout(Duration::from_secs(1))
+        .after_connect(|conn| {
+            Box::pin(async move {
+                conn.send("SELECT pg_sleep(5)").await?;
+
+                Ok(())
+            })
+        })
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let start = Instant::now();
+    let res = pool.acquire().await;
+
+    assert!(matches!(res, Err(sqlx::Error::PoolTimedOut)));
+    assert!(start.elapsed() < Duration::from_secs(3));
+    assert_eq!(pool.size(), 0);
+
+    Ok(())
+}
+
+async fn add_one<'c, A>(conn: A, value: i32) -> sqlx::Result<i32>
+where
+    A: sqlx::Acquire<'c, Database = sqlx::Postgres>,
+{
+    let mut conn = conn.acquire().await?;
+
+    let row = sqlx::query("SELECT $1 + 1")
+        .bind(value)
+        .fetch_one(&mut *conn)
+        .await?;
+
+    Ok(row.get(0))
+}
+
+#[tokio::test]
+async fn it_acquires_from_anything() -> anyhow::Result<()> {
+    let pool = PgPool::new(&dotenv::var("DATABASE_URL")?).await?;
+    let mut conn = connect().await?;
+
+    assert_eq!(add_one(&pool, 1).await?, 2);
+    assert_eq!(add_one(&mut conn, 2).await?, 3);
+    assert_eq!(add_one(&mut pool.acquire_owned().await?, 3).await?, 4);
+
+    let mut tx = conn.begin().await?;
+
+    assert_eq!(add_one(&mut tx, 4).await?, 5);
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+async fn insert_acquired<'c, A>(conn: A, id: i32) -> sqlx::Result<()>
+where
+    A: sqlx::Acquire<'c, Database = sqlx::Postgres>,
+{
+    let mut conn = conn.acquire().await?;
+
+    sqlx::query("INSERT INTO acquired (id) VALUES ($1)")
+        .bind(id)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_acquires_from_transactions() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE acquired (id INT NOT NULL)")
+        .await?;
+
+    // the queries run inside of the transaction, so they are rolled back with it
+    for (id, commit) in &[(1_i32, true), (2, false)] {
+        let mut tx = conn.begin().await?;
+
+        insert_acquired(&mut tx, *id).await?;
+
+        let count: i64 = sqlx::query("SELECT count(*) FROM acquired WHERE id = $1")
+            .bind(*id)
+            .fetch_scalar(&mut tx)
+            .await?;
+
+        assert_eq!(count, 1);
+
+        if *commit {
+            tx.commit().await?;
+        } else {
+            tx.rollback().await?;
+        }
+    }
+
+    let ids: Vec<i32> = sqlx::query("SELECT id FROM acquired")
+        .fetch(&mut conn)
+        .map_ok(|row| row.get::<i32, _>(0))
+        .try_collect()
+        .await?;
+
+    assert_eq!(ids, [1]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_copies_out() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let data: Vec<_> = conn
+        .copy_out(
+            "COPY (SELECT i, i * 2 FROM generate_series(1, 3) as i) TO STDOUT WITH CSV HEADER",
+        )
+        .await?
+        .try_collect()
+        .await?;
+
+    assert_eq!(data.len(), 4);
+    assert_eq!(data.concat(), b"i,?column?\n1,2\n2,4\n3,6\n");
+
+    // the connection is usable afterwards
+    let row = sqlx::query("SELECT 1 + 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(2, row.get(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_copies_in() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE copied (id INT NOT NULL, name TEXT)")
+        .await?;
+
+    let mut copy = conn
+        .copy_in("COPY copied (id, name) FROM STDIN WITH (FORMAT binary)")
+        .await?;
+
+    copy.write_row((1_i32, "Herp Derpinson")).await?;
+    copy.write_row((2_i32, None::<String>)).await?;
+
+    assert_eq!(copy.finish().await?, 2);
+
+    let mut copy = conn.copy_in("COPY copied (id, name) FROM STDIN").await?;
+
+    copy.send("3\tthree\n").await?;
+
+    assert_eq!(copy.finish().await?, 1);
+
+    let copy = conn.copy_in("COPY copied (id, name) FROM STDIN").await?;
+
+    copy.abort("never mind").await?;
+
+    let rows: Vec<(i32, Option<String>)> = sqlx::query("SELECT id, name FROM copied ORDER BY id")
+        .fetch(&mut conn)
+        .map_ok(|row| (row.get(0), row.get(1)))
+        .try_collect()
+        .await?;
+
+    assert_eq!(
+        rows,
+        [
+            (1, Some("Herp Derpinson".to_owned())),
+            (2, None),
+            (3, Some("three".to_owned()))
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_fails_a_dropped_copy_in() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE dropped (id INT NOT NULL)")
+        .await?;
+
+    let mut copy = conn.copy_in("COPY dropped (id) FROM STDIN").await?;
+
+    copy.send("1\n").await?;
+
+    drop(copy);
+
+    // the next use of the connection does not wait for the rest of the COPY, but fails it and
+    // runs as usual
+    let row = tokio::time::timeout(
+        Duration::from_secs(5),
+        sqlx::query("SELECT count(*) FROM dropped").fetch_one(&mut conn),
+    )
+    .await??;
+
+    assert_eq!(row.get::<i64, _>(0), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_reports_copy_progress() -> anyhow::Result<()> {
+    use sqlx::postgres::PgCopyProgress;
+    use std::sync::{Arc, Mutex};
+
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE progressed (id INT NOT NULL)")
+        .await?;
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let reports_ = reports.clone();
+
+    let mut copy = conn
+        .copy_in("COPY progressed (id) FROM STDIN WITH (FORMAT binary)")
+        .await?
+        .on_progress(1000, move |progress| {
+            reports_.lock().unwrap().push(progress)
+        });
+
+    for id in 0..500_i32 {
+        copy.write_row((id,)).await?;
+    }
+
+    assert_eq!(copy.finish().await?, 500);
+
+    let reports = reports.lock().unwrap();
+
+    // header, then 10 bytes per row (field count, length and value), then the trailer
+    let total = 19 + 500 * 10 + 2;
+
+    assert_eq!(reports.len(), 6);
+    assert!(reports
+        .windows(2)
+        .all(|w| w[0].bytes < w[1].bytes && w[0].rows < w[1].rows));
+    assert_eq!(reports[0].rows, 99);
+    assert_eq!(
+        reports.last(),
+        Some(&PgCopyProgress {
+            bytes: total,
+            rows: 500
+        })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_reads_bytea_in_chunks() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE files (id INT NOT NULL, data BYTEA)")
+        .await?;
+
+    let data: Vec<u8> = (0..100_003_u32).map(|i| (i % 251) as u8).collect();
+
+    for (id, len) in &[(1_i32, data.len()), (2, 20_000), (3, 0)] {
+        sqlx::query("INSERT INTO files (id, data) VALUES ($1, $2)")
+            .bind(*id)
+            .bind(&data[..*len])
+            .execute(&mut conn)
+            .await?;
+    }
+
+    for (id, len, chunks) in &[(1_i32, data.len(), 11), (2, 20_000, 2), (3, 0, 0)] {
+        let read: Vec<_> = conn
+            .read_bytea("SELECT data FROM files WHERE id = $1", (*id,), 10_000)
+            .try_collect()
+            .await?;
+
+        assert_eq!(read.len(), *chunks);
+        assert!(read.iter().all(|chunk| chunk.len() <= 10_000));
+        assert_eq!(read.concat(), &data[..*len]);
+    }
+
+    let res = conn
+        .read_bytea("SELECT data FROM files WHERE id = $1", (4_i32,), 10_000)
+        .try_collect::<Vec<_>>()
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::NotFound)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_reads_large_objects_in_chunks() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let data: Vec<u8> = (0..25_000_u32).map(|i| (i % 251) as u8).collect();
+
+    let row = sqlx::query("SELECT lo_from_bytea(0, $1)::int8")
+        .bind(&data[..])
+        .fetch_one(&mut conn)
+        .await?;
+
+    let oid = row.get::<i64, _>(0) as u32;
+
+    let read: Vec<_> = conn.read_large_object(oid, 8192).try_collect().await?;
+
+    sqlx::query("SELECT lo_unlink($1)")
+        .bind(oid)
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(read.len(), 4);
+    assert_eq!(read.concat(), data);
+
+    Ok(())
+}
+
+// needs the socket directory of the server on this machine, which a server in a container does
+// not share; run with `--ignored`
+#[cfg(unix)]
+#[tokio::test]
+#[ignore]
+async fn it_connects_over_unix_socket() -> anyhow::Result<()> {
+    use std::convert::TryFrom;
+
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT split_part(current_setting('unix_socket_directories'), ',', 1)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let dir: String = row.get(0);
+
+    let dir = std::path::Path::new(dir.trim());
+
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let by_host =
+        PgConnection::open(format!("{}{}host={}", url, separator, dir.display()).as_str()).await?;
+
+    // a connection can also be built on a socket that was connected beforehand
+    let port = sqlx::Url::try_from(&url)?.port(5432);
+    let stream = tokio::net::UnixStream::connect(dir.join(format!(".s.PGSQL.{}", port))).await?;
+    let by_stream = PgConnection::connect_stream(stream, url).await?;
+
+    for mut conn in vec![by_host, by_stream] {
+        let row = sqlx::query("SELECT inet_server_addr() IS NULL")
+            .fetch_one(&mut conn)
+            .await?;
+
+        // there is no server address for a connection over a Unix-domain socket
+        assert!(row.get::<bool, _>(0));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_connects_over_stream() -> anyhow::Result<()> {
+    use std::convert::TryFrom;
+
+    let url = dotenv::var("DATABASE_URL")?;
+    let parsed = sqlx::Url::try_from(&url)?;
+
+    // dial the server ourselves, as a proxy or tunnel would
+    let stream = tokio::net::TcpStream::connect((parsed.host(), parsed.port(5432))).await?;
+    let mut conn = PgConnection::connect_stream(stream, url).await?;
+
+    let row = sqlx::query("select 1 + 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(2, row.get(0));
+
+    conn.close().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_refuses_to_send_password_without_ssl() -> anyhow::Result<()> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mut listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    // a server that asks for the password in cleartext
+    let server = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await?;
+
+        let mut startup = [0; 1024];
+        stream.read(&mut startup).await?;
+        stream.write_all(b"R\0\0\0\x08\0\0\0\x03").await?;
+
+        Ok::<_, std::io::Error>(stream)
+    });
+
+    let url = format!(
+        "postgres://postgres:password@{}/postgres?require_ssl_for_password=true",
+        addr
+    );
+
+    let err = PgConnection::open(&url).await.err().unwrap();
+
+    assert!(matches!(err, sqlx::Error::PasswordRequiresSsl), "{:?}", err);
+
+    server.await??;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_sends_notifications() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+    let mut listener = connect().await?;
+
+    listener.send(r#"LISTEN "it's a channel""#).await?;
+
+    // the connection is notified too; the query it is running must not be interrupted
+    conn.send(r#"LISTEN "it's a channel""#).await?;
+
+    conn.notify("it's a channel", "'); DROP TABLE users; --")
+        .await?;
+
+    let mut tx = conn.begin().await?;
+    tx.notify("it's a channel", "rolled back").await?;
+    tx.rollback().await?;
+
+    // the payload is limited to 8000 bytes
+    assert!(conn
+        .notify("it's a channel", &"x".repeat(8000))
+        .await
+        .is_err());
+
+    let row = sqlx::query("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let pid = row.get::<i32, _>(0) as u32;
+
+    // the notifications are received while the listener runs a query
+    listener.ping().await?;
+
+    let notifications = listener.take_notifications();
+
+    assert_eq!(
+        notifications,
+        [PgNotification {
+            process_id: pid,
+            channel: "it's a channel".to_owned(),
+            payload: "'); DROP TABLE users; --".to_owned(),
+        }]
+    );
+    assert!(listener.take_notifications().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_executes_files() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let dir = std::env::temp_dir();
+    let path = dir.join(format!("sqlx-migration-{}.sql", std::process::id()));
+
+    std::fs::write(
+        &path,
+        "CREATE TEMPORARY TABLE migrated (id INT NOT NULL);\n\
+         INSERT INTO migrated VALUES (1), (2);\n\
+         INSERT INTO migrated VALUES (3);\n",
+    )?;
+
+    let affected = conn.execute_file(&path).await;
+    std::fs::remove_file(&path)?;
+
+    assert_eq!(affected?, 3);
+
+    let row = sqlx::query("SELECT count(*) FROM migrated")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<i64, _>(0), 3);
+
+    let err = conn
+        .execute_file(dir.join("sqlx-migration-does-not-exist.sql"))
+        .await
+        .unwrap_err();
+
+    match err {
+        sqlx::Error::Io(err) => assert_eq!(err.kind(), std::io::ErrorKind::NotFound),
+        err => panic!("unexpected error: {:?}", err),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_tracks_parameter_statuses() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    assert_eq!(conn.parameter_statuses()["client_encoding"], "UTF8");
+
+    // the server time zone may be anything
+    conn.send("SET TIME ZONE 'UTC'").await?;
+
+    assert_eq!(conn.parameter_statuses()["TimeZone"], "UTC");
+
+    conn.send("SET TIME ZONE 'America/New_York'").await?;
+
+    assert_eq!(conn.parameter_statuses()["TimeZone"], "America/New_York");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_reports_closed_connection() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+    let mut other = connect().await?;
+
+    let pid: i32 = sqlx::query("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?
+        .get(0);
+
+    sqlx::query("SELECT pg_terminate_backend($1)")
+        .bind(pid)
+        .fetch_one(&mut other)
+        .await?;
+
+    // the server may still get to send us the reason for the shutdown first
+    let err = match conn.send("SELECT 1").await {
+        Err(sqlx::Error::Database(_)) => conn.send("SELECT 1").await.unwrap_err(),
+        res => res.unwrap_err(),
+    };
+
+    assert!(
+        matches!(err, sqlx::Error::ConnectionClosed(_)),
+        "expected `ConnectionClosed`, got {:?}",
+        err
+    );
+    assert!(std::error::Error::source(&err).is_some());
+    assert!(conn.is_closed());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_wakes_pool_waiters_when_a_connection_is_closed() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .connect_timeout(Duration::from_secs(30))
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire_owned().await?;
+
+    let pid: i32 = sqlx::query("SELECT pg_backend_pid()")
+        .fetch_one(&mut *conn)
+        .await?
+        .get(0);
+
+    let waiting = tokio::spawn({
+        let pool = pool.clone();
+
+        async move { pool.acquire().await.map(drop) }
+    });
+
+    tokio::time::delay_for(Duration::from_millis(100)).await;
+
+    sqlx::query("SELECT pg_terminate_backend($1)")
+        .bind(pid)
+        .fetch_one(&mut connect().await?)
+        .await?;
+
+    while !conn.is_closed() {
+        let _ = conn.send("SELECT 1").await;
+    }
+
+    // the connection is not returned to the pool, which makes room for a new one
+    drop(conn);
+
+    tokio::time::timeout(Duration::from_secs(5), waiting).await???;
+
+    assert_eq!(pool.size(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_prepares_statements() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let statement = conn.prepare("SELECT $1::int4 + 1").await?;
+
+    for i in 0..3_i32 {
+        let row = statement.query().bind(i).fetch_one(&mut conn).await?;
+
+        assert_eq!(i + 1, row.get(0));
+    }
+
+    let prepared: i64 = sqlx::query(
+        "SELECT count(*) FROM pg_prepared_statements WHERE statement = 'SELECT $1::int4 + 1'",
+    )
+    .fetch_one(&mut conn)
+    .await?
+    .get(0);
+
+    assert_eq!(prepared, 1);
+
+    statement.close(&mut conn).await?;
+
+    let prepared: i64 = sqlx::query(
+        "SELECT count(*) FROM pg_prepared_statements WHERE statement = 'SELECT $1::int4 + 1'",
+    )
+    .fetch_one(&mut conn)
+    .await?
+    .get(0);
+
+    assert_eq!(prepared, 0);
+
+    // a failed prepare doesn't poison the connection
+    assert!(conn.prepare("SELECT * FROM").await.is_err());
+    assert!(conn.prepare("SELECT * FROM").await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_clears_cached_statements() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    assert_eq!(conn.cached_statements_len(), 0);
+
+    sqlx::query("SELECT 1").fetch_one(&mut conn).await?;
+    sqlx::query("SELECT 2").fetch_one(&mut conn).await?;
+
+    assert_eq!(conn.cached_statements_len(), 2);
+
+    conn.clear_cached_statements().await?;
+
+    assert_eq!(conn.cached_statements_len(), 0);
+
+    // only the statement counting them is left on the server
+    let prepared: i64 = sqlx::query("SELECT count(*) FROM pg_prepared_statements")
+        .fetch_one(&mut conn)
+        .await?
+        .get(0);
+
+    assert_eq!(prepared, 1);
+
+    // the statements are prepared again when needed
+    let row = sqlx::query("SELECT 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(1, row.get::<i32, _>(0));
+    assert_eq!(conn.cached_statements_len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_runs_queries_without_caching_statements() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    sqlx::query("CREATE TEMPORARY TABLE uncached (id INTEGER)")
+        .persistent(false)
+        .execute(&mut conn)
+        .await?;
+
+    for id in 1..=3_i32 {
+        sqlx::query("INSERT INTO uncached (id) VALUES ($1)")
+            .bind(id)
+            .persistent(false)
+            .execute(&mut conn)
+            .await?;
+    }
+
+    let ids: Vec<i32> = sqlx::query("SELECT id FROM uncached ORDER BY id")
+        .persistent(false)
+        .fetch_size(2)
+        .fetch(&mut conn)
+        .map_ok(|row| row.get::<i32, _>(0))
+        .try_collect()
+        .await?;
+
+    assert_eq!(ids, [1, 2, 3]);
+
+    let row = sqlx::query("SELECT count(*) FROM uncached WHERE id > $1")
+        .bind(1_i32)
+        .persistent(false)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<i64, _>(0), 2);
+    assert_eq!(conn.cached_statements_len(), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_limits_fetched_rows() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let rows = sqlx::query("SELECT generate_series(1, 3)")
+        .fetch_all_limited(&mut conn, 3)
+        .await?;
+
+    assert_eq!(rows.len(), 3);
+
+    let res = sqlx::query("SELECT generate_series(1, 1000)")
+        .fetch_all_limited(&mut conn, 10)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::RowLimitExceeded(10))));
+
+    // the rest of the result set is skipped
+    let row = sqlx::query("SELECT 1 + 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(2, row.get(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_fetches_all_into_a_vec() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let mut rows = Vec::new();
+
+    for n in &[3, 1] {
+        let count = sqlx::query("SELECT generate_series(1, $1)")
+            .bind(n)
+            .fetch_all_into(&mut conn, &mut rows)
+            .await?;
+
+        assert_eq!(count, *n as usize);
+        assert_eq!(rows.len(), *n as usize);
+        assert_eq!(rows.last().unwrap().get::<i32, _>(0), *n);
+    }
+
+    let mut ids = vec![(0,)];
+
+    let count = sqlx::query_as::<_, (i32,)>("SELECT generate_series(1, 0)")
+        .fetch_all_into(&mut conn, &mut ids)
+        .await?;
+
+    assert_eq!(count, 0);
+    assert!(ids.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_builds_queries() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let mut builder = QueryBuilder::<Postgres>::new("");
+
+    for (min, name) in &[
+        (None, None),
+        (Some(2), None),
+        (Some(2), Some("'; DROP TABLE x; --")),
+    ] {
+        builder
+            .reset()
+            .push("SELECT n FROM generate_series(1, 5) n WHERE TRUE");
+
+        if let Some(min) = min {
+            builder.push(" AND n >= ").push_bind(*min);
+        }
+
+        if let Some(name) = name {
+            builder.push(" AND n::text <> ").push_bind(*name);
+        }
+
+        let rows = builder.build().fetch_all(&mut conn).await?;
+
+        assert_eq!(rows.len(), if min.is_some() { 4 } else { 5 });
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_fetches_exactly_one_row() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT generate_series(1, 1)")
+        .fetch_exactly_one(&mut conn)
+        .await?;
+
+    assert_eq!(1, row.get(0));
+
+    let res = sqlx::query("SELECT generate_series(1, 0)")
+        .fetch_exactly_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::NotFound)));
+
+    let res = sqlx::query("SELECT generate_series(1, 3)")
+        .fetch_exactly_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::FoundMoreThanOne)));
+
+    // `fetch_one` still takes the first of many rows
+    let row = sqlx::query("SELECT generate_series(1, 3)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(1, row.get(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_runs_transactions() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE transacted (id INT NOT NULL)")
+        .await?;
+
+    for (id, commit) in &[(1_i32, true), (2, false)] {
+        let mut tx = conn.begin().await?;
+
+        sqlx::query("INSERT INTO transacted (id) VALUES ($1)")
+            .bind(*id)
+            .execute(&mut tx)
+            .await?;
+
+        if *commit {
+            tx.commit().await?;
+        } else {
+            tx.rollback().await?;
+        }
+    }
+
+    // a transaction that is dropped is rolled back
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("INSERT INTO transacted (id) VALUES (3)")
+        .execute(&mut tx)
+        .await?;
+
+    drop(tx);
+
+    let rows = sqlx::query("SELECT id FROM transacted")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<i32, _>(0), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_runs_queries_in_transactions() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE transacted (id INT NOT NULL)")
+        .await?;
+
+    let mut tx = conn.begin().await?;
+
+    for id in 1..=3_i32 {
+        sqlx::query("INSERT INTO transacted (id) VALUES ($1)")
+            .bind(id)
+            .execute(&mut tx)
+            .await?;
+    }
+
+    let rows = sqlx::query("SELECT id FROM transacted ORDER BY id")
+        .fetch_all(&mut tx)
+        .await?;
+
+    assert_eq!(rows.len(), 3);
+
+    let (max,): (i32,) = sqlx::query_as("SELECT MAX(id) FROM transacted")
+        .fetch_one(&mut tx)
+        .await?;
+
+    assert_eq!(max, 3);
+
+    let row = sqlx::query("SELECT id FROM transacted WHERE id = $1")
+        .bind(4_i32)
+        .persistent(false)
+        .fetch_optional(&mut tx)
+        .await?;
+
+    assert!(row.is_none());
+
+    tx.rollback().await?;
+
+    let row = sqlx::query("SELECT COUNT(*) FROM transacted")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<i64, _>(0), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_runs_transactions_with_isolation_levels() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("SET SESSION CHARACTERISTICS AS TRANSACTION ISOLATION LEVEL REPEATABLE READ")
+        .await?;
+
+    for (isolation, name) in &[
+        (IsolationLevel::ReadUncommitted, "read uncommitted"),
+        (IsolationLevel::ReadCommitted, "read committed"),
+        (IsolationLevel::RepeatableRead, "repeatable read"),
+        (IsolationLevel::Serializable, "serializable"),
+    ] {
+        for read_only in &[false, true] {
+            let mut tx = conn.begin_with(*isolation, *read_only).await?;
+
+            let row = sqlx::query(
+                "SELECT current_setting('transaction_isolation'), \
+                 current_setting('transaction_read_only')::boolean",
+            )
+            .fetch_one(&mut tx)
+            .await?;
+
+            assert_eq!(row.get::<String, _>(0), *name);
+            assert_eq!(row.get::<bool, _>(1), *read_only);
+
+            tx.commit().await?;
+        }
+    }
+
+    // a read-only transaction rejects writes
+    let mut tx = conn.begin_with(IsolationLevel::Serializable, true).await?;
+
+    assert!(tx
+        .send("CREATE TEMPORARY TABLE written (id INT)")
+        .await
+        .is_err());
+
+    tx.rollback().await?;
+
+    // the level set for the session is used again after the transaction
+    let row = sqlx::query("SELECT current_setting('transaction_isolation')")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<String, _>(0), "repeatable read");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_recovers_from_dropped_fetches() -> anyhow::Result<()> {
+    use futures::FutureExt;
+
+    let mut conn = connect().await?;
+
+    // the rest of the rows are drained before the next query
+    for fetch_size in &[None, Some(10)] {
+        let mut query = sqlx::query("SELECT generate_series(1, 100000)");
+
+        if let Some(fetch_size) = fetch_size {
+            query = query.fetch_size(*fetch_size);
+        }
+
+        let mut rows = query.fetch(&mut conn);
+
+        assert_eq!(1, rows.try_next().await?.unwrap().get::<i32, _>(0));
+
+        drop(rows);
+
+        let row = sqlx::query("SELECT $1::int4")
+            .bind(42_i32)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(42, row.get::<i32, _>(0));
+    }
+
+    // a query dropped while it waits for a previous query to complete is never sent, so its
+    // results are not mistaken for those of the next query
+    let sleep = sqlx::query("SELECT pg_sleep(0.5)")
+        .fetch_one(&mut conn)
+        .now_or_never();
+
+    assert!(sleep.is_none());
+
+    let dropped = sqlx::query("SELECT 'dropped'")
+        .fetch_one(&mut conn)
+        .now_or_never();
+
+    assert!(dropped.is_none());
+
+    let row = sqlx::query("SELECT $1::int4")
+        .bind(42_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(42, row.get::<i32, _>(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_binds_dynamic_arguments() -> anyhow::Result<()> {
+    use sqlx::encode::DynEncode;
+
+    let mut conn = connect().await?;
+
+    let params: Vec<Box<dyn DynEncode<sqlx::Postgres>>> =
+        vec![Box::new(5_i32), Box::new("five"), Box::new(None::<i64>)];
+
+    let mut query = sqlx::query("SELECT $1 + 1, $2 || '!', $3 IS NULL");
+
+    for param in &params {
+        query = query.bind_dyn(&**param);
+    }
+
+    let row = query.fetch_one(&mut conn).await?;
+
+    assert_eq!(6, row.get(0));
+    assert_eq!("five!", row.get::<String, _>(1));
+    assert!(row.get::<bool, _>(2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_counts_columns() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT 1, 'two', NULL")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.len(), 3);
+    assert!(!row.is_empty());
+
+    let row = sqlx::query("SELECT").fetch_one(&mut conn).await?;
+
+    assert_eq!(row.len(), 0);
+    assert!(row.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_maps_rows_with_a_closure() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let accounts: Vec<(i32, String)> = sqlx::query_as_mapped::<sqlx::Postgres, _>(
+        "SELECT id, 'account ' || id AS name FROM generate_series(1, 3) id WHERE id > $1",
+        |row| Ok((row.try_get("id")?, row.try_get(1)?)),
+    )
+    .bind(1_i32)
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(
+        accounts,
+        [(2, "account 2".to_owned()), (3, "account 3".to_owned())]
+    );
+
+    let res =
+        sqlx::query_as_mapped::<sqlx::Postgres, i32>("SELECT 1", |row| row.try_get("missing"))
+            .fetch_one(&mut conn)
+            .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnNotFound(_))));
+
+    // the closure may capture and mutate its environment
+    let names = vec!["zero", "one", "two"];
+    let mut seen = 0;
+
+    let accounts: Vec<&str> =
+        sqlx::query_as_mapped::<sqlx::Postgres, _>("SELECT generate_series(0, 2)", |row| {
+            seen += 1;
+
+            Ok(names[row.try_get::<i32, _>(0)? as usize])
+        })
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(accounts, ["zero", "one", "two"]);
+    assert_eq!(seen, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_fetches_in_pages() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let rows = sqlx::query("SELECT generate_series(1, 1000)")
+        .fetch_size(64)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(rows.len(), 1000);
+    assert_eq!(rows[999].get::<i32, _>(0), 1000);
+
+    // stop reading part way through
+    {
+        let mut s = sqlx::query("SELECT generate_series(1, 1000)")
+            .fetch_size(10)
+            .fetch(&mut conn);
+
+        assert_eq!(s.try_next().await?.unwrap().get::<i32, _>(0), 1);
+    }
+
+    let row = sqlx::query("SELECT 1 + 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(2, row.get(0));
+
+    // fail part way through
+    let res = sqlx::query("SELECT 10 / (50 - i) FROM generate_series(1, 100) i")
+        .fetch_size(10)
+        .fetch_all(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Database(_))));
+
+    let row = sqlx::query("SELECT 1 + 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(2, row.get(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_chains_database_errors() -> anyhow::Result<()> {
+    use std::error::Error as _;
+
+    let mut conn = connect().await?;
+
+    let err = sqlx::query("SELECT 1 / 0")
+        .fetch_one(&mut conn)
+        .await
+        .err()
+        .unwrap();
+
+    let source = err.source().unwrap();
+
+    assert_eq!(source.to_string(), err.to_string());
+    assert!(source.downcast_ref::<sqlx::postgres::PgError>().is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_reports_error_positions() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let err = conn
+        .describe("SELECT id,\n       nmae\nFROM (SELECT 1 AS id) AS t")
+        .await;
+
+    match err {
+        Err(sqlx::Error::Database(err)) => assert_eq!(err.position(), Some(19)),
+        other => panic!("expected a database error, got {:?}", other.map(|_| ())),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_rejects_mysql_placeholders() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let res = sqlx::query("SELECT ?::int4 + 1")
+        .bind(1_i32)
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::PlaceholderStyleMismatch(_))));
+
+    let res = sqlx::query("UPDATE pg_catalog.pg_type SET typname = ? WHERE false")
+        .bind("name")
+        .execute(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::PlaceholderStyleMismatch(_))));
+
+    // `?` is also an operator on `jsonb`
+    let row = sqlx::query("SELECT '{\"a\": 1}'::jsonb ? $1")
+        .bind("a")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.get::<bool, _>(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_describes_upserts() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send(
+        "CREATE TEMPORARY TABLE upserted (id INT PRIMARY KEY, name TEXT NOT NULL, hits INT NOT NULL DEFAULT 0)",
+    )
+    .await?;
+
+    let query = "INSERT INTO upserted (id, name) VALUES ($1, $2) \
+                 ON CONFLICT (id) DO UPDATE SET name = $3, hits = upserted.hits + 1 \
+                 RETURNING id, name, hits";
+
+    // the placeholders of the conflict clause are parameters too
+    let describe = conn.describe(query).await?;
+
+    assert_eq!(&*describe.param_types, [23, 25, 25]);
+
+    let columns: Vec<_> = describe
+        .result_columns
+        .iter()
+        .map(|column| column.name.as_deref())
+        .collect();
+
+    assert_eq!(columns, [Some("id"), Some("name"), Some("hits")]);
+
+    for (name, hits) in &[("first", 0), ("second", 1)] {
+        let row = sqlx::query(query)
+            .bind(1_i32)
+            .bind(*name)
+            .bind(*name)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(row.get::<i32, _>("id"), 1);
+        assert_eq!(row.get::<String, _>("name"), *name);
+        assert_eq!(row.get::<i32, _>("hits"), *hits);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_keeps_the_plan_cache_mode_of_the_session() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE authors (id INT PRIMARY KEY, name TEXT NOT NULL)")
+        .await?;
+
+    conn.send("SET plan_cache_mode = force_custom_plan").await?;
+
+    // the outputs of a join with parameters are explained with a generic plan
+    let describe = conn
+        .describe(
+            "SELECT a.name, b.name FROM authors a \
+             LEFT JOIN authors b ON b.id = a.id + 1 WHERE a.id = $1",
+        )
+        .await?;
+
+    assert_eq!(&*describe.param_types, [23]);
+    assert_eq!(describe.result_columns[1].nullable, Some(true));
+
+    let mode: String = sqlx::query("SELECT current_setting('plan_cache_mode')")
+        .fetch_scalar(&mut conn)
+        .await?;
+
+    assert_eq!(mode, "force_custom_plan");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_describes_parameters_of_conditional_expressions() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let describe = conn.describe("SELECT COALESCE($1, 5)").await?;
+
+    assert_eq!(&*describe.param_types, [23]);
+
+    // the types are deduced even if a parameter is first used in `IS NULL`
+    let query = "SELECT CASE WHEN $1 IS NULL THEN 0 ELSE $1 END WHERE $2 IS NULL OR $2 = 'b'";
+    let describe = conn.describe(query).await?;
+
+    assert_eq!(&*describe.param_types, [23, 25]);
+    assert_eq!(describe.result_columns[0].type_id, 23);
+
+    // a parameter that is only used in `IS NULL` has no type
+    assert!(conn.describe("SELECT $1 IS NULL").await.is_err());
+
+    let row = sqlx::query(query)
+        .bind(4_i32)
+        .bind("b")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<i32, _>(0), 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_describes_default_values() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE defaulted (id SERIAL PRIMARY KEY)")
+        .await?;
+
+    let describe = conn
+        .describe("INSERT INTO defaulted DEFAULT VALUES RETURNING id")
+        .await?;
+
+    assert!(describe.param_types.is_empty());
+    assert_eq!(describe.result_columns.len(), 1);
+
+    // a statement without output is described with no columns
+    let describe = conn
+        .describe("INSERT INTO defaulted DEFAULT VALUES")
+        .await?;
+
+    assert!(describe.param_types.is_empty());
+    assert!(describe.result_columns.is_empty());
+
+    let row = sqlx::query("INSERT INTO defaulted DEFAULT VALUES RETURNING id")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<i32, _>(0), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_describes_outer_joins() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TEMPORARY TABLE authors (id INT PRIMARY KEY, name TEXT NOT NULL)")
+        .await?;
+
+    conn.send(
+        "CREATE TEMPORARY TABLE books (id INT PRIMARY KEY, author_id INT NOT NULL, title TEXT NOT NULL)",
+    )
+    .await?;
+
+    let nullable = |describe: &sqlx::describe::Describe<Postgres>| -> Vec<_> {
+        describe
+            .result_columns
+            .iter()
+            .map(|column| column.nullable)
+            .collect()
+    };
+
+    // the columns of the right table may be `NULL`, unless they are replaced
+    let describe = conn
+        .describe(
+            "SELECT authors.name, books.title, COALESCE(books.id, 0) AS id \
+             FROM authors LEFT JOIN books ON books.author_id = authors.id WHERE authors.id = $1",
+        )
+        .await?;
+
+    assert_eq!(nullable(&describe), [None, Some(true), None]);
+
+    let describe = conn
+        .describe(
+            "SELECT authors.name, books.title \
+             FROM authors RIGHT JOIN books ON books.author_id = authors.id",
+        )
+        .await?;
+
+    assert_eq!(nullable(&describe), [Some(true), None]);
+
+    let describe = conn
+        .describe(
+            "SELECT authors.name, books.title, t.kind \
+             FROM authors FULL JOIN books ON books.author_id = authors.id \
+             LEFT JOIN (SELECT 1 AS id, 'x'::text AS kind) t ON t.id = books.id",
+        )
+        .await?;
+
+    assert_eq!(nullable(&describe), [Some(true), Some(true), Some(true)]);
+
+    let describe = conn
+        .describe("SELECT authors.name, books.title FROM authors JOIN books ON books.author_id = authors.id")
+        .await?;
+
+    assert_eq!(nullable(&describe), [None, None]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_describes_and_decodes_enums() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.send("CREATE TYPE pg_temp.mood AS ENUM ('sad', 'ok', 'happy')")
+        .await?;
+
+    let query = "SELECT 'happy'::pg_temp.mood AS mood, 'happy'::text AS label";
+
+    let describe = conn.describe(query).await?;
+
+    assert_eq!(
+        describe.result_columns[0].type_name.as_deref(),
+        Some("mood")
+    );
+    assert_eq!(describe.result_columns[0].type_is_enum, Some(true));
+
+    // builtin types are not looked up
+    assert_eq!(describe.result_columns[1].type_is_enum, None);
+
+    let row = sqlx::query(query).fetch_one(&mut conn).await?;
+
+    assert_eq!(row.get::<String, _>("mood"), "happy");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_describes_views() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    // materialized views cannot select from temporary tables so the schema is rolled back
+    conn.send(
+        "BEGIN; \
+         CREATE DOMAIN positive AS int4 CHECK (VALUE > 0); \
+         CREATE TABLE viewed (id int4 NOT NULL, score positive, name text); \
+         CREATE VIEW viewed_names AS SELECT id, score, upper(name) AS name FROM viewed; \
+         CREATE MATERIALIZED VIEW viewed_ids AS SELECT id, score FROM viewed",
+    )
+    .await?;
+
+    let table = conn.describe("SELECT id, score, name FROM viewed").await?;
+    let view = conn
+        .describe("SELECT id, score, name FROM viewed_names")
+        .await?;
+    let materialized = conn.describe("SELECT id, score FROM viewed_ids").await?;
+
+    conn.send("ROLLBACK").await?;
+
+    let type_ids = |describe: &sqlx::describe::Describe<sqlx::Postgres>| {
+        describe
+            .result_columns
+            .iter()
+            .map(|column| column.type_id)
+            .collect::<Vec<_>>()
+    };
+
+    // the domain is described as its base type
+    assert_eq!(type_ids(&table), [23, 23, 25]);
+    assert_eq!(type_ids(&view), type_ids(&table));
+    assert_eq!(type_ids(&materialized), [23, 23]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_fetches_rows_as_tuples() -> anyhow::Result<()> {
+    use sqlx::FromRow;
+
+    let mut conn = connect().await?;
+
+    let row = sqlx::query("SELECT 1, 'Herp Derpinson', NULL::text")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let (id, name, nickname): (i32, String, Option<String>) = FromRow::from_row(row);
+
+    assert_eq!((id, name.as_str(), nickname), (1, "Herp Derpinson", None));
+
+    let rows: Vec<_> = sqlx::query("SELECT i, i * 2 FROM generate_series(1, 3) i")
+        .fetch_as::<(i32, i32), _>(&mut conn)
+        .try_collect()
+        .await?;
+
+    assert_eq!(rows, [(1, 2), (2, 4), (3, 6)]);
+
+    // values that cannot be decoded are returned as errors instead of panicking
+    let res: sqlx::Result<Vec<(i32, String)>> = sqlx::query("SELECT 1, NULL::text")
+        .fetch_as::<(i32, String), _>(&mut conn)
+        .try_collect()
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_fetches_scalars() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let count = sqlx::query("SELECT count(*) FROM generate_series(1, 5)")
+        .fetch_scalar::<i64, _>(&mut conn)
+        .await?;
+
+    assert_eq!(count, 5);
+
+    let name: Option<String> = sqlx::query("SELECT NULL::text")
+        .fetch_scalar(&mut conn)
+        .await?;
+
+    assert_eq!(name, None);
+
+    let err = sqlx::query("SELECT 1 WHERE false")
+        .fetch_scalar::<i32, _>(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, sqlx::Error::NotFound), "{:?}", err);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_fetches_rows_as_user_type() -> anyhow::Result<()> {
+    struct Account {
+        id: i32,
+        name: String,
+    }
+
+    impl sqlx::FromRow<PgRow> for Account {
+        fn from_row(row: PgRow) -> Self {
+            Account {
+                id: row.get("id"),
+                name: row.get("name"),
+            }
+        }
+    }
+
+    let mut conn = connect().await?;
+
+    let mut accounts = sqlx::query(
+        "SELECT id, 'account ' || id AS name FROM generate_series(1, 5) id WHERE id > $1",
+    )
+    .bind(2_i32)
+    .fetch_size(1)
+    .fetch_as::<Account, _>(&mut conn);
+
+    let mut ids = Vec::new();
+
+    while let Some(account) = accounts.try_next().await? {
+        assert_eq!(account.name, format!("account {}", account.id));
+
+        ids.push(account.id);
+    }
+
+    assert_eq!(ids, [3, 4, 5]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_rewrites_sql() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    conn.set_sql_rewriter(|sql| {
+        if sql.contains("{table}") {
+            sql.replace("{table}", "rewritten").into()
+        } else {
+            sql.into()
+        }
+    });
+
+    conn.send("CREATE TEMPORARY TABLE {table} (id INTEGER)")
+        .await?;
+
+    sqlx::query("INSERT INTO {table} (id) VALUES ($1), ($2)")
+        .bind(1_i32)
+        .bind(2_i32)
+        .execute(&mut conn)
+        .await?;
+
+    let statement = conn.prepare("SELECT count(*) FROM {table}").await?;
+
+    assert_eq!(statement.sql(), "SELECT count(*) FROM {table}");
+
+    let mut copy = conn.copy_in("COPY {table} (id) FROM STDIN").await?;
+
+    copy.send("3\n").await?;
+    copy.finish().await?;
+
+    let count = statement.query().fetch_scalar::<i64, _>(&mut conn).await?;
+
+    assert_eq!(count, 3);
+    assert_eq!(conn.cached_statements_len(), 2);
+
+    statement.close(&mut conn).await?;
+
+    assert_eq!(conn.cached_statements_len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_resets_the_sql_rewriter_of_released_pool_connections() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    conn.set_sql_rewriter(|sql| sql.replace("'{greeting}'", "'rewritten'").into());
+
+    let greeting: String = sqlx::query("SELECT '{greeting}'")
+        .fetch_scalar(&mut *conn)
+        .await?;
+
+    assert_eq!(greeting, "rewritten");
+
+    drop(conn);
+
+    // the same connection, without the rewriter
+    let mut conn = pool.acquire().await?;
+
+    let greeting: String = sqlx::query("SELECT '{greeting}'")
+        .fetch_scalar(&mut *conn)
+        .await?;
+
+    assert_eq!(greeting, "{greeting}");
+    assert_eq!(pool.size(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_sends_commands() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let tags = conn
+        .send(
+            r#"
+CREATE TEMPORARY TABLE sent (id INTEGER);
+INSERT INTO sent (id) VALUES (1), (2), (3);
+UPDATE sent SET id = id + 1 WHERE id > 1;
+SELECT id FROM sent;
+            "#,
+        )
+        .await?;
+
+    let tags: Vec<_> = tags
+        .iter()
+        .map(|tag| (tag.command(), tag.rows_affected()))
+        .collect();
+
+    assert_eq!(
+        tags,
+        [
+            ("CREATE TABLE", 0),
+            ("INSERT", 3),
+            ("UPDATE", 2),
+            ("SELECT", 3)
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_maps_query_rows() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let query = "SELECT id, 'account ' || id AS name FROM generate_series(1, 3) id WHERE id >= $1";
+
+    let names: Vec<String> = sqlx::query(query)
+        .bind(2_i32)
+        .map(|row| row.try_get("name"))
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(names, ["account 2", "account 3"]);
+
+    let ids: Vec<_> = sqlx::query(query)
+        .bind(1_i32)
+        .map(|row| row.try_get::<i32, _>(0))
+        .fetch(&mut conn)
+        .try_collect()
+        .await?;
+
+    assert_eq!(ids, [1, 2, 3]);
+
+    let id = sqlx::query(query)
+        .bind(3_i32)
+        .map(|row| row.try_get::<i32, _>(0))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(id, 3);
+
+    let id = sqlx::query(query)
+        .bind(4_i32)
+        .map(|row| row.try_get::<i32, _>(0))
+        .fetch_optional(&mut conn)
+        .await?;
+
+    assert_eq!(id, None);
+
+    // decode errors are returned from the closure
+    let res = sqlx::query::<sqlx::Postgres>("SELECT NULL::int")
+        .map(|row| row.try_get::<i32, _>(0))
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    // the closure may capture its environment
+    let prefix = String::from("#");
+
+    let labels: Vec<String> = sqlx::query(query)
+        .bind(2_i32)
+        .map(move |row| Ok(format!("{}{}", prefix, row.try_get::<i32, _>(0)?)))
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(labels, ["#2", "#3"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_sets_startup_options() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let sep = if url.contains('?') { '&' } else { '?' };
+
+    let mut conn = PgConnection::open(format!(
+        "{}{}options=-c%20statement_timeout%3D1234&search_path=tenant1,%20public",
+        url, sep
+    ))
+    .await?;
+
+    let row = sqlx::query("SHOW statement_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<String, _>(0), "1234ms");
+
+    let row = sqlx::query("SHOW search_path").fetch_one(&mut conn).await?;
+
+    assert_eq!(row.get::<String, _>(0), "tenant1, public");
+
+    Ok(())
+}
+
 async fn connect() -> anyhow::Result<PgConnection> {
     Ok(PgConnection::open(dotenv::var("DATABASE_URL")?).await?)
 }